@@ -0,0 +1,27 @@
+//! Times `Bouncer::draw` for each symbol, writing to a sink so only the
+//! drawing itself is measured. Run with `cargo bench`.
+
+use snowflake_bounce::{Bouncer, SymbolMode};
+use std::hint::black_box;
+use std::io;
+use std::time::Instant;
+
+const FRAMES: u32 = 20_000;
+
+fn main() -> io::Result<()> {
+    for mode in SymbolMode::ALL {
+        let mut bouncer = Bouncer::new();
+        bouncer.set_mode(mode);
+        bouncer.resize(200, 60);
+        let mut out = io::sink();
+
+        let start = Instant::now();
+        for _ in 0..FRAMES {
+            bouncer.update();
+            bouncer.draw(black_box(&mut out))?;
+        }
+        let per_frame = start.elapsed() / FRAMES;
+        println!("{:<16} {per_frame:>10.2?} per frame", mode.name());
+    }
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::Stats;
+use crate::config;
+
+// --- Achievements ---
+/// A milestone that unlocks once and stays unlocked across runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Achievement {
+    /// Hit two walls at once
+    FirstCorner,
+    /// 10,000 bounces, counted over every run
+    TenThousandBounces,
+    /// Kept a single run going for 24 hours
+    Marathon,
+}
+
+impl Achievement {
+    pub const ALL: [Self; 3] = [Self::FirstCorner, Self::TenThousandBounces, Self::Marathon];
+
+    /// The title shown in the unlock toast.
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::FirstCorner => "Perfect Corner",
+            Self::TenThousandBounces => "10,000 Bounces",
+            Self::Marathon => "24 Hour Marathon",
+        }
+    }
+
+    const fn reached(self, progress: &Progress) -> bool {
+        match self {
+            Self::FirstCorner => progress.corners > 0,
+            Self::TenThousandBounces => progress.bounces >= 10_000,
+            Self::Marathon => progress.longest_run_secs >= 24 * 60 * 60,
+        }
+    }
+}
+
+// --- Progress ---
+/// Lifetime totals and unlocked achievements, saved to `achievements.toml`
+/// in the data directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Progress {
+    pub unlocked: Vec<Achievement>,
+    pub bounces: u64,
+    pub corners: u64,
+    pub longest_run_secs: u64,
+    // Totals already folded in from the current run, so saving twice doesn't double count
+    #[serde(skip)]
+    counted: Stats,
+}
+
+impl Progress {
+    /// `$XDG_DATA_HOME/snowflake-bounce/achievements.toml`
+    #[must_use]
+    pub fn path() -> Option<PathBuf> {
+        Some(config::data_dir()?.join("achievements.toml"))
+    }
+
+    /// Loads saved progress; a missing or unreadable file starts from scratch.
+    #[must_use]
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|text| toml::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes progress to [`path`](Self::path), creating the directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if there's no data directory or the file can't be written.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, text)
+    }
+
+    /// Treats `stats` as already folded in, so totals carried over from a
+    /// resumed session aren't counted twice.
+    pub const fn already_counted(&mut self, stats: Stats) {
+        self.counted = stats;
+    }
+
+    /// Folds in the current run's stats and returns anything newly unlocked.
+    pub fn record(&mut self, stats: Stats, run_time: Duration) -> Vec<Achievement> {
+        self.bounces += stats.bounces.saturating_sub(self.counted.bounces);
+        self.corners += stats.corners.saturating_sub(self.counted.corners);
+        self.counted = stats;
+        self.longest_run_secs = self.longest_run_secs.max(run_time.as_secs());
+
+        let new: Vec<_> = Achievement::ALL
+            .into_iter()
+            .filter(|a| !self.unlocked.contains(a) && a.reached(self))
+            .collect();
+        self.unlocked.extend(&new);
+        new
+    }
+}
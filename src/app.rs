@@ -0,0 +1,2218 @@
+use chrono::Timelike;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute, queue,
+    style::{self, Color},
+    terminal::{self, disable_raw_mode, enable_raw_mode},
+};
+use std::io::{self, BufWriter, Stdout, Write, stdout};
+use std::mem;
+use std::panic;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::{Mutex, Once, PoisonError};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+use crate::achievements::Progress;
+use crate::aquarium::Aquarium;
+#[cfg(feature = "audio")]
+use crate::audio::Audio;
+use crate::banner::{self, Banner};
+use crate::collage::Collage;
+use crate::command::{self, Command, SPEED_RANGE};
+use crate::config::{self, Config};
+use crate::disk::Gauge;
+#[cfg(feature = "rss")]
+use crate::feed::Feed;
+use crate::fifo::Fifo;
+use crate::game::{Snake, Versus};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::Gamepad;
+use crate::git;
+use crate::inhibit::Inhibitor;
+use crate::keymap::{Action, KONAMI, Keymap, Sequence};
+use crate::meter::{Meter, RenderStats};
+#[cfg(feature = "midi")]
+use crate::midi::{Control, Midi};
+use crate::motion::{Lissajous, Orbit};
+use crate::net::Throughput;
+#[cfg(feature = "price")]
+use crate::price::Quotes;
+use crate::rainbow::Rainbow;
+use crate::session::Session;
+use crate::snow::{Snowfall, Weather};
+use crate::sound::{Sound, Speaker};
+use crate::system;
+use crate::tail::Tail;
+use crate::theme::{ColorDepth, Gradient, Theme};
+use crate::trajectory::{Recorder, Replay};
+use crate::ui::{
+    self, ColorChoice, ColorPicker, FpsCounter, Input, InputAction, Menu, MenuAction, Overlay,
+    Ticker,
+};
+use crate::{Bouncer, Direction, Lines, Rect, StartPos, Stats, SymbolMode, theme};
+
+// --- Options ---
+/// Settings for a [`run`] session, usually filled in from the command line.
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// Symbol to start with, instead of the default
+    pub symbol: Option<SymbolMode>,
+    /// Palette to start with, in place of the default colors
+    pub theme: Option<Theme>,
+    /// Color to start in, over the theme's and the symbol's own
+    pub color: Option<Color>,
+    /// Colors the logo fades through from top to bottom
+    pub gradient: Option<Gradient>,
+    /// Colors from the config's `[cycle]`, in place of the theme's when
+    /// there are any
+    pub colors: Vec<Color>,
+    /// Cycle through the palette in order rather than at random
+    pub in_order: bool,
+    /// Bounce `user@hostname` in big figlet letters instead of a symbol
+    pub banner: bool,
+    /// Bounce a greeting for the time of day instead of a symbol
+    pub greeting: bool,
+    /// Bounce this month's calendar with today picked out
+    pub calendar: bool,
+    /// Bounce a neofetch-style card of the system beside its logo
+    pub neofetch: bool,
+    /// Bounce today's moon phase
+    pub moon: bool,
+    /// Bounce a card of the current directory's git status
+    pub git: bool,
+    /// Bounce the last line of this file, following it as it grows
+    pub tail: Option<PathBuf>,
+    /// Bounce the network's download and upload rates
+    pub net: bool,
+    /// Bounce a gauge of how full the filesystem holding this path is
+    pub disk: Option<PathBuf>,
+    /// Bounce the headlines of the RSS or Atom feed at this URL
+    #[cfg(feature = "rss")]
+    pub rss: Option<String>,
+    /// Bounces each headline stays up for
+    #[cfg(feature = "rss")]
+    pub rss_every: u64,
+    /// Bounce the price of this stock or coin, e.g. `AAPL` or `BTC-USD`
+    #[cfg(feature = "price")]
+    pub price: Option<String>,
+    /// Bounce this text instead of a symbol
+    pub text: Option<String>,
+    /// Columns `text` is word-wrapped to
+    pub text_width: u16,
+    /// Where clicking the logo goes, in terminals with OSC 8 hyperlinks
+    pub link: Option<String>,
+    /// No color escapes at all; bounces switch between bold and reverse video
+    pub no_color: bool,
+    /// Bold white on a black background, without dimming or gradients, and
+    /// the bigger snowflake
+    pub high_contrast: bool,
+    /// Slower, with wall hits fading to the next color instead of switching,
+    /// and nothing that flashes or cycles colors quickly
+    pub reduced_motion: bool,
+    /// Never draw a background, for terminals with see-through ones
+    pub transparent: bool,
+    /// Spin the small symbols while they bounce
+    pub spin: bool,
+    /// Grow and shrink the snowflakes between their sizes
+    pub pulse: bool,
+    /// Dissolve between symbols when switching, and between spots on a jump
+    pub morph: bool,
+    /// Draw the symbols in plain ASCII
+    pub ascii: bool,
+    /// Carry on from the scene saved by the last `--resume` run, and save this one on quit
+    pub resume: bool,
+    /// Draw in the normal screen buffer instead of the alternate screen
+    pub inline: bool,
+    /// Show a status bar on the bottom row
+    pub status: bool,
+    /// Draw a frame around the play area
+    pub border: bool,
+    /// Empty cells kept between the screen edges and the play area
+    pub margin: u16,
+    /// Restrict the play area to this rectangle of the screen
+    pub area: Option<Rect>,
+    /// Where the logo starts, instead of somewhere random
+    pub start_pos: Option<StartPos>,
+    /// Which way the logo starts moving, instead of a random diagonal
+    pub direction: Option<Direction>,
+    /// Attract mode: show off a new symbol and theme every [`DEMO_INTERVAL`]
+    pub demo: bool,
+    /// Switch to a random symbol this often
+    pub shuffle: Option<Duration>,
+    /// Grow the symbol through the [`STAGES`] as the bounces mount up
+    pub evolve: bool,
+    /// Quit by itself once this much time has passed
+    pub duration: Option<Duration>,
+    /// Quit on any key press or mouse movement, instead of reading commands
+    pub screensaver: bool,
+    /// Keep the machine from idling or sleeping while it runs
+    pub inhibit: bool,
+    /// Keep the bounce and corner counts in the terminal's title
+    pub title: bool,
+    /// Write every tick's position and velocity to this file
+    pub record_path: Option<PathBuf>,
+    /// Play back a recorded run instead of bouncing, and quit at its end
+    pub replay: Option<PathBuf>,
+    /// Two-player keep-away instead of a screensaver
+    pub versus: bool,
+    /// Tron-style survival: the flake leaves a trail and must never cross it
+    pub snake: bool,
+    /// Fish swimming across the screen instead of a bouncing logo
+    pub aquarium: bool,
+    /// Every symbol bouncing at once, each in its own color
+    pub all: bool,
+    /// Have those logos push one another away rather than overlap
+    pub spread: bool,
+    /// What wall hits sound like
+    pub sound: Sound,
+    /// Brighten and dim the colors with the time of day
+    pub day_night: bool,
+    /// Stay on the dim night palette whatever the time
+    pub night: bool,
+    /// Light snow falling behind the logo
+    pub snow: bool,
+    /// Text to scroll along the bottom row
+    pub ticker: Option<String>,
+    /// How fast the ticker scrolls, in characters per second
+    pub ticker_speed: u16,
+    /// Make the snow follow the local weather; implies `snow`
+    #[cfg(feature = "weather")]
+    pub weather: bool,
+    /// Follow the default audio input: louder runs faster, beats change color
+    #[cfg(feature = "audio")]
+    pub audio: bool,
+    /// Steer with any connected gamepad
+    #[cfg(feature = "gamepad")]
+    pub gamepad: bool,
+    /// MIDI input port to take knob turns from, by part of its name; empty for any
+    #[cfg(feature = "midi")]
+    pub midi: Option<String>,
+    /// Named pipe to take `:` commands from, one a line
+    pub fifo: Option<PathBuf>,
+    /// Multiplier on the frame rate to start with, instead of 1
+    pub speed: Option<f64>,
+    /// Multipliers on horizontal and vertical movement alone
+    pub axis_speed: (f64, f64),
+    /// Share of its speed the logo keeps through each wall hit
+    pub restitution: f64,
+    /// Pull the logo toward or away from the mouse pointer
+    pub cursor: Option<Cursor>,
+    /// Follow a Lissajous figure with these frequencies instead of bouncing
+    pub lissajous: Option<Lissajous>,
+    /// Circle the middle of the screen instead of bouncing
+    pub orbit: Option<Orbit>,
+    /// Scale each of those by a random factor from this range to start with
+    pub random_speed: Option<(f64, f64)>,
+    /// Which keys trigger which actions
+    pub keymap: Keymap,
+    /// Config file to read again on `reload_config` or a service's SIGHUP, or `None`
+    /// for the default location
+    pub config_path: Option<PathBuf>,
+    /// What the config file held when these options were made, so a
+    /// reload only applies the settings that changed since
+    pub config: Config,
+}
+
+// How much faster rainbow turbo mode runs
+const TURBO: f64 = 2.5;
+// How much slower `--reduced-motion` runs, and how long its color fades take
+const REDUCED_MOTION: f64 = 0.5;
+const FADE_TIME: Duration = Duration::from_millis(1500);
+// How long an achievement toast stays up
+const TOAST_TIME: Duration = Duration::from_secs(4);
+// How often achievement progress is written to disk
+const SAVE_INTERVAL: Duration = Duration::from_mins(1);
+// Most frames in a row left undrawn when drawing can't keep up
+const MAX_FRAME_SKIP: u32 = 4;
+// Room for a full-screen redraw, so a frame never needs more than one write
+const FRAME_BUFFER: usize = 64 * 1024;
+// How often `--title` looks at the counts again
+const TITLE_INTERVAL: Duration = Duration::from_secs(1);
+// xterm's window title stack, so the shell's title comes back on quit
+const PUSH_TITLE: &str = "\x1b[22;0t";
+const POP_TITLE: &str = "\x1b[23;0t";
+
+// Sets, then resets, the terminal's default background color (OSC 11 and 111)
+const BLACK_BACKGROUND: &str = "\x1b]11;#000000\x1b\\";
+const DEFAULT_BACKGROUND: &str = "\x1b]111\x1b\\";
+
+/// How long attract mode lingers on each look.
+pub const DEMO_INTERVAL: Duration = Duration::from_secs(10);
+
+// --- Modals ---
+/// A modal UI element that takes over keyboard input while open
+enum Modal {
+    /// Pauses the animation; any key dismisses it
+    Help(Overlay),
+    /// Previews symbols live; Esc restores the symbol that was active on open
+    Symbols { menu: Menu, original: SymbolMode },
+    /// Previews colors and themes live; Esc restores the original color and palette
+    Colors {
+        picker: ColorPicker,
+        original: (Color, Vec<Color>),
+    },
+    /// Custom RGB entry opened from the color picker
+    Rgb {
+        input: Input,
+        original: (Color, Vec<Color>),
+    },
+    /// The `:` prompt; a failed command leaves its error showing until the next key
+    Command { input: Input, error: Option<String> },
+    /// The snake crashed; pauses until any key starts another run
+    GameOver(Overlay),
+}
+
+fn apply_color(bouncer: &mut Bouncer, choice: ColorChoice) {
+    match choice {
+        ColorChoice::Color(c) => bouncer.set_color(c),
+        ColorChoice::Theme(t) => {
+            // Themes are written in full color, so they're fitted to what
+            // the terminal can show like any other color
+            let depth = ColorDepth::detect();
+            let palette: Vec<Color> = t.colors.iter().map(|&c| depth.fit(c)).collect();
+            bouncer.set_palette(&palette);
+            bouncer.set_color(palette[0]);
+        }
+        ColorChoice::Rgb => {}
+    }
+}
+
+fn restore_color(bouncer: &mut Bouncer, (color, palette): &(Color, Vec<Color>)) {
+    bouncer.set_color(*color);
+    bouncer.set_palette(palette);
+}
+
+// --- Demo ---
+/// Attract-mode state: when to switch next and which theme is showing.
+struct Demo {
+    next_at: Instant,
+    theme: usize,
+}
+
+impl Demo {
+    fn new() -> Self {
+        Self {
+            next_at: Instant::now() + DEMO_INTERVAL,
+            theme: 0,
+        }
+    }
+
+    /// Moves to the next symbol, and to the next theme each time the symbols wrap.
+    fn step(&mut self, bouncer: &mut Bouncer) {
+        // Keep the easter egg out of the showcase
+        bouncer.cycle_symbol();
+        if bouncer.mode == SymbolMode::MiddleFinger {
+            bouncer.cycle_symbol();
+        }
+        if bouncer.mode == SymbolMode::all()[0] {
+            self.theme = (self.theme + 1) % theme::ALL.len();
+        }
+        apply_color(bouncer, ColorChoice::Theme(theme::ALL[self.theme]));
+        self.next_at = Instant::now() + DEMO_INTERVAL;
+    }
+}
+
+// --- Evolution ---
+/// Bounces `--evolve` takes to reach each stage, what it's called and the
+/// symbol it shows; the last stage turns the logo through the rainbow too.
+const STAGES: [(u64, &str, SymbolMode); 4] = [
+    (0, "Snowflake", SymbolMode::SnowflakeSmall),
+    (25, "Large snowflake", SymbolMode::SnowflakeLarge),
+    (100, "NixOS logo", SymbolMode::NixOS),
+    (250, "Rainbow NixOS logo", SymbolMode::NixOS),
+];
+// Degrees the rainbow stage turns the hue each tick
+const RAINBOW_STEP: u32 = 3;
+
+/// How far `--evolve` has brought the symbol, and where the rainbow is.
+struct Evolution {
+    stage: usize,
+    hue: u16,
+}
+
+impl Evolution {
+    const fn new() -> Self {
+        Self { stage: 0, hue: 0 }
+    }
+
+    /// Moves on to the furthest stage the bouncer's bounces have reached,
+    /// returning its name if that's a new one. A symbol that doesn't fit
+    /// yet waits for a bounce where it does.
+    fn grow(&mut self, bouncer: &mut Bouncer) -> Option<&'static str> {
+        let hits = bouncer.stats().bounces;
+        let stage = STAGES.iter().rposition(|&(at, ..)| hits >= at)?;
+        if stage <= self.stage {
+            return None;
+        }
+        let (_, name, mode) = STAGES[stage];
+        bouncer.resize_symbol(mode);
+        if bouncer.mode != mode {
+            return None;
+        }
+        self.stage = stage;
+        Some(name)
+    }
+
+    /// Turns the hue on by `ticks` steps, once the rainbow stage is reached.
+    fn shine(&mut self, bouncer: &mut Bouncer, ticks: u32) {
+        if self.stage + 1 < STAGES.len() || ticks == 0 {
+            return;
+        }
+        let hue = (u32::from(self.hue) + RAINBOW_STEP * ticks) % 360;
+        self.hue = u16::try_from(hue).unwrap_or(0);
+        bouncer.set_color(theme::hue(self.hue));
+    }
+}
+
+// --- Cursor ---
+/// Which way `--cursor` pulls the logo, given mouse reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cursor {
+    /// Draws the logo in, so it swings about the pointer
+    Attract,
+    /// Pushes the logo off, harder the closer it comes
+    Repel,
+}
+
+impl FromStr for Cursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "attract" => Ok(Self::Attract),
+            "repel" => Ok(Self::Repel),
+            _ => Err(format!("`{s}`: expected `attract` or `repel`")),
+        }
+    }
+}
+
+// Cells per tick the pointer draws the logo in by each tick, however far
+const ATTRACTION: f64 = 0.15;
+// How hard the pointer pushes the logo off from right beside it, fading
+// to nothing at the edge of the reach
+const REPULSION: f64 = 1.0;
+const REPULSION_REACH: f64 = 15.0;
+
+impl Cursor {
+    // What `Bouncer::pull` gets from a pointer this far off
+    fn strength(self, distance: f64) -> f64 {
+        match self {
+            Self::Attract => ATTRACTION,
+            Self::Repel => -REPULSION * (1.0 - distance / REPULSION_REACH).max(0.0),
+        }
+    }
+}
+
+// --- App ---
+/// Everything the main loop mutates between frames.
+struct App {
+    options: Options,
+    bouncer: Bouncer,
+    modal: Option<Modal>,
+    cols: u16,
+    rows: u16,
+    fps: FpsCounter,
+    ticker: Option<Ticker>,
+    /// Multiplier on the frame rate, set with `:speed`
+    speed: f64,
+    demo: Option<Demo>,
+    evolution: Option<Evolution>,
+    /// Cell the mouse pointer was last reported over, for `--cursor`
+    pointer: Option<(u16, u16)>,
+    /// When `--shuffle` next picks a symbol
+    next_shuffle: Option<Instant>,
+    /// When `--duration` is up
+    deadline: Option<Instant>,
+    konami: Sequence,
+    versus: Option<Versus>,
+    snake: Option<Snake>,
+    aquarium: Option<Aquarium>,
+    collage: Option<Collage>,
+    /// The `--replay` run, moving the logo in place of physics
+    replay: Option<Replay>,
+    snow: Option<Snowfall>,
+    banner: Option<Banner>,
+    /// New statuses for the `--git` card, as they come in
+    git: Option<Receiver<git::Status>>,
+    /// The `--tail` file
+    tail: Option<Tail>,
+    /// The `--net` rates
+    net: Option<Throughput>,
+    /// The `--disk` gauge
+    disk: Option<Gauge>,
+    #[cfg(feature = "rss")]
+    feed: Option<Feed>,
+    /// The `--price` symbol's quotes
+    #[cfg(feature = "price")]
+    price: Option<Quotes>,
+    /// Nyan cat's trail, laid only while that's the symbol
+    rainbow: Rainbow,
+    /// Forecasts for the snow, as they arrive
+    #[cfg(feature = "weather")]
+    forecast: Option<Receiver<Weather>>,
+    progress: Progress,
+    started: Instant,
+    last_save: Instant,
+    last_update: Instant,
+    /// Achievement unlock message and when it stops showing
+    toast: Option<(Overlay, Instant)>,
+    /// What `--title` last put in the title, and when
+    title: Option<(String, Instant)>,
+    /// Symbol whose colors the palette was last switched to
+    scheme_for: Option<SymbolMode>,
+    /// Daylight level the palette was last built for, from 0 to 1000
+    daylight: Option<u16>,
+    /// Secret rainbow turbo mode, holding the current hue while active
+    turbo: Option<u16>,
+    /// A `--reduced-motion` color change under way: from, to, and since when
+    fade: Option<(Color, Color, Instant)>,
+    #[cfg(feature = "audio")]
+    audio: Option<Audio>,
+    #[cfg(feature = "gamepad")]
+    gamepad: Option<Gamepad>,
+    #[cfg(feature = "midi")]
+    midi: Option<Midi>,
+    fifo: Option<Fifo>,
+    speaker: Speaker,
+    running: bool,
+}
+
+impl App {
+    fn new(options: Options) -> Self {
+        let (cols, rows) = terminal::size().unwrap_or((80, 24));
+        let mut app = Self {
+            demo: options.demo.then(Demo::new),
+            evolution: options.evolve.then(Evolution::new),
+            pointer: None,
+            versus: options.versus.then(|| Versus::new(Rect::default())),
+            snake: options.snake.then(|| Snake::new(Rect::default())),
+            aquarium: options.aquarium.then(|| Aquarium::new(Rect::default())),
+            collage: options
+                .all
+                .then(|| Collage::new(Rect::default(), options.ascii)),
+            replay: None,
+            snow: options
+                .snow
+                .then(|| Snowfall::new(Rect::default(), Weather::CALM)),
+            rainbow: Rainbow::new(Rect::default(), options.ascii),
+            banner: None,
+            git: None,
+            tail: None,
+            net: None,
+            disk: None,
+            #[cfg(feature = "rss")]
+            feed: None,
+            #[cfg(feature = "price")]
+            price: None,
+            #[cfg(feature = "weather")]
+            forecast: options.weather.then(crate::weather::watch),
+            next_shuffle: options.shuffle.map(|every| Instant::now() + every),
+            deadline: options.duration.map(|time| Instant::now() + time),
+            ticker: options
+                .ticker
+                .as_deref()
+                .map(|text| Ticker::new(text, options.ticker_speed)),
+            speed: options.speed.unwrap_or(1.0),
+            options,
+            bouncer: Bouncer::new(),
+            modal: None,
+            cols,
+            rows,
+            fps: FpsCounter::new(),
+            konami: Sequence::new(&KONAMI),
+            progress: Progress::load(),
+            started: Instant::now(),
+            last_save: Instant::now(),
+            last_update: Instant::now(),
+            toast: None,
+            title: None,
+            scheme_for: None,
+            daylight: None,
+            turbo: None,
+            fade: None,
+            #[cfg(feature = "audio")]
+            audio: None,
+            #[cfg(feature = "gamepad")]
+            gamepad: None,
+            #[cfg(feature = "midi")]
+            midi: None,
+            fifo: None,
+            speaker: Speaker::Silent,
+            running: true,
+        };
+        if app.versus.is_some() || app.snake.is_some() {
+            // Big logos would make keep-away far too easy, and fill a snake's screen at once
+            app.bouncer.mode = SymbolMode::SnowflakeSmall;
+        }
+        if app.evolution.is_some() {
+            app.bouncer.mode = STAGES[0].2;
+        }
+        app.set_up_colors();
+        if let Some(mode) = app.options.symbol {
+            app.bouncer.mode = mode;
+            if let Some(color) = mode.color() {
+                app.bouncer.set_color(color);
+            }
+        }
+        if let Some(color) = app.options.color {
+            app.bouncer.set_color(color);
+        }
+        if app.options.banner || app.options.text.is_some() {
+            app.bouncer.mode = SymbolMode::Banner;
+        }
+        app.set_up_drawing();
+        app.set_up_accessibility();
+        app.follow_symbol();
+        app.set_up_motion();
+        if app.options.resume {
+            app.resume();
+        }
+        app.set_up_banner();
+        app.resize(cols, rows);
+        // After resizing, so percentages are of the real play area
+        if let Some(at) = app.options.start_pos {
+            app.bouncer.place(at);
+        }
+        if let Some(Direction { dx, dy }) = app.options.direction {
+            app.bouncer.set_velocity(dx, dy);
+        }
+        app
+    }
+
+    // Carries on from the last session saved, if there is one
+    fn resume(&mut self) {
+        if let Some(session) = Session::load() {
+            session.restore(&mut self.bouncer);
+            // The palette it saved wins over the symbol's
+            self.scheme_for = Some(self.bouncer.mode);
+            self.speed = session.speed;
+            // Those bounces already went into the lifetime totals
+            self.progress.already_counted(session.stats);
+        }
+    }
+
+    // How the logo is drawn, apart from its colors
+    fn set_up_drawing(&mut self) {
+        // Leaked once at startup, as every frame draws it
+        let link = self.options.link.clone();
+        self.bouncer
+            .set_link(link.map(|url| &*Box::leak(url.into_boxed_str())));
+        self.bouncer.set_ascii(self.options.ascii);
+        self.bouncer.set_transparent(self.options.transparent);
+        self.bouncer.set_spin(self.options.spin);
+        // The games want a logo that stays one size
+        self.bouncer
+            .set_pulse(self.options.pulse && self.versus.is_none() && self.snake.is_none());
+        self.bouncer.set_morph(self.options.morph);
+        if let Some(collage) = &mut self.collage {
+            collage.set_spread(self.options.spread);
+        }
+    }
+
+    // How fast and along what path the logo moves
+    fn set_up_motion(&mut self) {
+        let (x, y) = self.options.axis_speed;
+        self.bouncer.set_axis_speed(x, y);
+        self.bouncer.set_restitution(self.options.restitution);
+        if let Some(range) = self.options.random_speed {
+            self.bouncer.randomize_axis_speed(range);
+        }
+        if let Some(figure) = self.options.lissajous {
+            self.bouncer.set_motion(Some(Box::new(figure)));
+        }
+        if let Some(orbit) = self.options.orbit {
+            self.bouncer.set_motion(Some(Box::new(orbit)));
+        }
+    }
+
+    // The theme, then the config's own colors over it
+    fn set_up_colors(&mut self) {
+        if let Some(theme) = self.options.theme {
+            apply_color(&mut self.bouncer, ColorChoice::Theme(theme));
+        }
+        if let Some(&first) = self.options.colors.first() {
+            self.bouncer.set_palette(&self.options.colors);
+            self.bouncer.set_color(first);
+        }
+        self.bouncer.set_in_order(self.options.in_order);
+        self.bouncer.set_gradient(self.options.gradient);
+    }
+
+    fn set_up_accessibility(&mut self) {
+        // Flipping to reverse video and back is a flash of the whole logo
+        self.bouncer
+            .set_monochrome(self.options.no_color && !self.options.reduced_motion);
+        if self.options.reduced_motion {
+            let (min, _) = SPEED_RANGE;
+            self.speed = (self.speed * REDUCED_MOTION).max(min);
+        }
+        if self.options.high_contrast {
+            self.use_high_contrast();
+        }
+    }
+
+    // White is the brightest there is on black, so bounces no longer change
+    // the color; the small snowflake is a single cell, too easy to lose
+    fn use_high_contrast(&mut self) {
+        self.bouncer.set_palette(&[Color::White]);
+        self.bouncer.set_color(Color::White);
+        self.bouncer.set_bold(true);
+        self.bouncer.set_gradient(None);
+        if self.bouncer.mode == SymbolMode::SnowflakeSmall
+            && self.versus.is_none()
+            && self.snake.is_none()
+        {
+            self.bouncer.mode = SymbolMode::SnowflakeLarge;
+        }
+    }
+
+    // Gives banner-like modes their art: also when resuming a banner run,
+    // since the art isn't saved
+    fn set_up_banner(&mut self) {
+        if let Some(text) = &self.options.text {
+            self.bouncer.mode = SymbolMode::Banner;
+            let width = usize::from(self.options.text_width);
+            self.bouncer.set_banner(banner::wrap(text, width));
+        } else if self.options.greeting
+            || self.options.calendar
+            || self.options.neofetch
+            || self.options.moon
+            || self.bouncer.mode == SymbolMode::Banner
+        {
+            self.bouncer.mode = SymbolMode::Banner;
+            let banner = if self.options.greeting {
+                Banner::greeting(self.options.ascii)
+            } else if self.options.calendar {
+                Banner::calendar()
+            } else if self.options.neofetch {
+                if let Some(color) = system::logo().color() {
+                    self.bouncer.set_color(color);
+                }
+                Banner::system(self.options.ascii)
+            } else if self.options.moon {
+                Banner::moon(self.options.ascii)
+            } else {
+                Banner::new()
+            };
+            self.bouncer.set_banner(banner.art());
+            self.bouncer.set_accent(banner.accent());
+            self.banner = Some(banner);
+        }
+    }
+
+    // Swaps the symbol for a live card, kept up to date from then on
+    fn start_card(&mut self, card: Card) {
+        match card {
+            Card::Git(status) => self.start_git(&status),
+            Card::Tail(tail) => self.start_tail(tail),
+            Card::Net(net) => self.start_net(net),
+            Card::Disk(gauge) => self.start_disk(gauge),
+            #[cfg(feature = "rss")]
+            Card::Feed(url) => self.start_feed(url),
+            #[cfg(feature = "price")]
+            Card::Price(symbol) => self.start_price(symbol),
+        }
+    }
+
+    // Swaps the symbol for a card of the git status, kept up to date
+    fn start_git(&mut self, status: &git::Status) {
+        self.show_card(status.card(self.options.ascii));
+        self.git = Some(git::watch(status.clone()));
+    }
+
+    // Swaps the symbol for a gauge of how full a disk is, in its color
+    fn start_disk(&mut self, gauge: Gauge) {
+        self.show_card(gauge.card(self.options.ascii));
+        self.show_gauge_color(gauge.color());
+        self.disk = Some(gauge);
+    }
+
+    // Wall hits can't change the color, only how full the disk is
+    fn show_gauge_color(&mut self, color: Color) {
+        self.bouncer.set_palette(&[color]);
+        self.bouncer.set_color(color);
+    }
+
+    // Swaps the symbol for the network rates, sampled every second
+    fn start_net(&mut self, net: Throughput) {
+        self.show_card(net.card(self.options.ascii));
+        self.net = Some(net);
+    }
+
+    // Swaps the symbol for the last line of a file, kept up to date
+    fn start_tail(&mut self, tail: Tail) {
+        self.tail = Some(tail);
+        self.show_tail();
+    }
+
+    fn show_tail(&mut self) {
+        let Some(tail) = &self.tail else {
+            return;
+        };
+        let line = match tail.line() {
+            "" => "waiting for a line...".to_string(),
+            line => line.to_string(),
+        };
+        self.show_text(&line);
+    }
+
+    // Swaps the symbol for a feed's headlines, one at a time
+    #[cfg(feature = "rss")]
+    fn start_feed(&mut self, url: String) {
+        let feed = Feed::watch(url, self.options.rss_every);
+        self.show_text(feed.text());
+        self.feed = Some(feed);
+    }
+
+    // Swaps the symbol for a price, colored by how it's moved
+    #[cfg(feature = "price")]
+    fn start_price(&mut self, symbol: String) {
+        let quotes = Quotes::watch(symbol);
+        self.show_text(&quotes.text());
+        self.price = Some(quotes);
+    }
+
+    // Bounces `text` in place of the symbol, wrapped like `--text`
+    fn show_text(&mut self, text: &str) {
+        let width = usize::from(self.options.text_width);
+        self.show_card(banner::wrap(text, width));
+    }
+
+    // Bounces `art` in place of the symbol, sized to fit whatever it is
+    fn show_card(&mut self, art: impl Into<Lines>) {
+        self.bouncer.mode = SymbolMode::Banner;
+        self.bouncer.set_accent(None);
+        // Not a resumed banner run's `user@hostname` any more
+        self.banner = None;
+        self.bouncer.set_banner(art);
+        self.resize(self.cols, self.rows);
+    }
+
+    // Plays `replay` from its first tick, with the symbol and play area it
+    // was recorded with; the area has to fit on this screen
+    fn start_replay(&mut self, mut replay: Replay) -> io::Result<()> {
+        let area = replay.area();
+        let screen = Rect::new(0, 0, self.cols, self.rows);
+        if area.clip(screen) != area {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "the recording's play area doesn't fit in a {}x{} terminal",
+                    self.cols, self.rows
+                ),
+            ));
+        }
+        if let Some(mode) = replay.symbol() {
+            self.bouncer.set_mode(mode);
+        }
+        self.bouncer.set_area(area);
+        if let Some(start) = replay.next() {
+            let (x, y) = start.position;
+            let (dx, dy) = start.velocity;
+            self.bouncer.set_position(x, y);
+            self.bouncer.set_velocity(dx, dy);
+        }
+        self.replay = Some(replay);
+        Ok(())
+    }
+
+    // Rows taken away from the play area by the status bar and ticker
+    const fn reserved_rows(&self) -> u16 {
+        let status = if self.options.status { 1 } else { 0 };
+        let ticker = if self.ticker.is_some() { 1 } else { 0 };
+        status + ticker
+    }
+
+    // The play area before the border: the screen minus the status bar,
+    // cut down to `--area` and shrunk by `--margin`
+    fn frame(&self) -> Rect {
+        let screen = Rect::new(
+            0,
+            0,
+            self.cols,
+            self.rows.saturating_sub(self.reserved_rows()),
+        );
+        self.options
+            .area
+            .map_or(screen, |area| area.clip(screen))
+            .inset(self.options.margin)
+    }
+
+    fn resize(&mut self, cols: u16, rows: u16) {
+        (self.cols, self.rows) = (cols, rows);
+        let frame = self.frame();
+        // Inside the border, if there is one
+        let inner = if self.options.border {
+            frame.inset(1)
+        } else {
+            frame
+        };
+        if let Some(replay) = &self.replay {
+            // Where it was recorded, whatever the screen is now
+            self.bouncer.set_area(replay.area());
+        } else if let Some(game) = &mut self.versus {
+            game.resize(inner);
+            self.bouncer.set_area(game.ball_area());
+        } else if self.options.border {
+            self.bouncer.set_area(frame.inset(1));
+        } else {
+            // Like `Bouncer::resize`, leave the frame's last column and row free
+            self.bouncer.set_area(Rect {
+                width: frame.width.saturating_sub(1),
+                height: frame.height.saturating_sub(1),
+                ..frame
+            });
+        }
+        if let Some(game) = &mut self.snake {
+            game.resize(inner);
+        }
+        if let Some(aquarium) = &mut self.aquarium {
+            aquarium.resize(inner);
+        }
+        if let Some(collage) = &mut self.collage {
+            collage.resize(inner);
+        }
+        if let Some(snow) = &mut self.snow {
+            snow.resize(inner);
+        }
+        self.rainbow.resize(inner);
+    }
+
+    // Wipes the screen, so anything drawn incrementally must be repainted
+    fn clear(&mut self, w: &mut impl Write) -> io::Result<()> {
+        if let Some(game) = &mut self.snake {
+            game.redraw();
+        }
+        if let Some(aquarium) = &mut self.aquarium {
+            aquarium.redraw();
+        }
+        if let Some(collage) = &mut self.collage {
+            collage.redraw();
+        }
+        if let Some(snow) = &mut self.snow {
+            snow.redraw();
+        }
+        self.rainbow.redraw();
+        self.bouncer.redraw();
+        Overlay::clear(w)
+    }
+
+    // Picks up again after being suspended: the terminal may have been
+    // resized or drawn over while stopped, and the clock kept running
+    fn resumed(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let (cols, rows) = terminal::size()?;
+        self.resize(cols, rows);
+        self.last_update = Instant::now();
+        self.clear(w)
+    }
+
+    // Tall enough for a menu to fit inside its border
+    fn menu_height(&self) -> usize {
+        usize::from(self.rows.saturating_sub(4))
+    }
+
+    const fn paused(&self) -> bool {
+        matches!(self.modal, Some(Modal::Help(_) | Modal::GameOver(_)))
+    }
+
+    // Time between frames: one tick, so the games see every move
+    fn frame_time(&self) -> Duration {
+        Bouncer::TICK.div_f64(self.rate())
+    }
+
+    // How many times faster than normal the animation runs
+    fn rate(&self) -> f64 {
+        let turbo = if self.turbo.is_some() { TURBO } else { 1.0 };
+        // Louder music runs faster, up to three times as fast at full volume
+        #[cfg(feature = "audio")]
+        let loudness = self
+            .audio
+            .as_ref()
+            .map_or(1.0, |audio| f64::from(audio.level()).mul_add(2.0, 1.0));
+        #[cfg(not(feature = "audio"))]
+        let loudness = 1.0;
+        #[cfg(feature = "gamepad")]
+        let throttle = self.gamepad.as_ref().map_or(1.0, Gamepad::throttle);
+        #[cfg(not(feature = "gamepad"))]
+        let throttle = 1.0;
+        self.speed * turbo * loudness * throttle
+    }
+
+    // Ages Nyan cat's rainbow, and lays more over wherever the cat moved off
+    fn lay_rainbow(&mut self, (x, y): (u16, u16), ticks: u32) {
+        self.rainbow.update(ticks);
+        // No gradients in high contrast
+        if self.bouncer.mode == SymbolMode::Nyan && ticks > 0 && !self.options.high_contrast {
+            let (width, height) = self.bouncer.logo_size();
+            let (nx, ny) = self.bouncer.position();
+            self.rainbow.lay(
+                Rect::new(x, y, width, height),
+                Rect::new(nx, ny, width, height),
+            );
+        }
+    }
+
+    // Acts on what controllers and the `--fifo` sent since the last update
+    fn take_input(&mut self, w: &mut impl Write) -> io::Result<()> {
+        #[cfg(feature = "gamepad")]
+        if let Some(pad) = &mut self.gamepad {
+            // Menus and overlays are keyboard-only, so presses behind them are dropped
+            for action in pad.actions() {
+                if self.modal.is_none() {
+                    self.handle_action(action, w)?;
+                }
+            }
+        }
+        #[cfg(feature = "midi")]
+        if let Some(midi) = &self.midi {
+            let controls: Vec<Control> = midi.controls().collect();
+            for control in controls {
+                match control {
+                    Control::Speed(speed) => self.speed = speed,
+                    Control::Hue(degrees) => self.bouncer.set_color(theme::hue(degrees)),
+                    Control::Symbol(mode) => self.set_symbol(mode, w)?,
+                }
+            }
+        }
+        // Commands wait behind menus and overlays, as they would for the prompt
+        if self.modal.is_some() {
+            return Ok(());
+        }
+        let sent: Vec<_> = self.fifo.iter().flat_map(Fifo::commands).collect();
+        for command in sent {
+            match command {
+                Ok(command) => self.execute(command, w)?,
+                Err(e) => {
+                    let toast = Overlay::new("Bad command", vec![e]);
+                    self.toast = Some((toast, Instant::now() + TOAST_TIME));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Advances physics by the time since the last update, and any timed changes.
+    fn update(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let now = Instant::now();
+        let dt = now - self.last_update;
+        self.last_update = now;
+        // Even behind the help overlay or a game over; a terminal that hung
+        // up leaves nothing to draw on, but the progress still gets saved
+        if self.deadline.is_some_and(|at| now >= at) || hung_up() {
+            self.running = false;
+            return Ok(());
+        }
+        if reload_asked() {
+            self.reload_config(w)?;
+        }
+        self.take_input(w)?;
+        // Everything stands still behind the help overlay
+        if self.paused() {
+            return Ok(());
+        }
+        let rate = self.rate();
+        if let Some(aquarium) = &mut self.aquarium {
+            aquarium.step(dt.mul_f64(rate));
+            return Ok(());
+        }
+        if let Some(collage) = &mut self.collage {
+            if collage.step(dt.mul_f64(rate)) > 0 {
+                self.speaker.play(w)?;
+            }
+            return Ok(());
+        }
+        self.switch_symbols(w)?;
+        let before = self.bouncer.velocity();
+        let before_position = self.bouncer.position();
+        let bounces = self.bouncer.stats().bounces;
+        let color = self.bouncer.color();
+        let ticks = match &mut self.replay {
+            Some(replay) => self.bouncer.step_along(dt.mul_f64(rate), replay),
+            None => self.bouncer.step(dt.mul_f64(rate)),
+        };
+        if self.replay.as_ref().is_some_and(Replay::done) {
+            self.running = false;
+        }
+        self.lay_rainbow(before_position, ticks);
+        self.follow_pointer(ticks);
+        #[cfg(feature = "gamepad")]
+        if let Some(pad) = &self.gamepad {
+            // A held stick moves one cell a tick, like holding a nudge key
+            let (dx, dy) = pad.stick();
+            for _ in 0..ticks {
+                self.bouncer.nudge(dx, dy);
+            }
+        }
+        if self.bouncer.stats().bounces > bounces {
+            self.speaker.play(w)?;
+            if self.options.reduced_motion {
+                self.fade = Some((color, self.bouncer.color(), now));
+                self.bouncer.set_color(color);
+            }
+        }
+        self.fade_color(now);
+        if let Some(game) = &mut self.versus {
+            game.referee(&mut self.bouncer, before);
+        }
+        self.referee_snake(before_position);
+        #[cfg(feature = "audio")]
+        if self.audio.as_mut().is_some_and(Audio::beat) && !self.options.reduced_motion {
+            self.bouncer.cycle_color();
+        }
+        self.follow_daylight();
+        self.evolve(ticks);
+        self.follow_symbol();
+        #[cfg(feature = "weather")]
+        if let (Some(snow), Some(forecast)) = (&mut self.snow, &self.forecast)
+            && let Some(weather) = forecast.try_iter().last()
+        {
+            snow.set_weather(weather);
+        }
+        if let Some(snow) = &mut self.snow {
+            snow.update();
+        }
+        self.refresh_banner();
+        if let Some(hue) = &mut self.turbo
+            && ticks > 0
+        {
+            *hue = (*hue + 15) % 360;
+            self.bouncer.set_color(theme::hue(*hue));
+        }
+        self.track_achievements(w)
+    }
+
+    // Bends the logo's path toward or away from the mouse pointer for
+    // `ticks` ticks, once it's been seen
+    fn follow_pointer(&mut self, ticks: u32) {
+        let (Some(cursor), Some((col, row))) = (self.options.cursor, self.pointer) else {
+            return;
+        };
+        let at = (f64::from(col) + 0.5, f64::from(row) + 0.5);
+        for _ in 0..ticks {
+            let (x, y) = self.bouncer.center();
+            let strength = cursor.strength((at.0 - x).hypot(at.1 - y));
+            self.bouncer.pull(at, strength);
+        }
+    }
+
+    // Moves `--evolve` on at its milestones, saying so, and keeps the
+    // rainbow turning once it's there
+    fn evolve(&mut self, ticks: u32) {
+        let Some(evolution) = &mut self.evolution else {
+            return;
+        };
+        if let Some(name) = evolution.grow(&mut self.bouncer) {
+            let bounces = format!("{} bounces", self.bouncer.stats().bounces);
+            let toast = Overlay::new("Evolved", vec![name.to_string(), bounces]);
+            self.toast = Some((toast, Instant::now() + TOAST_TIME));
+        }
+        // A turning rainbow is as much motion as turbo mode's
+        if !self.options.reduced_motion {
+            evolution.shine(&mut self.bouncer, ticks);
+        }
+    }
+
+    // Ends the game once the snake crosses its trail
+    fn referee_snake(&mut self, before_position: (u16, u16)) {
+        if let Some(game) = &mut self.snake
+            && game.step(&self.bouncer, before_position)
+        {
+            let score = format!("Score: {}", game.score());
+            let lines = vec![
+                score,
+                String::new(),
+                "Press any key to play again".to_string(),
+            ];
+            self.modal = Some(Modal::GameOver(Overlay::new("Game over", lines)));
+        }
+    }
+
+    // Moves a `--reduced-motion` color change along
+    fn fade_color(&mut self, now: Instant) {
+        let Some((from, to, since)) = self.fade else {
+            return;
+        };
+        let elapsed = now - since;
+        if elapsed >= FADE_TIME {
+            self.bouncer.set_color(to);
+            self.fade = None;
+            return;
+        }
+        let amount = elapsed.as_millis() * 1000 / FADE_TIME.as_millis();
+        self.bouncer.set_color(theme::blend(
+            from,
+            to,
+            u16::try_from(amount).unwrap_or(1000),
+        ));
+    }
+
+    // Brings the banner's art up to date with whatever it shows
+    fn refresh_banner(&mut self) {
+        if let Some(banner) = &mut self.banner
+            && banner.refresh()
+        {
+            self.bouncer.set_banner(banner.art());
+            self.bouncer.set_accent(banner.accent());
+            // The new name may be a different size
+            self.resize(self.cols, self.rows);
+        }
+        if let Some(statuses) = &self.git
+            && let Some(status) = statuses.try_iter().last()
+        {
+            self.bouncer.set_banner(status.card(self.options.ascii));
+            self.resize(self.cols, self.rows);
+        }
+        if let Some(net) = &mut self.net
+            && net.refresh()
+        {
+            // Always the same size, so there's no need to resize
+            self.bouncer.set_banner(net.card(self.options.ascii));
+        }
+        if let Some(gauge) = &mut self.disk
+            && gauge.refresh()
+        {
+            let (card, color) = (gauge.card(self.options.ascii), gauge.color());
+            self.bouncer.set_banner(card);
+            self.show_gauge_color(color);
+            // The sizes may have gained a digit
+            self.resize(self.cols, self.rows);
+        }
+        if self.tail.as_mut().is_some_and(Tail::refresh) {
+            self.show_tail();
+        }
+        #[cfg(feature = "rss")]
+        if let Some(feed) = &mut self.feed
+            && feed.update(self.bouncer.stats().bounces)
+        {
+            let headline = feed.text().to_string();
+            self.show_text(&headline);
+        }
+        #[cfg(feature = "price")]
+        if let Some(quotes) = &mut self.price
+            && quotes.update()
+        {
+            let (text, color) = (quotes.text(), quotes.color());
+            self.show_text(&text);
+            // Wall hits can't change it back
+            if let Some(color) = color {
+                self.bouncer.set_palette(&[color]);
+                self.bouncer.set_color(color);
+            }
+        }
+    }
+
+    // Moves on to the next symbol when `--demo` or `--shuffle` says it's time
+    fn switch_symbols(&mut self, w: &mut impl Write) -> io::Result<()> {
+        if let Some(demo) = &mut self.demo
+            && Instant::now() >= demo.next_at
+        {
+            demo.step(&mut self.bouncer);
+            // The new symbol may be a different size
+            self.clear(w)?;
+        }
+        if let (Some(at), Some(every)) = (self.next_shuffle, self.options.shuffle)
+            && Instant::now() >= at
+        {
+            self.bouncer.shuffle_symbol();
+            self.next_shuffle = Some(Instant::now() + every);
+            self.clear(w)?;
+        }
+        Ok(())
+    }
+
+    // Reads the config file again and applies what changed in it, leaving
+    // the counters and anything the file doesn't mention alone. A file
+    // that no longer loads changes nothing, and says why
+    fn reload_config(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let path = self.options.config_path.clone();
+        let toast = match Config::load(path.as_deref()).and_then(|c| self.apply_config(c)) {
+            Ok(()) => {
+                let path = path.or_else(config::default_path);
+                let lines = path.iter().map(|p| p.display().to_string()).collect();
+                Overlay::new("Config reloaded", lines)
+            }
+            Err(e) => Overlay::new("Config not reloaded", vec![e.to_string()]),
+        };
+        self.toast = Some((toast, Instant::now() + TOAST_TIME));
+        self.clear(w)
+    }
+
+    fn apply_config(&mut self, config: Config) -> io::Result<()> {
+        // Everything that can fail goes first, so it's all or nothing
+        let keymap = config.keymap()?;
+        let theme = config.theme()?;
+        let colors = config.colors()?;
+        let color = config.color()?;
+        let gradient = config.gradient()?;
+        let old = mem::replace(&mut self.options.config, config);
+        let new = &self.options.config;
+        self.options.keymap = keymap;
+        if let Some(theme) = theme
+            && new.theme != old.theme
+        {
+            apply_color(&mut self.bouncer, ColorChoice::Theme(theme));
+        }
+        if let Some(color) = color
+            && new.color != old.color
+        {
+            self.bouncer.set_color(color);
+        }
+        if new.gradient != old.gradient && !self.options.high_contrast {
+            self.bouncer.set_gradient(gradient);
+        }
+        if new.cycle != old.cycle {
+            // Without colors of its own it goes back to the theme's
+            if let Some(&first) = colors.first() {
+                self.bouncer.set_palette(&colors);
+                self.bouncer.set_color(first);
+            } else {
+                let theme = theme.or(self.options.theme).unwrap_or(theme::DEFAULT);
+                apply_color(&mut self.bouncer, ColorChoice::Theme(theme));
+            }
+            self.bouncer
+                .set_in_order(new.cycle.as_ref().is_some_and(|c| c.in_order));
+        }
+        if let Some(mode) = new.symbol
+            && new.symbol != old.symbol
+        {
+            self.bouncer.set_mode(mode);
+            if let Some(color) = mode.color() {
+                self.bouncer.set_color(color);
+            }
+        }
+        if let Some(speed) = new.speed
+            && new.speed != old.speed
+        {
+            self.speed = speed;
+        }
+        if let Some(snow) = new.snow
+            && new.snow != old.snow
+        {
+            self.snow = snow.then(|| Snowfall::new(Rect::default(), Weather::CALM));
+        }
+        // Places new snow, and makes room for a symbol of a different size
+        self.resize(self.cols, self.rows);
+        Ok(())
+    }
+
+    // Switches to the colors that go with the symbol whenever it changes,
+    // unless colors were picked some other way
+    fn follow_symbol(&mut self) {
+        let mode = self.bouncer.mode;
+        if !self.uses_schemes() || self.scheme_for == Some(mode) {
+            return;
+        }
+        self.scheme_for = Some(mode);
+        if let Some(scheme) = mode.scheme() {
+            apply_color(&mut self.bouncer, ColorChoice::Theme(scheme));
+        } else {
+            self.bouncer.set_palette(theme::DEFAULT.colors);
+            if let Some(color) = mode.color() {
+                self.bouncer.set_color(color);
+            }
+        }
+    }
+
+    // Any theme or color setting, time of day or accessibility mode wins
+    // over the symbols' own colors, and the demo brings its own themes
+    const fn uses_schemes(&self) -> bool {
+        let options = &self.options;
+        let config = &options.config;
+        options.theme.is_none()
+            && options.colors.is_empty()
+            && options.color.is_none()
+            && config.theme.is_none()
+            && config.cycle.is_none()
+            && config.color.is_none()
+            && !options.high_contrast
+            && !options.day_night
+            && !options.night
+            && !options.demo
+    }
+
+    // Rebuilds the palette whenever the daylight level moves
+    fn follow_daylight(&mut self) {
+        // Dimming is the last thing high contrast wants
+        let level = if self.options.high_contrast {
+            return;
+        } else if self.options.night {
+            0
+        } else if self.options.day_night {
+            let now = chrono::Local::now();
+            let minute = now.hour() * 60 + now.minute();
+            theme::daylight(u16::try_from(minute).unwrap_or(0))
+        } else {
+            return;
+        };
+        if self.daylight == Some(level) {
+            return;
+        }
+        self.daylight = Some(level);
+
+        // Keep the same hue through the change
+        let depth = ColorDepth::detect();
+        let palette: Vec<Color> = theme::sky(level)
+            .into_iter()
+            .map(|c| depth.fit(c))
+            .collect();
+        let index = self
+            .bouncer
+            .palette()
+            .iter()
+            .position(|&c| c == self.bouncer.color());
+        self.bouncer
+            .set_color(index.map_or(palette[0], |i| palette[i % palette.len()]));
+        self.bouncer.set_palette(&palette);
+    }
+
+    fn track_achievements(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let unlocked = self
+            .progress
+            .record(self.bouncer.stats(), self.started.elapsed());
+        if !unlocked.is_empty() {
+            let lines = unlocked.iter().map(|a| a.title().to_string()).collect();
+            let toast = Overlay::new("Achievement unlocked", lines);
+            self.toast = Some((toast, Instant::now() + TOAST_TIME));
+            // Save straight away so an unlock is never lost
+            self.save_progress();
+        } else if self.last_save.elapsed() >= SAVE_INTERVAL {
+            self.save_progress();
+        }
+
+        if self
+            .toast
+            .as_ref()
+            .is_some_and(|(_, until)| Instant::now() >= *until)
+        {
+            self.toast = None;
+            self.clear(w)?;
+        }
+        Ok(())
+    }
+
+    // Best effort: a read-only home shouldn't stop the animation
+    fn save_progress(&mut self) {
+        let _ = self.progress.save();
+        self.last_save = Instant::now();
+    }
+
+    // Also best effort, and only on quit
+    fn save_session(&self) {
+        if self.options.resume {
+            let _ = Session::capture(&self.bouncer, self.speed).save();
+        }
+    }
+
+    fn draw(&mut self, w: &mut impl Write) -> io::Result<()> {
+        self.draw_title(w)?;
+        if self.options.border {
+            ui::draw_border(w, self.frame(), self.options.ascii)?;
+        }
+        // Behind everything else, and kept off both the logo's old and new spots
+        let (x, y) = self.bouncer.position();
+        let (width, height) = self.bouncer.logo_size();
+        let logo = Rect::new(x, y, width, height);
+        let covered = [self.bouncer.drawn_rect().unwrap_or(logo), logo];
+        if let Some(aquarium) = &mut self.aquarium {
+            aquarium.draw(w)?;
+        } else if let Some(collage) = &mut self.collage {
+            collage.draw(w)?;
+        } else {
+            if let Some(snow) = &mut self.snow {
+                snow.draw(w, &covered)?;
+            }
+            self.rainbow.draw(w, &covered)?;
+            self.bouncer.draw(w)?;
+        }
+        if let Some(game) = &self.versus {
+            game.draw(w)?;
+        }
+        if let Some(game) = &mut self.snake {
+            game.draw(w)?;
+        }
+        self.fps.tick();
+        if self.options.status {
+            let row = self.rows.saturating_sub(1);
+            ui::draw_status_bar(
+                w,
+                &self.bouncer,
+                &self.options.keymap,
+                self.speed,
+                self.fps.fps(),
+                self.cols,
+                row,
+            )?;
+        }
+        let (cols, rows, ascii) = (self.cols, self.rows, self.options.ascii);
+        // Just above the status bar
+        if let Some(ticker) = &self.ticker {
+            let row = rows.saturating_sub(self.reserved_rows());
+            ticker.draw(w, cols, row)?;
+        }
+        if let Some((toast, _)) = &self.toast {
+            toast.draw_top(w, cols, rows, ascii)?;
+        }
+        match &self.modal {
+            Some(Modal::Help(o) | Modal::GameOver(o)) => o.draw(w, cols, rows, ascii),
+            Some(Modal::Symbols { menu, .. }) => menu.overlay().draw(w, cols, rows, ascii),
+            Some(Modal::Colors { picker, .. }) => picker.menu.overlay().draw(w, cols, rows, ascii),
+            Some(Modal::Rgb { input, .. }) => input.overlay().draw(w, cols, rows, ascii),
+            Some(Modal::Command { error: Some(e), .. }) => {
+                ui::draw_error(w, e, cols, rows.saturating_sub(1))
+            }
+            Some(Modal::Command { input, .. }) => input.draw_line(w, cols, rows.saturating_sub(1)),
+            None => Ok(()),
+        }?;
+        // Everything above was only queued: the whole frame goes out at once
+        w.flush()
+    }
+
+    // Shows the counts in the title, for when the tab is in the background.
+    // They're looked at once every `TITLE_INTERVAL`, and only written when
+    // they changed
+    fn draw_title(&mut self, w: &mut impl Write) -> io::Result<()> {
+        if !self.options.title
+            || self
+                .title
+                .as_ref()
+                .is_some_and(|(_, at)| at.elapsed() < TITLE_INTERVAL)
+        {
+            return Ok(());
+        }
+        let Stats { bounces, corners } = self.bouncer.stats();
+        let plural = |n: u64| if n == 1 { "" } else { "s" };
+        let title = format!(
+            "snowflake-bounce — {bounces} bounce{}, {corners} corner{}",
+            plural(bounces),
+            plural(corners)
+        );
+        if self.title.as_ref().is_none_or(|(shown, _)| *shown != title) {
+            queue!(w, terminal::SetTitle(&title))?;
+        }
+        self.title = Some((title, Instant::now()));
+        Ok(())
+    }
+
+    fn handle_event(&mut self, event: &Event, w: &mut impl Write) -> io::Result<()> {
+        match event {
+            // A screensaver goes away as soon as anyone touches anything
+            Event::Key(_) | Event::Mouse(_) if self.options.screensaver => self.running = false,
+            // Any key dismisses the help overlay, or starts a new snake run
+            Event::Key(_) if self.paused() => {
+                if let (Some(Modal::GameOver(_)), Some(game)) = (&self.modal, &mut self.snake) {
+                    game.reset();
+                }
+                self.modal = None;
+                self.clear(w)?;
+            }
+            Event::Key(KeyEvent { code, .. }) if self.modal.is_some() => {
+                self.handle_modal_key(*code, w)?;
+            }
+            // The final key of the code is swallowed rather than acted on
+            // Turbo mode cycles colors far too quickly for reduced motion
+            Event::Key(key) if self.konami.feed(key.code) && !self.options.reduced_motion => {
+                self.turbo = match self.turbo {
+                    Some(_) => {
+                        self.bouncer.cycle_color();
+                        None
+                    }
+                    None => Some(0),
+                };
+            }
+            // Paddle keys win over the keymap while a game is on
+            Event::Key(key)
+                if self
+                    .versus
+                    .as_mut()
+                    .is_some_and(|game| game.handle_key(key.code)) => {}
+            Event::Key(key) => {
+                if let Some(action) = self.options.keymap.action(*key) {
+                    self.handle_action(action, w)?;
+                }
+            }
+            Event::Mouse(mouse) => self.pointer = Some((mouse.column, mouse.row)),
+            Event::Resize(cols, rows) => {
+                self.resize(*cols, *rows);
+                self.clear(w)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_action(&mut self, action: Action, w: &mut impl Write) -> io::Result<()> {
+        match action {
+            Action::Quit => self.running = false,
+            Action::ReloadConfig => RELOAD.store(true, Ordering::Relaxed),
+            Action::CycleColor => self.bouncer.cycle_color(),
+            Action::CycleSymbol => self.set_symbol(self.bouncer.mode.next(), w)?,
+            Action::CycleSymbolBack => self.set_symbol(self.bouncer.mode.prev(), w)?,
+            Action::MiddleFinger => self.set_symbol(SymbolMode::MiddleFinger, w)?,
+            Action::Arch => self.set_symbol(SymbolMode::Arch, w)?,
+            Action::NudgeLeft => self.bouncer.nudge(-1, 0),
+            Action::NudgeDown => self.bouncer.nudge(0, 1),
+            Action::NudgeUp => self.bouncer.nudge(0, -1),
+            Action::NudgeRight => self.bouncer.nudge(1, 0),
+            Action::PushLeft => self.bouncer.bias_velocity(-1, 0),
+            Action::PushDown => self.bouncer.bias_velocity(0, 1),
+            Action::PushUp => self.bouncer.bias_velocity(0, -1),
+            Action::PushRight => self.bouncer.bias_velocity(1, 0),
+            Action::PickSymbol => {
+                self.modal = Some(Modal::Symbols {
+                    menu: ui::symbol_picker(&self.bouncer, self.menu_height()),
+                    original: self.bouncer.mode,
+                });
+            }
+            Action::PickColor => {
+                self.modal = Some(Modal::Colors {
+                    picker: ColorPicker::new(self.menu_height()),
+                    original: (self.bouncer.color(), self.bouncer.palette().to_vec()),
+                });
+            }
+            Action::Help => {
+                let help = ui::help(&self.bouncer, &self.options.keymap);
+                self.modal = Some(Modal::Help(help));
+            }
+            Action::CommandPrompt => {
+                self.modal = Some(Modal::Command {
+                    input: Input::new(":"),
+                    error: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, command: Command, w: &mut impl Write) -> io::Result<()> {
+        match command {
+            Command::Action(action) => self.handle_action(action, w)?,
+            Command::Symbol(mode) => self.set_symbol(mode, w)?,
+            Command::Theme(t) => apply_color(&mut self.bouncer, ColorChoice::Theme(t)),
+            Command::Color(c) => self.bouncer.set_color(c),
+            Command::Speed(speed) => self.speed = speed,
+            Command::Seed(seed) => crate::seed(seed),
+        }
+        Ok(())
+    }
+
+    // Switches to `mode`, fitting the logo back inside its area since
+    // symbols differ in size, and starts the next frame clean. A morph
+    // erases the old symbol itself as it dissolves, so it's left to.
+    fn set_symbol(&mut self, mode: SymbolMode, w: &mut impl Write) -> io::Result<()> {
+        // A MIDI knob sends the symbol it's on every time it moves at all
+        if mode == self.bouncer.mode() {
+            return Ok(());
+        }
+        self.bouncer.set_mode(mode);
+        let area = self.bouncer.bounds();
+        self.bouncer.set_area(area);
+        if self.options.morph {
+            return Ok(());
+        }
+        self.clear(w)
+    }
+
+    fn handle_modal_key(&mut self, code: KeyCode, w: &mut impl Write) -> io::Result<()> {
+        let bouncer = &mut self.bouncer;
+        // Whether the modal closed (or changed) and the screen needs wiping
+        let mut clear = false;
+        // A symbol the picker moved to, or back to
+        let mut symbol = None;
+        match &mut self.modal {
+            Some(Modal::Symbols { menu, original }) => {
+                let action = menu.handle_key(code);
+                match action {
+                    MenuAction::Moved => symbol = Some(SymbolMode::all()[menu.selected()]),
+                    MenuAction::Cancel => symbol = Some(*original),
+                    MenuAction::Confirm | MenuAction::None => {}
+                }
+                if matches!(action, MenuAction::Confirm | MenuAction::Cancel) {
+                    self.modal = None;
+                    clear = true;
+                }
+            }
+            Some(Modal::Colors { picker, original }) => match picker.menu.handle_key(code) {
+                MenuAction::Moved => apply_color(bouncer, picker.current()),
+                MenuAction::Confirm if picker.current() == ColorChoice::Rgb => {
+                    let original = original.clone();
+                    self.modal = Some(Modal::Rgb {
+                        input: Input::new("#RRGGBB or r,g,b: "),
+                        original,
+                    });
+                    clear = true;
+                }
+                MenuAction::Confirm => {
+                    self.modal = None;
+                    clear = true;
+                }
+                MenuAction::Cancel => {
+                    restore_color(bouncer, original);
+                    self.modal = None;
+                    clear = true;
+                }
+                MenuAction::None => {}
+            },
+            Some(Modal::Rgb { input, original }) => match input.handle_key(code) {
+                // Invalid input keeps the field open for another try
+                InputAction::Submit(text) => {
+                    if let Some(c) = theme::parse_rgb(&text).map(|c| ColorDepth::detect().fit(c)) {
+                        bouncer.set_color(c);
+                        self.modal = None;
+                        clear = true;
+                    }
+                }
+                InputAction::Cancel => {
+                    restore_color(bouncer, original);
+                    self.modal = None;
+                    clear = true;
+                }
+                InputAction::Edited | InputAction::None => {}
+            },
+            Some(Modal::Command { input, error }) => {
+                // Any key after an error goes back to editing
+                if error.take().is_some() {
+                    return Ok(());
+                }
+                if code == KeyCode::Tab {
+                    let completed = command::complete(input.text());
+                    input.set_text(completed);
+                    return Ok(());
+                }
+                match input.handle_key(code) {
+                    InputAction::Submit(text) => match text.parse() {
+                        Ok(cmd) => {
+                            self.modal = None;
+                            self.clear(w)?;
+                            return self.execute(cmd, w);
+                        }
+                        Err(e) => *error = Some(e),
+                    },
+                    InputAction::Cancel => {
+                        self.modal = None;
+                        clear = true;
+                    }
+                    InputAction::Edited | InputAction::None => {}
+                }
+            }
+            Some(Modal::Help(_) | Modal::GameOver(_)) | None => {}
+        }
+
+        if let Some(mode) = symbol {
+            self.set_symbol(mode, w)?;
+        }
+        if clear {
+            self.clear(w)?;
+        }
+        Ok(())
+    }
+}
+
+// --- Live Cards ---
+// What `--git`, `--tail` and the like bounce in place of a symbol. They're
+// opened before the terminal is taken over, so a failure is reported on a
+// normal one
+enum Card {
+    Git(git::Status),
+    Tail(Tail),
+    Net(Throughput),
+    Disk(Gauge),
+    #[cfg(feature = "rss")]
+    Feed(String),
+    #[cfg(feature = "price")]
+    Price(String),
+}
+
+impl Card {
+    // The one the options ask for; they can't ask for more than one
+    fn open(options: &Options) -> io::Result<Option<Self>> {
+        if options.git {
+            return Ok(Some(Self::Git(git::status()?)));
+        }
+        if let Some(path) = &options.tail {
+            return Ok(Some(Self::Tail(Tail::open(path)?)));
+        }
+        if options.net {
+            return Ok(Some(Self::Net(Throughput::open()?)));
+        }
+        if let Some(path) = &options.disk {
+            return Ok(Some(Self::Disk(Gauge::open(path)?)));
+        }
+        #[cfg(feature = "rss")]
+        if let Some(url) = &options.rss {
+            return Ok(Some(Self::Feed(url.clone())));
+        }
+        #[cfg(feature = "price")]
+        if let Some(symbol) = &options.price {
+            return Ok(Some(Self::Price(symbol.clone())));
+        }
+        Ok(None)
+    }
+}
+
+// --- Run ---
+/// Runs the animation like [`run`] for at most `duration`, then puts the
+/// terminal back and returns, for break timers and demo loops.
+///
+/// # Errors
+/// Returns an error if the terminal can't be set up or written to.
+pub fn run_for(options: Options, duration: Duration) -> io::Result<()> {
+    run(Options {
+        duration: Some(duration),
+        ..options
+    })
+}
+
+/// Runs the animation in the current terminal until the user quits, or
+/// until [`Options::duration`] is up.
+///
+/// # Errors
+/// Returns an error if the terminal can't be set up or written to.
+pub fn run(options: Options) -> io::Result<()> {
+    run_measured(options).map(|_| ())
+}
+
+/// Runs the animation like [`run`], and returns what drawing it cost.
+///
+/// # Errors
+/// Returns an error if the terminal can't be set up or written to.
+pub fn run_measured(options: Options) -> io::Result<RenderStats> {
+    // 1. SETUP
+    // Open sound devices first, so a failure is reported on a normal terminal
+    let speaker = Speaker::open(&options.sound)?;
+    #[cfg(feature = "audio")]
+    let audio = if options.audio {
+        Some(Audio::start()?)
+    } else {
+        None
+    };
+    #[cfg(feature = "gamepad")]
+    let gamepad = if options.gamepad {
+        Some(Gamepad::open()?)
+    } else {
+        None
+    };
+    #[cfg(feature = "midi")]
+    let midi = options.midi.as_deref().map(Midi::open).transpose()?;
+    let fifo = options.fifo.as_deref().map(Fifo::open).transpose()?;
+    let recorder = options
+        .record_path
+        .as_deref()
+        .map(Recorder::create)
+        .transpose()?;
+    let replay = options.replay.as_deref().map(Replay::load).transpose()?;
+    let card = Card::open(&options)?;
+    // Held until the run is over, whichever way it ends
+    let _inhibitor = options.inhibit.then(Inhibitor::take).transpose()?;
+    if options.no_color {
+        // Every color change then writes a plain reset instead
+        style::Colored::set_ansi_color_disabled(true);
+    }
+
+    // Raw mode and the alternate screen, undone however this function ends
+    let mut terminal = Terminal::enter(&options)?;
+
+    // 2. STATE
+    let mut app = App::new(options);
+    app.speaker = speaker;
+    #[cfg(feature = "audio")]
+    {
+        app.audio = audio;
+    }
+    #[cfg(feature = "gamepad")]
+    {
+        app.gamepad = gamepad;
+    }
+    #[cfg(feature = "midi")]
+    {
+        app.midi = midi;
+    }
+    app.fifo = fifo;
+    if let Some(card) = card {
+        app.start_card(card);
+    }
+    if let Some(replay) = replay {
+        app.start_replay(replay)?;
+    }
+    // Last, so the recording starts from wherever the logo really does
+    if let Some(mut recorder) = recorder {
+        recorder.start(&app.bouncer)?;
+        app.bouncer.add_observer(recorder);
+    }
+
+    // 3. GAME LOOP
+    let mut next_frame = Instant::now();
+    let mut skipped = 0;
+    while app.running {
+        next_frame += app.frame_time();
+
+        // SIGNALS: Stop when told to from outside, and after any stop take
+        // the terminal back, in case whatever ran meanwhile changed it
+        let stopped = stop_asked();
+        if stopped || continued() {
+            if stopped {
+                terminal.suspend()?;
+            } else {
+                terminal.take_back()?;
+            }
+            app.resumed(&mut terminal.out)?;
+            // Don't rush to make up for the time spent stopped
+            next_frame = Instant::now();
+        }
+
+        // DRAW: Render the current frame, with any overlay on top. When the
+        // last frame overran its budget, skip drawing for a while so the
+        // physics keeps up with the clock
+        let behind = Instant::now() > next_frame;
+        if behind && skipped < MAX_FRAME_SKIP {
+            skipped += 1;
+        } else {
+            terminal.out.frame(|out| app.draw(out))?;
+            skipped = 0;
+            if behind {
+                // Too far behind to catch up: carry on from here
+                next_frame = Instant::now();
+            }
+        }
+
+        // SLEEP: Block until the next frame is due, waking straight away for
+        // input and resizes so they show up without waiting out the frame
+        while app.running && event::poll(next_frame.saturating_duration_since(Instant::now()))? {
+            let event = event::read()?;
+            if is_suspend(&event) {
+                terminal.suspend()?;
+                app.resumed(&mut terminal.out)?;
+                // Don't rush to make up for the time spent stopped
+                next_frame = Instant::now();
+            } else {
+                app.handle_event(&event, &mut terminal.out)?;
+            }
+            terminal.out.frame(|out| app.draw(out))?;
+        }
+
+        // UPDATE: Advance animation physics
+        app.update(&mut terminal.out)?;
+    }
+
+    // 4. CLEANUP
+    app.save_progress();
+    app.save_session();
+
+    // Dropping `terminal` puts the terminal back
+    Ok(terminal.out.stats())
+}
+
+// --- Terminal ---
+// The thread that owns the terminal, if any, so the panic hook leaves
+// panics elsewhere alone; and whether it's drawing inline
+static OWNER: Mutex<Option<(ThreadId, bool)>> = Mutex::new(None);
+
+// Puts the terminal into raw mode on the alternate screen, or the normal
+// one when inline, and back again when dropped, whether `run` returns,
+// fails or panics
+// Each bool is one thing to undo on the way out
+#[allow(clippy::struct_excessive_bools)]
+struct Terminal {
+    // Frames are written in pieces; buffer them so each goes out in one
+    // write. Counted before the buffer, as they're queued
+    out: Meter<BufWriter<Stdout>>,
+    inline: bool,
+    // Report mouse movement, which terminals only do when asked
+    mouse: bool,
+    // Save the window title, to put back after `--title` changed it
+    title: bool,
+    // Turn the background black for `--high-contrast`, and back afterwards
+    black: bool,
+    signals: Signals,
+}
+
+impl Terminal {
+    fn enter(options: &Options) -> io::Result<Self> {
+        let inline = options.inline;
+        install_panic_hook();
+        *OWNER.lock().unwrap_or_else(PoisonError::into_inner) =
+            Some((thread::current().id(), inline));
+        // Created first, so a failure below still restores what was changed
+        let mut terminal = Self {
+            out: Meter::new(BufWriter::with_capacity(FRAME_BUFFER, stdout())),
+            inline,
+            mouse: options.screensaver || options.cursor.is_some(),
+            title: options.title,
+            // A see-through background is what `--transparent` keeps
+            black: options.high_contrast && !options.transparent,
+            signals: Signals::catch(),
+        };
+        terminal.take_over()?;
+        Ok(terminal)
+    }
+
+    fn take_over(&mut self) -> io::Result<()> {
+        // Read keys byte-by-byte instantly
+        enable_raw_mode()?;
+        if self.mouse {
+            execute!(self.out, event::EnableMouseCapture)?;
+        }
+        if self.title {
+            execute!(self.out, style::Print(PUSH_TITLE))?;
+        }
+        if self.black {
+            execute!(self.out, style::Print(BLACK_BACKGROUND))?;
+        }
+        if self.inline {
+            // Draw over whatever the shell left on screen
+            execute!(
+                self.out,
+                terminal::Clear(terminal::ClearType::All),
+                cursor::Hide
+            )
+        } else {
+            // Switch to alternate screen (like vim/htop do) and hide cursor
+            execute!(self.out, terminal::EnterAlternateScreen, cursor::Hide)
+        }
+    }
+
+    // Hands the terminal back to the shell and stops, like Ctrl+Z does
+    // outside raw mode, then takes it over again once resumed
+    fn suspend(&mut self) -> io::Result<()> {
+        self.out.flush()?;
+        self.put_title_back();
+        self.put_background_back();
+        restore_terminal(self.inline);
+        Signals::stop();
+        // Taken over below already
+        continued();
+        self.take_over()
+    }
+
+    // Takes the terminal over again after a stop that couldn't be caught,
+    // like SIGSTOP's, in case the shell reset it in the meantime
+    fn take_back(&mut self) -> io::Result<()> {
+        // Raw mode is only set again once it's been turned off
+        disable_raw_mode()?;
+        self.take_over()
+    }
+
+    // Best effort, like the rest of putting the terminal back
+    fn put_title_back(&self) {
+        if self.title {
+            let _ = execute!(stdout(), style::Print(POP_TITLE));
+        }
+    }
+
+    fn put_background_back(&self) {
+        if self.black {
+            let _ = execute!(stdout(), style::Print(DEFAULT_BACKGROUND));
+        }
+    }
+}
+
+// --- Signals ---
+// Set when the config should be read again: by the reload key, or SIGHUP
+// when there's no terminal for it to mean was lost
+static RELOAD: AtomicBool = AtomicBool::new(false);
+
+// Set by SIGHUP when it means the terminal went away
+static HANGUP: AtomicBool = AtomicBool::new(false);
+
+// Set by a SIGTSTP from outside, as Ctrl+Z is read as a key instead
+static STOP: AtomicBool = AtomicBool::new(false);
+
+// Set by SIGCONT, which may follow a SIGSTOP that couldn't be caught
+static CONTINUE: AtomicBool = AtomicBool::new(false);
+
+// The signals a run catches, and what each did before, put back when
+// the run is over so the process is left as it was found
+#[cfg(unix)]
+struct Signals {
+    previous: Vec<(libc::c_int, libc::sigaction)>,
+}
+
+#[cfg(unix)]
+impl Signals {
+    fn catch() -> Self {
+        extern "C" fn on_reload(_: libc::c_int) {
+            RELOAD.store(true, Ordering::Relaxed);
+        }
+        extern "C" fn on_hangup(_: libc::c_int) {
+            HANGUP.store(true, Ordering::Relaxed);
+        }
+        extern "C" fn on_continue(_: libc::c_int) {
+            CONTINUE.store(true, Ordering::Relaxed);
+        }
+        // Under a service manager, or started with `nohup`, there's no
+        // terminal to hang up, so SIGHUP is the daemon convention's reload
+        let hangup = if std::env::var_os("INVOCATION_ID").is_some() || ignored(libc::SIGHUP) {
+            on_reload as extern "C" fn(libc::c_int)
+        } else {
+            on_hangup
+        };
+        let handlers = [
+            (libc::SIGHUP, hangup),
+            (libc::SIGTSTP, on_stop),
+            (libc::SIGCONT, on_continue),
+        ];
+        let previous = handlers
+            .into_iter()
+            .filter_map(|(signal, handler)| {
+                let previous = set_handler(signal, handler as libc::sighandler_t)?;
+                Some((signal, previous))
+            })
+            .collect();
+        Self { previous }
+    }
+
+    // Stops the process the way SIGTSTP does when it isn't caught, and
+    // catches it again once continued
+    fn stop() {
+        set_handler(libc::SIGTSTP, libc::SIG_DFL);
+        // SAFETY: raising a signal has no preconditions; with the default
+        // action, SIGTSTP stops the process here until SIGCONT
+        unsafe {
+            libc::raise(libc::SIGTSTP);
+        }
+        let handler: extern "C" fn(libc::c_int) = on_stop;
+        set_handler(libc::SIGTSTP, handler as libc::sighandler_t);
+    }
+
+    fn restore(&self) {
+        for (signal, action) in &self.previous {
+            // SAFETY: `action` is what `sigaction` handed back for `signal`
+            unsafe {
+                libc::sigaction(*signal, action, std::ptr::null_mut());
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+extern "C" fn on_stop(_: libc::c_int) {
+    STOP.store(true, Ordering::Relaxed);
+}
+
+// Points `signal` at `handler`, returning what it did before
+#[cfg(unix)]
+fn set_handler(signal: libc::c_int, handler: libc::sighandler_t) -> Option<libc::sigaction> {
+    // SAFETY: the action is zeroed before being filled in, and every
+    // handler only stores to an atomic, which is async-signal-safe
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_sigaction = handler;
+        action.sa_flags = libc::SA_RESTART;
+        libc::sigemptyset(&raw mut action.sa_mask);
+        let mut previous = mem::zeroed();
+        (libc::sigaction(signal, &raw const action, &raw mut previous) == 0).then_some(previous)
+    }
+}
+
+// Whether `signal` was already being ignored, as `nohup` does to SIGHUP
+#[cfg(unix)]
+fn ignored(signal: libc::c_int) -> bool {
+    // SAFETY: with no new action, `sigaction` only fills in the current one
+    unsafe {
+        let mut current: libc::sigaction = mem::zeroed();
+        libc::sigaction(signal, std::ptr::null(), &raw mut current) == 0
+            && current.sa_sigaction == libc::SIG_IGN
+    }
+}
+
+#[cfg(not(unix))]
+struct Signals;
+
+#[cfg(not(unix))]
+impl Signals {
+    const fn catch() -> Self {
+        Self
+    }
+
+    const fn stop() {}
+
+    const fn restore(&self) {}
+}
+
+// Whether a reload was asked for since the last call
+fn reload_asked() -> bool {
+    RELOAD.swap(false, Ordering::Relaxed)
+}
+
+// Whether the terminal hung up since the last call
+fn hung_up() -> bool {
+    HANGUP.swap(false, Ordering::Relaxed)
+}
+
+// Whether something outside asked for a stop since the last call
+fn stop_asked() -> bool {
+    STOP.swap(false, Ordering::Relaxed)
+}
+
+// Whether the process was continued since the last call
+fn continued() -> bool {
+    CONTINUE.swap(false, Ordering::Relaxed)
+}
+
+// Raw mode turns Ctrl+Z into a key press rather than a signal
+const fn is_suspend(event: &Event) -> bool {
+    cfg!(unix)
+        && matches!(
+            event,
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('z'),
+                modifiers,
+                ..
+            }) if modifiers.contains(KeyModifiers::CONTROL)
+        )
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        // Anything still buffered is from a frame that never finished
+        let unwritten = mem::replace(&mut self.out, Meter::new(BufWriter::new(stdout())));
+        let _ = unwritten.into_inner().into_parts();
+        *OWNER.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        self.put_title_back();
+        self.put_background_back();
+        restore_terminal(self.inline);
+        self.signals.restore();
+    }
+}
+
+// Best effort: there's nothing more to do if the terminal won't listen
+fn restore_terminal(inline: bool) {
+    let mut out = stdout();
+    if inline {
+        // Leave the last frame up and give the prompt a fresh line below it
+        let rows = terminal::size().map_or(1, |(_, rows)| rows);
+        let _ = execute!(
+            out,
+            cursor::MoveTo(0, rows.saturating_sub(1)),
+            style::Print("\r\n")
+        );
+    } else {
+        let _ = execute!(out, terminal::LeaveAlternateScreen);
+    }
+    // Harmless if mouse reporting was never turned on
+    let _ = execute!(out, event::DisableMouseCapture, cursor::Show);
+    let _ = disable_raw_mode();
+}
+
+// Restores the terminal before the panic message is printed, so it's
+// readable and the shell is usable afterwards
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let owner = *OWNER.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some((thread, inline)) = owner
+                && thread == thread::current().id()
+            {
+                restore_terminal(inline);
+            }
+            previous(info);
+        }));
+    });
+}
@@ -0,0 +1,217 @@
+use crossterm::style::Color;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::layer::{Cell, Layer};
+use crate::{Art, Bouncer, Rect, rng};
+
+// --- Aquarium ---
+/// Fish swimming across the screen at different depths, asciiquarium-style,
+/// with bubbles rising to the surface.
+///
+/// Each fish swims one way at its own pace and comes back round from the
+/// other side once it's off the edge. Slower fish are further away, so the
+/// nearer, faster ones pass in front of them.
+pub struct Aquarium {
+    layer: Layer,
+    fish: Vec<Fish>,
+    /// Where each bubble is, and the tick it was blown
+    bubbles: Vec<(u16, u16, u32)>,
+    tick: u32,
+    carry: Duration,
+}
+
+struct Fish {
+    /// Column of the leftmost cell, off screen while wrapping round
+    x: i32,
+    y: u16,
+    kind: usize,
+    right: bool,
+    color: Color,
+    /// Ticks per column moved; higher is further away
+    every: u32,
+}
+
+// Each kind of fish facing right, then left. Spaces are see-through
+const FISH: [(Art, Art); 3] = [
+    (&["><>"], &["<><"]),
+    (&["><((('>"], &["<')))><"]),
+    (
+        &["  ,   ", "}<(('>", "  `   "],
+        &["   ,  ", "<'))>{", "   `  "],
+    ),
+];
+
+const COLORS: [Color; 6] = [
+    Color::Yellow,
+    Color::Red,
+    Color::Magenta,
+    Color::Green,
+    Color::Cyan,
+    Color::AnsiValue(208),
+];
+
+// Cells of water per fish, and never fewer fish than this
+const CELLS_PER_FISH: usize = 300;
+const MIN_FISH: usize = 3;
+
+// Ticks for a bubble to rise one row
+const RISE_EVERY: u32 = 2;
+
+// One fish in this many blows a bubble each tick
+const BUBBLE_ODDS: u16 = 40;
+
+impl Aquarium {
+    #[must_use]
+    pub fn new(area: Rect) -> Self {
+        let mut aquarium = Self {
+            layer: Layer::new(area),
+            fish: Vec::new(),
+            bubbles: Vec::new(),
+            tick: 0,
+            carry: Duration::ZERO,
+        };
+        aquarium.stock();
+        aquarium
+    }
+
+    /// Moves the tank into a new area, with a fresh set of fish for its size.
+    pub fn resize(&mut self, area: Rect) {
+        self.layer.resize(area);
+        self.bubbles.clear();
+        self.stock();
+    }
+
+    /// Forgets what's on screen, for after it was cleared.
+    pub fn redraw(&mut self) {
+        self.layer.redraw();
+    }
+
+    // Fills the tank with fish spread over its width and depth
+    fn stock(&mut self) {
+        let area = self.layer.area();
+        let count = (Layer::cells(area) / CELLS_PER_FISH).max(MIN_FISH);
+        self.fish = (0..count)
+            .map(|_| {
+                let kind = usize::from(rng::<u16>()) % FISH.len();
+                let height = u16::try_from(FISH[kind].0.len()).unwrap_or(1);
+                // Below the surface, and fully in the water where there's room
+                let depth = area.height.saturating_sub(height + 1).max(1);
+                Fish {
+                    x: i32::from(rng::<u16>() % area.width.max(1)),
+                    y: area.y + 1 + rng::<u16>() % depth,
+                    kind,
+                    right: rng(),
+                    color: COLORS[usize::from(rng::<u16>()) % COLORS.len()],
+                    every: 1 + u32::from(rng::<u16>() % 3),
+                }
+            })
+            .collect();
+        // Furthest first, so it's painted over by the ones in front
+        self.fish.sort_by_key(|fish| std::cmp::Reverse(fish.every));
+    }
+
+    /// Advances the tank by `dt`, running one tick per whole
+    /// [`Bouncer::TICK`] like the logo does; returns how many ran.
+    pub fn step(&mut self, dt: Duration) -> u32 {
+        self.carry += dt;
+        let mut ticks = 0;
+        while self.carry >= Bouncer::TICK {
+            self.carry -= Bouncer::TICK;
+            self.update();
+            ticks += 1;
+        }
+        ticks
+    }
+
+    /// Runs one fixed tick: fish swim on, and bubbles rise and pop at the surface.
+    pub fn update(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+        let area = self.layer.area();
+        let width = i32::from(area.width);
+        for fish in &mut self.fish {
+            let length = fish_width(FISH[fish.kind].0);
+            if self.tick.is_multiple_of(fish.every) {
+                fish.x += if fish.right { 1 } else { -1 };
+            }
+            // Wrap round once it's all the way off
+            if fish.right && fish.x >= width {
+                fish.x = -length;
+            } else if !fish.right && fish.x + length <= 0 {
+                fish.x = width;
+            }
+            if rng::<u16>().is_multiple_of(BUBBLE_ODDS) {
+                let mouth = if fish.right {
+                    fish.x + length
+                } else {
+                    fish.x - 1
+                };
+                if let Ok(column) = u16::try_from(mouth)
+                    && column < area.width
+                {
+                    self.bubbles.push((area.x + column, fish.y, self.tick));
+                }
+            }
+        }
+        if self.tick.is_multiple_of(RISE_EVERY) {
+            for bubble in &mut self.bubbles {
+                bubble.1 -= 1;
+            }
+            // Popped on reaching the surface
+            self.bubbles.retain(|&(_, y, _)| y > area.y);
+        }
+    }
+
+    /// Draws the tank as it is now, writing only the cells that changed.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let area = self.layer.area();
+        let mut frame: Vec<Cell> = vec![None; Layer::cells(area)];
+        // The surface along the top
+        for cell in frame.iter_mut().take(usize::from(area.width)) {
+            *cell = Some(('~', Color::Blue));
+        }
+        for &(x, y, blown) in &self.bubbles {
+            // Bubbles grow as they rise
+            let c = match self.tick.wrapping_sub(blown) / RISE_EVERY {
+                0..3 => '.',
+                3..8 => 'o',
+                _ => 'O',
+            };
+            if let Some(cell) = self.layer.index(x, y) {
+                frame[cell] = Some((c, Color::White));
+            }
+        }
+        for fish in &self.fish {
+            let art = if fish.right {
+                FISH[fish.kind].0
+            } else {
+                FISH[fish.kind].1
+            };
+            for (y, line) in (fish.y..).zip(art.iter()) {
+                for (x, c) in (fish.x..).zip(line.chars()) {
+                    if c == ' ' {
+                        continue;
+                    }
+                    if let Ok(x) = u16::try_from(x)
+                        && x < area.width
+                        && let Some(cell) = self.layer.index(area.x + x, y)
+                    {
+                        frame[cell] = Some((c, fish.color));
+                    }
+                }
+            }
+        }
+        self.layer.draw(w, frame)
+    }
+}
+
+fn fish_width(art: Art) -> i32 {
+    art.iter()
+        .map(|line| line.chars().count())
+        .max()
+        .and_then(|width| i32::try_from(width).ok())
+        .unwrap_or(0)
+}
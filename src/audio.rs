@@ -0,0 +1,113 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// --- Audio Input ---
+/// Loudness of the default input device (a microphone, or a monitor of the
+/// system output), for animations that follow the music.
+///
+/// Capture runs on cpal's own thread for as long as this is alive.
+pub struct Audio {
+    /// Loudness of the latest buffer as `f32` bits, from 0.0 to 1.0
+    level: Arc<AtomicU32>,
+    /// Slow-moving average of `level`, the baseline a beat stands out from
+    average: f32,
+    // Dropping the stream stops capture
+    _stream: Stream,
+}
+
+// A beat is a buffer this much louder than the recent average
+const BEAT_RATIO: f32 = 1.5;
+// Ignore "beats" in near silence
+const BEAT_FLOOR: f32 = 0.1;
+
+impl Audio {
+    /// Starts capturing from the default input device.
+    ///
+    /// # Errors
+    /// Returns an error if there's no input device or it can't be opened.
+    pub fn start() -> io::Result<Self> {
+        let device = cpal::default_host()
+            .default_input_device()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no audio input device"))?;
+        let supported = device.default_input_config().map_err(other)?;
+        let level = Arc::new(AtomicU32::new(0));
+        let config = supported.config();
+        let stream = match supported.sample_format() {
+            SampleFormat::F32 => capture::<f32>(&device, config, &level),
+            SampleFormat::I16 => capture::<i16>(&device, config, &level),
+            SampleFormat::U16 => capture::<u16>(&device, config, &level),
+            SampleFormat::I32 => capture::<i32>(&device, config, &level),
+            format => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    format!("unsupported audio sample format {format}"),
+                ));
+            }
+        }?;
+        stream.play().map_err(other)?;
+        Ok(Self {
+            level,
+            average: 0.0,
+            _stream: stream,
+        })
+    }
+
+    /// How loud the input is right now, from 0.0 (silence) to 1.0.
+    #[must_use]
+    pub fn level(&self) -> f32 {
+        f32::from_bits(self.level.load(Ordering::Relaxed))
+    }
+
+    /// Reads the current level; returns true if it jumped well above the
+    /// recent average. Call once per frame.
+    pub fn beat(&mut self) -> bool {
+        let level = self.level();
+        let beat = level > BEAT_FLOOR && level > self.average * BEAT_RATIO;
+        self.average = self.average.mul_add(0.9, level * 0.1);
+        beat
+    }
+}
+
+fn other(e: impl std::fmt::Display) -> io::Error {
+    io::Error::other(format!("audio: {e}"))
+}
+
+// Opens an input stream that publishes each buffer's loudness to `level`
+fn capture<T>(
+    device: &cpal::Device,
+    config: StreamConfig,
+    level: &Arc<AtomicU32>,
+) -> io::Result<Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let level = Arc::clone(level);
+    device
+        .build_input_stream(
+            config,
+            move |data: &[T], _: &_| {
+                if data.is_empty() {
+                    return;
+                }
+                // RMS, scaled so ordinary music reaches the top of the range
+                let sum: f32 = data
+                    .iter()
+                    .map(|&s| {
+                        let s = s.to_sample::<f32>();
+                        s * s
+                    })
+                    .sum();
+                #[allow(clippy::cast_precision_loss)]
+                let rms = (sum / data.len() as f32).sqrt();
+                level.store((rms * 4.0).min(1.0).to_bits(), Ordering::Relaxed);
+            },
+            // Capture errors aren't worth stopping the animation for
+            |_| {},
+            None,
+        )
+        .map_err(other)
+}
@@ -0,0 +1,427 @@
+use chrono::{Datelike, NaiveDate, Timelike};
+use crossterm::style::Color;
+use figlet_rs::FIGfont;
+use std::fmt::Write;
+use std::mem;
+use std::time::{Duration, Instant};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{Accent, Lines, moon, system};
+
+// --- Banner ---
+/// Text that can change while it bounces, for
+/// [`Bouncer::set_banner`](crate::Bouncer::set_banner).
+///
+/// That's `user@hostname` in big figlet letters, neofetch-style, a greeting
+/// for the time of day, this month's calendar, a system info card, or
+/// tonight's moon.
+///
+/// The text is worked out again every [`CHECK_EVERY`], so a machine that
+/// gets renamed shows its new name, "Good morning" turns into "Good
+/// afternoon" at noon, and the calendar and the moon move on at midnight.
+pub struct Banner {
+    kind: Kind,
+    text: String,
+    art: Lines,
+    accent: Option<Accent>,
+    checked: Instant,
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Host,
+    Greeting { ascii: bool },
+    Calendar,
+    System { ascii: bool },
+    Moon { ascii: bool },
+}
+
+// Today on the calendar
+const TODAY: Color = Color::Red;
+
+/// How often [`Banner::refresh`] looks at the text again.
+pub const CHECK_EVERY: Duration = Duration::from_secs(10);
+
+impl Banner {
+    /// `user@hostname` in figlet letters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::of(Kind::Host)
+    }
+
+    /// "Good morning, $USER" in a box, going by the local time: morning
+    /// from 5 to noon, afternoon until 6pm, evening until 5 again. With
+    /// `ascii` the box is drawn in `+`, `-` and `|`.
+    #[must_use]
+    pub fn greeting(ascii: bool) -> Self {
+        Self::of(Kind::Greeting { ascii })
+    }
+
+    /// This month laid out like `cal`, weeks starting on Sunday, with
+    /// today in its own color.
+    #[must_use]
+    pub fn calendar() -> Self {
+        Self::of(Kind::Calendar)
+    }
+
+    /// A neofetch-style card: the distro's logo, with `user@hostname`, the
+    /// OS, kernel, uptime and package count beside it. With `ascii` the
+    /// logo is its plain stand-in.
+    #[must_use]
+    pub fn system(ascii: bool) -> Self {
+        Self::of(Kind::System { ascii })
+    }
+
+    /// Today's moon phase, drawn as a lit disc over the phase's name. With
+    /// `ascii` it's drawn in `#` and `.`.
+    #[must_use]
+    pub fn moon(ascii: bool) -> Self {
+        Self::of(Kind::Moon { ascii })
+    }
+
+    fn of(kind: Kind) -> Self {
+        let text = kind.text();
+        let (art, accent) = kind.render(&text);
+        Self {
+            art,
+            accent,
+            kind,
+            text,
+            checked: Instant::now(),
+        }
+    }
+
+    /// The banner's lines, all the same width.
+    #[must_use]
+    pub fn art(&self) -> Lines {
+        self.art.clone()
+    }
+
+    /// The part of the art for
+    /// [`Bouncer::set_accent`](crate::Bouncer::set_accent), like today on
+    /// the calendar.
+    #[must_use]
+    pub const fn accent(&self) -> Option<Accent> {
+        self.accent
+    }
+
+    /// Rebuilds the art if the text changed since the last check, at most
+    /// once every [`CHECK_EVERY`]; returns true if it did.
+    pub fn refresh(&mut self) -> bool {
+        if self.checked.elapsed() < CHECK_EVERY {
+            return false;
+        }
+        self.checked = Instant::now();
+        let text = self.kind.text();
+        if text == self.text {
+            return false;
+        }
+        (self.art, self.accent) = self.kind.render(&text);
+        self.text = text;
+        true
+    }
+}
+
+impl Default for Banner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Kind {
+    fn text(self) -> String {
+        match self {
+            Self::Host => format!("{}@{}", user(), hostname()),
+            Self::Greeting { .. } => {
+                let period = match chrono::Local::now().hour() {
+                    5..12 => "morning",
+                    12..18 => "afternoon",
+                    _ => "evening",
+                };
+                format!("Good {period}, {}", user())
+            }
+            Self::Calendar | Self::Moon { .. } => chrono::Local::now().date_naive().to_string(),
+            // Changes as the uptime ticks over
+            Self::System { .. } => system::lines().join("\n"),
+        }
+    }
+
+    // Figlet would make a greeting far too wide for most terminals
+    fn render(self, text: &str) -> (Lines, Option<Accent>) {
+        match self {
+            Self::Host => (render(text).into(), None),
+            Self::Greeting { ascii } => (boxed(text, ascii).into(), None),
+            Self::Calendar => {
+                let today = text
+                    .parse()
+                    .unwrap_or_else(|_| chrono::Local::now().date_naive());
+                let (art, accent) = calendar(today);
+                (art.into(), Some(accent))
+            }
+            Self::System { ascii } => {
+                let info: Vec<&str> = text.lines().collect();
+                (beside(system::logo().art(ascii), &info, 3).into(), None)
+            }
+            Self::Moon { ascii } => {
+                let today = text
+                    .parse()
+                    .unwrap_or_else(|_| chrono::Local::now().date_naive());
+                (moon::art(moon::on(today), ascii).into(), None)
+            }
+        }
+    }
+}
+
+pub(crate) fn user() -> String {
+    ["USER", "USERNAME", "LOGNAME"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+        .unwrap_or_else(|| "user".to_string())
+}
+
+#[cfg(unix)]
+pub(crate) fn hostname() -> String {
+    let mut buf = [0u8; 256];
+    // SAFETY: the buffer is valid for its whole length, and one byte short
+    // of it is passed so the name is always NUL-terminated
+    let ok = unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len() - 1) } == 0;
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(0);
+    if ok && len > 0 {
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    } else {
+        "localhost".to_string()
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn hostname() -> String {
+    std::env::var("COMPUTERNAME").unwrap_or_else(|_| "localhost".to_string())
+}
+
+// One line of `text` with a frame around it
+fn boxed(text: &str, ascii: bool) -> Vec<String> {
+    framed(&[text], ascii)
+}
+
+/// `lines` left-aligned in a frame, `╭─╮` or with `ascii` `+-+`.
+#[must_use]
+pub fn framed(lines: &[&str], ascii: bool) -> Vec<String> {
+    let ([top_left, top_right, bottom_left, bottom_right], across, side) = if ascii {
+        (['+'; 4], "-", '|')
+    } else {
+        (['╭', '╮', '╰', '╯'], "─", '│')
+    };
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0);
+    let rule = across.repeat(width + 2);
+    std::iter::once(format!("{top_left}{rule}{top_right}"))
+        .chain(lines.iter().map(|l| {
+            let pad = " ".repeat(width - l.width());
+            format!("{side} {l}{pad} {side}")
+        }))
+        .chain(std::iter::once(format!(
+            "{bottom_left}{rule}{bottom_right}"
+        )))
+        .collect()
+}
+
+/// `left` and `right` side by side, `gap` columns apart, each centered on
+/// the rows of the other.
+#[must_use]
+pub fn beside(left: &[&str], right: &[&str], gap: usize) -> Vec<String> {
+    let rows = left.len().max(right.len());
+    let column = |art: &[&str]| {
+        let width = art.iter().map(|l| l.width()).max().unwrap_or(0);
+        let top = (rows - art.len()) / 2;
+        (0..rows)
+            .map(|row| {
+                let line = row.checked_sub(top).and_then(|i| art.get(i)).unwrap_or(&"");
+                format!("{line}{}", " ".repeat(width - line.width()))
+            })
+            .collect::<Vec<_>>()
+    };
+    column(left)
+        .into_iter()
+        .zip(column(right))
+        .map(|(l, r)| format!("{l}{}{r}", " ".repeat(gap)))
+        .collect()
+}
+
+// The month `today` is in, the way `cal` prints it: always six rows of
+// weeks, so the calendar stays the same size from month to month
+fn calendar(today: NaiveDate) -> (Vec<String>, Accent) {
+    const WIDTH: usize = 20;
+    let first = today.with_day(1).unwrap_or(today);
+    let lead = first.weekday().num_days_from_sunday();
+    let days = (28..=31)
+        .take_while(|&day| first.with_day(day).is_some())
+        .last()
+        .unwrap_or(28);
+    let title = format!("{} {}", first.format("%B"), first.year());
+    let mut lines = vec![
+        format!("{title:^WIDTH$}"),
+        "Su Mo Tu We Th Fr Sa".to_string(),
+    ];
+    let mut week = "   ".repeat(lead as usize);
+    for day in 1..=days {
+        let _ = write!(week, "{day:>2} ");
+        if (lead + day).is_multiple_of(7) {
+            lines.push(week);
+            week = String::new();
+        }
+    }
+    lines.push(week);
+    lines.resize(8, String::new());
+    let cell = lead + today.day() - 1;
+    let accent = Accent {
+        row: 2 + cell as usize / 7,
+        col: cell as usize % 7 * 3,
+        len: 2,
+        color: TODAY,
+    };
+    let padded = lines
+        .iter()
+        .map(|l| format!("{:WIDTH$}", l.trim_end()))
+        .collect();
+    (padded, accent)
+}
+
+// Lays `text` out in the standard FIGlet font, padded to a rectangle
+fn render(text: &str) -> Vec<String> {
+    let figure = FIGfont::standard()
+        .ok()
+        .and_then(|font| font.convert(text).map(|figure| figure.to_string()));
+    let text = figure.unwrap_or_else(|| text.to_string());
+    let lines: Vec<&str> = text.lines().collect();
+    // Figlet leaves blank lines above and below letters that don't reach them
+    let start = lines.iter().position(|l| !l.trim().is_empty()).unwrap_or(0);
+    let end = lines
+        .iter()
+        .rposition(|l| !l.trim().is_empty())
+        .map_or(start, |last| last + 1);
+    let width = lines
+        .iter()
+        .map(|l| l.trim_end().chars().count())
+        .max()
+        .unwrap_or(0);
+    lines[start..end]
+        .iter()
+        .map(|l| format!("{:width$}", l.trim_end()))
+        .collect()
+}
+
+/// Lays `text` out in plain letters for
+/// [`Bouncer::set_banner`](crate::Bouncer::set_banner), word-wrapped to at
+/// most `width` columns with each line centered.
+///
+/// A long message then bounces as a block instead of one line too wide for
+/// the screen. Newlines always start a new line, and words wider than
+/// `width` are broken up.
+#[must_use]
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for piece in paragraph
+            .split_whitespace()
+            .flat_map(|word| break_word(word, width))
+        {
+            if line.is_empty() {
+                line = piece;
+            } else if line.width() + 1 + piece.width() <= width {
+                line.push(' ');
+                line.push_str(&piece);
+            } else {
+                lines.push(mem::replace(&mut line, piece));
+            }
+        }
+        lines.push(line);
+    }
+    let start = lines.iter().position(|l| !l.is_empty()).unwrap_or(0);
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |last| last + 1);
+    let block = lines.iter().map(|l| l.width()).max().unwrap_or(0);
+    lines[start..end]
+        .iter()
+        .map(|l| {
+            let left = (block - l.width()) / 2;
+            let right = block - l.width() - left;
+            format!("{}{l}{}", " ".repeat(left), " ".repeat(right))
+        })
+        .collect()
+}
+
+// Splits a word into pieces no wider than `width`, unless a single
+// character is
+fn break_word(word: &str, width: usize) -> Vec<String> {
+    let mut pieces = vec![String::new()];
+    for c in word.chars() {
+        if let Some(last) = pieces.last_mut()
+            && !last.is_empty()
+            && last.width() + c.width().unwrap_or(0) > width
+        {
+            pieces.push(String::new());
+        }
+        if let Some(last) = pieces.last_mut() {
+            last.push(c);
+        }
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn framed_pads_every_line_to_the_widest() {
+        assert_eq!(
+            framed(&["Good morning", "hi"], true),
+            [
+                "+--------------+",
+                "| Good morning |",
+                "| hi           |",
+                "+--------------+",
+            ]
+        );
+        let fancy = boxed("✓ héllo", false);
+        assert_eq!(fancy.len(), 3);
+        assert_eq!(fancy[1], "│ ✓ héllo │");
+        assert!(fancy.iter().all(|l| l.width() == fancy[0].width()));
+    }
+
+    #[test]
+    fn beside_centers_the_shorter_side() {
+        let card = beside(&["/\\", "\\/"], &["host", "kernel 6.1", "up 5 mins"], 2);
+        assert_eq!(
+            card,
+            ["/\\  host      ", "\\/  kernel 6.1", "    up 5 mins ",]
+        );
+        let centered = beside(&["a", "b", "c"], &["x"], 1);
+        assert_eq!(centered, ["a  ", "b x", "c  "]);
+    }
+
+    #[test]
+    fn calendar_lays_the_month_out_like_cal() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let (art, accent) = calendar(today);
+        assert_eq!(art.len(), 8);
+        assert!(art.iter().all(|l| l.width() == 20));
+        assert_eq!(art[0], "    October 2026    ");
+        assert_eq!(art[2], "             1  2  3");
+        assert_eq!(&art[accent.row][accent.col..accent.col + accent.len], "14");
+    }
+
+    #[test]
+    fn wrap_centers_lines_no_wider_than_asked() {
+        assert_eq!(
+            wrap("error: build failed in 3 crates", 12),
+            ["error: build", "failed in 3 ", "   crates   "]
+        );
+        // Too long for one line, so broken up; blank lines around dropped
+        assert_eq!(wrap("\nabcdefgh\n\n", 3), ["abc", "def", "gh "]);
+        assert!(wrap("", 10).is_empty());
+    }
+}
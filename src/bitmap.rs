@@ -0,0 +1,83 @@
+use crossterm::style::Color;
+use font8x8::{BASIC_FONTS, BLOCK_FONTS, BOX_FONTS, UnicodeFonts};
+use unicode_width::UnicodeWidthChar;
+
+use crate::{Bouncer, theme};
+
+// --- Bitmap Rendering ---
+// Shared by the renderers that draw pixels rather than terminal cells
+
+/// Width of one character cell, in pixels: one 8x8 glyph.
+pub const CELL_WIDTH: usize = 8;
+/// Height of one character cell, in pixels: the glyph doubled in height
+/// to match a terminal cell's shape.
+pub const CELL_HEIGHT: usize = 16;
+
+/// Draws the bouncer's current frame into `pixels`, 0RGB and `width`
+/// pixels to a row, one [`CELL_WIDTH`] x [`CELL_HEIGHT`] cell per character.
+pub fn paint(bouncer: &Bouncer, pixels: &mut [u32], width: usize) {
+    pixels.fill(0);
+    let height = pixels.len() / width.max(1);
+    let cols = u16::try_from(width / CELL_WIDTH).unwrap_or(u16::MAX);
+    let rows = u16::try_from(height / CELL_HEIGHT).unwrap_or(u16::MAX);
+    let mut canvas = Canvas {
+        pixels,
+        width,
+        height,
+    };
+    let color = rgb(bouncer.color());
+    for (row, line) in bouncer.render(cols, rows).lines().enumerate() {
+        let mut col = 0;
+        for c in line.chars() {
+            if let Some(glyph) = glyph(c) {
+                canvas.paint_glyph(glyph, col, row, color);
+            }
+            col += c.width().unwrap_or(0);
+        }
+    }
+}
+
+// The font's bitmap for `c`, or `*` for glyphs it lacks like `❄` and emoji
+fn glyph(c: char) -> Option<[u8; 8]> {
+    if c.is_whitespace() {
+        return None;
+    }
+    BASIC_FONTS
+        .get(c)
+        .or_else(|| BLOCK_FONTS.get(c))
+        .or_else(|| BOX_FONTS.get(c))
+        .or_else(|| BASIC_FONTS.get('*'))
+}
+
+struct Canvas<'a> {
+    pixels: &'a mut [u32],
+    width: usize,
+    height: usize,
+}
+
+impl Canvas<'_> {
+    fn paint_glyph(&mut self, glyph: [u8; 8], col: usize, row: usize, color: u32) {
+        let (left, top) = (col * CELL_WIDTH, row * CELL_HEIGHT);
+        if left + CELL_WIDTH > self.width || top + CELL_HEIGHT > self.height {
+            return;
+        }
+        for (y, bits) in glyph.iter().enumerate() {
+            for x in (0..CELL_WIDTH).filter(|x| bits >> x & 1 == 1) {
+                // Each font row covers two pixel rows
+                for dy in 0..CELL_HEIGHT / 8 {
+                    self.pixels[(top + y * 2 + dy) * self.width + left + x] = color;
+                }
+            }
+        }
+    }
+}
+
+// A terminal color as 0RGB, using xterm's default palette for the named ones
+fn rgb(color: Color) -> u32 {
+    let (r, g, b) = match color {
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::AnsiValue(n) => theme::indexed_rgb(n),
+        named => theme::xterm_rgb(named).unwrap_or((255, 255, 255)),
+    };
+    u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b)
+}
@@ -0,0 +1,146 @@
+use std::io::{self, Write};
+use std::time::Duration;
+use unicode_width::UnicodeWidthChar;
+
+use crate::layer::{Cell, Layer, WIDE_TAIL};
+use crate::{Bouncer, Rect, SymbolMode, theme};
+
+// How hard two logos push apart with their centers together, in cells
+// per tick each tick, fading to nothing as they draw apart
+const SPREAD: f64 = 1.0;
+
+// --- Collage ---
+/// Every symbol bouncing at once, each in its own color spread around the
+/// color wheel: a quick look at all the art together, and some chaos.
+///
+/// The logos pass over one another, later symbols in front; spaces in the
+/// art are see-through. With [`set_spread`](Self::set_spread) they push
+/// each other away instead.
+pub struct Collage {
+    layer: Layer,
+    bouncers: Vec<Bouncer>,
+    spread: bool,
+}
+
+impl Collage {
+    /// One bouncer per symbol in [`SymbolMode::all`], each starting
+    /// somewhere random in `area`.
+    #[must_use]
+    pub fn new(area: Rect, ascii: bool) -> Self {
+        let modes = SymbolMode::all();
+        let count = u16::try_from(modes.len()).unwrap_or(u16::MAX).max(1);
+        let bouncers = modes
+            .iter()
+            .zip(0..)
+            .map(|(&mode, i)| {
+                let mut bouncer = Bouncer::new();
+                bouncer.set_ascii(ascii);
+                bouncer.mode = mode;
+                bouncer.set_color(theme::hue(i * 360 / count));
+                bouncer
+            })
+            .collect();
+        let mut collage = Self {
+            layer: Layer::new(area),
+            bouncers,
+            spread: false,
+        };
+        collage.resize(area);
+        collage
+    }
+
+    /// Moves every logo into a new area, keeping them inside it.
+    pub fn resize(&mut self, area: Rect) {
+        self.layer.resize(area);
+        for bouncer in &mut self.bouncers {
+            bouncer.set_area(area);
+        }
+    }
+
+    /// Has logos that come close push one another softly away, rather than
+    /// pass over each other, so they spread out and rarely sit on top of
+    /// one another for long. They can still meet when there isn't the room.
+    pub const fn set_spread(&mut self, spread: bool) {
+        self.spread = spread;
+    }
+
+    /// Forgets what's on screen, for after it was cleared.
+    pub fn redraw(&mut self) {
+        self.layer.redraw();
+    }
+
+    /// Moves every logo on by `dt`; returns how many walls they hit between
+    /// them.
+    pub fn step(&mut self, dt: Duration) -> u64 {
+        let mut ticks = 0;
+        let bounces = self
+            .bouncers
+            .iter_mut()
+            .map(|bouncer| {
+                let before = bouncer.stats().bounces;
+                ticks = bouncer.step(dt);
+                bouncer.stats().bounces - before
+            })
+            .sum();
+        if self.spread {
+            for _ in 0..ticks {
+                self.spread_out();
+            }
+        }
+        bounces
+    }
+
+    // Pushes each logo away from every other whose art it overlaps, or
+    // about does, the harder the nearer their centers are
+    fn spread_out(&mut self) {
+        let others: Vec<_> = self
+            .bouncers
+            .iter()
+            .map(|b| (b.center(), b.logo_size()))
+            .collect();
+        for (i, bouncer) in self.bouncers.iter_mut().enumerate() {
+            let (x, y) = bouncer.center();
+            let (width, height) = bouncer.logo_size();
+            for (_, &(at, (w, h))) in others.iter().enumerate().filter(|&(j, _)| j != i) {
+                // Their art starts to overlap about this far apart
+                let reach = (f64::from(width + w) / 2.0).max(f64::from(height + h) / 2.0);
+                let distance = (at.0 - x).hypot(at.1 - y);
+                if distance < reach {
+                    bouncer.pull(at, -SPREAD * (1.0 - distance / reach));
+                }
+            }
+        }
+    }
+
+    /// Draws every logo where it is now, writing only the cells that changed.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let area = self.layer.area();
+        let mut frame: Vec<Cell> = vec![None; Layer::cells(area)];
+        for bouncer in &self.bouncers {
+            let (x, y) = bouncer.position();
+            let color = bouncer.color();
+            for (row, line) in (y..).zip(&bouncer.logo_lines()) {
+                let mut col = x;
+                for c in line.chars() {
+                    let width = u16::try_from(c.width().unwrap_or(0)).unwrap_or(0);
+                    if c != ' '
+                        && width > 0
+                        && let Some(cell) = self.layer.index(col, row)
+                    {
+                        frame[cell] = Some((c, color));
+                        if width > 1
+                            && let Some(tail) = self.layer.index(col + 1, row)
+                        {
+                            frame[tail] = Some((WIDE_TAIL, color));
+                        }
+                    }
+                    col = col.saturating_add(width);
+                }
+            }
+        }
+        self.layer.draw(w, frame)
+    }
+}
@@ -0,0 +1,190 @@
+use crossterm::style::Color;
+
+use crate::SymbolMode;
+use crate::keymap::Action;
+use crate::theme::{self, Theme};
+
+// --- Commands ---
+/// A parsed command line, as typed at the `:` prompt.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    /// Anything a key can do, by its config name (`:quit`, `:cycle_color`)
+    Action(Action),
+    Symbol(SymbolMode),
+    Theme(Theme),
+    Color(Color),
+    /// Animation speed multiplier, 1.0 being the default
+    Speed(f64),
+    /// Reseeds the random number generator
+    Seed(u64),
+}
+
+/// Slowest and fastest speeds `:speed` accepts.
+pub const SPEED_RANGE: (f64, f64) = (0.1, 10.0);
+
+// Command words that take an argument, in completion order
+const WORDS: [&str; 5] = ["symbol", "theme", "color", "speed", "seed"];
+
+impl std::str::FromStr for Command {
+    type Err = String;
+
+    /// Parses `symbol arch`, `speed 1.5`, `theme nord`, `seed 42`, `quit`, ...
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let Some(word) = words.next() else {
+            return Err("empty command".to_string());
+        };
+        let arg = words.next();
+        if words.next().is_some() {
+            return Err(format!("too many arguments to `{word}`"));
+        }
+
+        let need = |what: &str| arg.ok_or_else(|| format!("usage: {word} <{what}>"));
+        match word {
+            "symbol" => need("name")?.parse().map(Self::Symbol),
+            "theme" => {
+                let name = need("name")?;
+                theme::find(name)
+                    .map(Self::Theme)
+                    .ok_or_else(|| format!("unknown theme `{name}`"))
+            }
+            "color" => {
+                let name = need("name or #RRGGBB")?;
+                theme::parse_for_terminal(name)
+                    .map(Self::Color)
+                    .ok_or_else(|| format!("unknown color `{name}`"))
+            }
+            "speed" => {
+                let value = need("multiplier")?;
+                let (min, max) = SPEED_RANGE;
+                match value.parse::<f64>() {
+                    Ok(v) if (min..=max).contains(&v) => Ok(Self::Speed(v)),
+                    _ => Err(format!("speed must be a number from {min} to {max}")),
+                }
+            }
+            "seed" => need("number")?
+                .parse()
+                .map(Self::Seed)
+                .map_err(|_| "seed must be a whole number".to_string()),
+            _ => match (word.parse(), arg) {
+                (Ok(action), None) => Ok(Self::Action(action)),
+                (Ok(_), Some(_)) => Err(format!("`{word}` takes no arguments")),
+                (Err(_), _) => Err(format!("unknown command `{word}`")),
+            },
+        }
+    }
+}
+
+// --- Completion ---
+// Everything that may follow `word`
+fn arguments(word: &str) -> Vec<String> {
+    match word {
+        "symbol" => SymbolMode::all()
+            .iter()
+            .map(|m| m.name().to_string())
+            .collect(),
+        "theme" => theme::ALL.iter().map(|t| t.name.to_string()).collect(),
+        "color" => theme::DEFAULT
+            .colors
+            .iter()
+            .map(|c| format!("{c:?}").to_ascii_lowercase())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Completes the last word of `line` as far as it is unambiguous.
+///
+/// A unique match also gets a trailing space so the next word can be typed
+/// straight away. Returns `line` unchanged when nothing matches.
+#[must_use]
+pub fn complete(line: &str) -> String {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    // A trailing space means the user is starting a new word, as does a
+    // line of nothing but spaces
+    let (done, partial): (&[&str], &str) = if line.ends_with(char::is_whitespace) {
+        (&words, "")
+    } else {
+        words
+            .split_last()
+            .map_or((&words[..], ""), |(last, done)| (done, last))
+    };
+
+    let candidates: Vec<String> = match done {
+        [] => WORDS
+            .iter()
+            .map(ToString::to_string)
+            .chain(Action::ALL.iter().map(|a| a.name().to_string()))
+            .collect(),
+        [word] => arguments(word),
+        _ => Vec::new(),
+    };
+    let matches: Vec<&String> = candidates
+        .iter()
+        .filter(|c| c.starts_with(partial))
+        .collect();
+
+    let completed = match matches.as_slice() {
+        [] => return line.to_string(),
+        [only] => format!("{only} "),
+        [first, rest @ ..] => rest.iter().fold((*first).clone(), |prefix, m| {
+            prefix
+                .chars()
+                .zip(m.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect()
+        }),
+    };
+
+    let mut out = done.join(" ");
+    if !out.is_empty() {
+        out.push(' ');
+    }
+    out.push_str(&completed);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_a_unique_word_with_a_space() {
+        assert_eq!(complete("the"), "theme ");
+        assert_eq!(complete("theme no"), "theme nord ");
+    }
+
+    #[test]
+    fn completes_a_shared_prefix_only() {
+        // `seed` and `speed`
+        assert_eq!(complete("s"), "s");
+        assert_eq!(complete("sp"), "speed ");
+    }
+
+    #[test]
+    fn leaves_a_line_with_no_match_alone() {
+        assert_eq!(complete("xyzzy"), "xyzzy");
+    }
+
+    #[test]
+    fn completes_nothing_typed_yet() {
+        // Every command word matches, so only their shared (empty) prefix
+        assert_eq!(complete(""), "");
+    }
+
+    #[test]
+    fn survives_a_line_of_only_whitespace() {
+        assert_eq!(complete("\u{a0}"), "");
+        assert_eq!(complete(" \t"), "");
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!("speed 1.5".parse(), Ok(Command::Speed(1.5)));
+        assert_eq!("seed 42".parse(), Ok(Command::Seed(42)));
+        assert!("speed 99".parse::<Command>().is_err());
+        assert!("".parse::<Command>().is_err());
+        assert!("seed 1 2".parse::<Command>().is_err());
+    }
+}
@@ -0,0 +1,397 @@
+use crossterm::style::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::SymbolMode;
+use crate::app::Options;
+use crate::command::SPEED_RANGE;
+use crate::keymap::{Action, Keymap};
+use crate::theme::{self, Gradient, Theme};
+
+// --- Config File ---
+/// Settings read from `config.toml`.
+///
+/// Every field is optional; a missing file is the same as an empty one.
+/// Command-line flags win over the settings here.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Symbol to start with, by name
+    pub symbol: Option<SymbolMode>,
+    /// Built-in theme to start with, by name
+    pub theme: Option<String>,
+    /// Multiplier on the frame rate, within [`SPEED_RANGE`]
+    pub speed: Option<f64>,
+    /// Light snow falling behind the logo
+    pub snow: Option<bool>,
+    /// Bold white on black, without dimming or gradients, and bigger symbols
+    pub high_contrast: Option<bool>,
+    /// Color to start in, by name or `#RRGGBB`
+    pub color: Option<String>,
+    /// Top and bottom colors of a gradient across the logo, like
+    /// `["#89b4fa", "#1e1e2e"]`
+    pub gradient: Option<[String; 2]>,
+    /// Colors to cycle through, in place of the theme's
+    pub cycle: Option<Cycle>,
+    /// Action name to key(s), e.g. `quit = "Esc"` or `quit = ["q", "Esc"]`
+    pub keys: BTreeMap<String, Keys>,
+}
+
+/// One key name or a list of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "KeysRepr", into = "KeysRepr")]
+pub struct Keys(pub Vec<String>);
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum KeysRepr {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<KeysRepr> for Keys {
+    fn from(repr: KeysRepr) -> Self {
+        match repr {
+            KeysRepr::One(k) => Self(vec![k]),
+            KeysRepr::Many(ks) => Self(ks),
+        }
+    }
+}
+
+impl From<Keys> for KeysRepr {
+    fn from(keys: Keys) -> Self {
+        match <[String; 1]>::try_from(keys.0) {
+            Ok([k]) => Self::One(k),
+            Err(ks) => Self::Many(ks),
+        }
+    }
+}
+
+/// The colors wall hits and `cycle_color` pick from, set under `[cycle]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Cycle {
+    /// Color names or `#RRGGBB`, each on its own or as
+    /// `{ color = "red", weight = 3 }`
+    pub colors: Vec<CycleColor>,
+    /// Go through the colors in order instead of at random
+    pub in_order: bool,
+}
+
+/// One of the colors in a [`Cycle`]. A color of weight 3 comes up three
+/// times as often as one of weight 1, or stays for three bounces in
+/// order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "CycleColorRepr", into = "CycleColorRepr")]
+pub struct CycleColor {
+    pub color: String,
+    pub weight: u32,
+}
+
+/// Heaviest weight a [`CycleColor`] can have.
+pub const MAX_WEIGHT: u32 = 100;
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CycleColorRepr {
+    Plain(String),
+    Weighted { color: String, weight: u32 },
+}
+
+impl From<CycleColorRepr> for CycleColor {
+    fn from(repr: CycleColorRepr) -> Self {
+        match repr {
+            CycleColorRepr::Plain(color) => Self { color, weight: 1 },
+            CycleColorRepr::Weighted { color, weight } => Self { color, weight },
+        }
+    }
+}
+
+impl From<CycleColor> for CycleColorRepr {
+    fn from(c: CycleColor) -> Self {
+        if c.weight == 1 {
+            Self::Plain(c.color)
+        } else {
+            Self::Weighted {
+                color: c.color,
+                weight: c.weight,
+            }
+        }
+    }
+}
+
+// A palette back in `[cycle]` form, each run of one color as a weight
+fn cycle(palette: &[Color], in_order: bool) -> Option<Cycle> {
+    if palette.is_empty() && !in_order {
+        return None;
+    }
+    let mut colors: Vec<CycleColor> = Vec::new();
+    let mut last = None;
+    for &color in palette {
+        let name = theme::color_name(color)?;
+        match colors.last_mut() {
+            Some(c) if last == Some(color) && c.weight < MAX_WEIGHT => c.weight += 1,
+            _ => colors.push(CycleColor {
+                color: name,
+                weight: 1,
+            }),
+        }
+        last = Some(color);
+    }
+    Some(Cycle { colors, in_order })
+}
+
+impl Config {
+    /// Parses config file contents.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error describing bad TOML or unknown settings.
+    pub fn parse(text: &str) -> io::Result<Self> {
+        let config: Self = toml::from_str(text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.message()))?;
+        // Checked up front, so a bad value is reported however it's used
+        config.theme()?;
+        config.color()?;
+        config.colors()?;
+        config.gradient()?;
+        if let Some(speed) = config.speed {
+            let (min, max) = SPEED_RANGE;
+            if !(min..=max).contains(&speed) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("speed: {speed} is outside {min}-{max}"),
+                ));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Every setting as `options` ended up with it, from the defaults
+    /// overridden by the file, the environment and then the flags; the
+    /// keys are the whole keymap, not just what was remapped. Settings a
+    /// flag switched off, like the `[cycle]` under `--theme`, are left
+    /// out, so loading the result gives back the same options.
+    #[must_use]
+    pub fn effective(options: &Options) -> Self {
+        let keys = Action::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = options.keymap.keys(action).iter().map(ToString::to_string);
+                (action.name().to_string(), Keys(keys.collect()))
+            })
+            .collect();
+        let gradient = options
+            .gradient
+            .and_then(|g| Some([theme::color_name(g.top)?, theme::color_name(g.bottom)?]));
+        Self {
+            symbol: Some(options.symbol.unwrap_or(SymbolMode::NixOS)),
+            // Only when one was picked: naming the default theme would turn
+            // off the symbols' own colors
+            theme: options.theme.map(|t| t.name.to_string()),
+            speed: Some(options.speed.unwrap_or(1.0)),
+            snow: Some(options.snow),
+            high_contrast: Some(options.high_contrast),
+            color: options.color.and_then(theme::color_name),
+            gradient,
+            cycle: cycle(&options.colors, options.in_order),
+            keys,
+        }
+    }
+
+    /// Writes the settings out in config file form.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if they can't be written as TOML.
+    pub fn to_toml(&self) -> io::Result<String> {
+        toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Looks up the `theme` setting.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if it doesn't name a built-in theme.
+    pub fn theme(&self) -> io::Result<Option<Theme>> {
+        self.theme
+            .as_deref()
+            .map(|name| {
+                theme::find(name).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("theme: unknown theme `{name}`"),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// Looks up the `color` setting, fitted to what the terminal can show.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if it isn't a color name or `#RRGGBB`.
+    pub fn color(&self) -> io::Result<Option<Color>> {
+        self.color
+            .as_deref()
+            .map(|s| {
+                theme::parse_for_terminal(s).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("color: unknown color `{s}`"),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// Looks up the `gradient` setting.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if either end isn't a color name or
+    /// `#RRGGBB`.
+    pub fn gradient(&self) -> io::Result<Option<Gradient>> {
+        let Some([top, bottom]) = &self.gradient else {
+            return Ok(None);
+        };
+        let color = |s: &str| {
+            theme::parse_color(s).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("gradient: unknown color `{s}`"),
+                )
+            })
+        };
+        Ok(Some(Gradient::new(color(top)?, color(bottom)?)))
+    }
+
+    /// The `[cycle]` colors as a palette, each repeated as many times as
+    /// its weight; empty when there aren't any.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error for an unknown color or a weight
+    /// outside 1 to [`MAX_WEIGHT`].
+    pub fn colors(&self) -> io::Result<Vec<Color>> {
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, format!("cycle: {e}"));
+        let mut palette = Vec::new();
+        for c in self.cycle.iter().flat_map(|cycle| &cycle.colors) {
+            let color = theme::parse_for_terminal(&c.color)
+                .ok_or_else(|| invalid(format!("unknown color `{}`", c.color)))?;
+            if !(1..=MAX_WEIGHT).contains(&c.weight) {
+                return Err(invalid(format!(
+                    "`{}` has weight {}, outside 1-{MAX_WEIGHT}",
+                    c.color, c.weight
+                )));
+            }
+            palette.extend(std::iter::repeat_n(color, c.weight as usize));
+        }
+        Ok(palette)
+    }
+
+    /// Loads `path`, or the default location when `None`.
+    ///
+    /// A missing file at the default location is not an error.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read or doesn't parse.
+    pub fn load(path: Option<&Path>) -> io::Result<Self> {
+        let (path, required) = match path {
+            Some(p) => (p.to_path_buf(), true),
+            None => match default_path() {
+                Some(p) => (p, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|e| with_path(&path, &e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !required => Ok(Self::default()),
+            Err(e) => Err(with_path(&path, &e)),
+        }
+    }
+
+    /// Builds the keymap: defaults with `[keys]` applied on top.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error for unknown actions or key names, or a
+    /// key bound to two actions.
+    pub fn keymap(&self) -> io::Result<Keymap> {
+        let mut keymap = Keymap::default();
+        keymap
+            .apply(self.keys.iter().map(|(a, k)| (a.as_str(), k.0.as_slice())))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("[keys]: {e}")))?;
+        Ok(keymap)
+    }
+}
+
+fn with_path(path: &Path, e: &io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
+
+// `$var/snowflake-bounce`, or `~/<fallback>/snowflake-bounce` when it's unset
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    Some(xdg_base(var, fallback)?.join("snowflake-bounce"))
+}
+
+fn xdg_base(var: &str, fallback: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(fallback)))
+}
+
+/// `$XDG_CONFIG_HOME` itself, falling back to `~/.config`, for files other
+/// programs read, like systemd user units.
+#[must_use]
+pub fn config_home() -> Option<PathBuf> {
+    xdg_base("XDG_CONFIG_HOME", ".config")
+}
+
+/// `$XDG_CONFIG_HOME/snowflake-bounce/config.toml`, falling back to `~/.config`.
+#[must_use]
+pub fn default_path() -> Option<PathBuf> {
+    Some(xdg_dir("XDG_CONFIG_HOME", ".config")?.join("config.toml"))
+}
+
+/// `$XDG_DATA_HOME/snowflake-bounce`, falling back to `~/.local/share`.
+#[must_use]
+pub fn data_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_DATA_HOME", ".local/share")
+}
+
+/// `$XDG_STATE_HOME/snowflake-bounce`, falling back to `~/.local/state`.
+#[must_use]
+pub fn state_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_STATE_HOME", ".local/state")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_collapses_runs_into_weights() {
+        let red = Color::Red;
+        let teal = Color::Rgb {
+            r: 0,
+            g: 128,
+            b: 128,
+        };
+        let back = cycle(&[red, red, teal, red], false).unwrap();
+        let names: Vec<_> = back
+            .colors
+            .iter()
+            .map(|c| (c.color.as_str(), c.weight))
+            .collect();
+        assert_eq!(names, [("red", 2), ("#008080", 1), ("red", 1)]);
+        assert!(cycle(&[], false).is_none());
+        assert!(cycle(&[], true).is_some_and(|c| c.in_order));
+    }
+
+    #[test]
+    fn cycle_splits_runs_over_the_heaviest_weight() {
+        let palette = vec![Color::Blue; MAX_WEIGHT as usize + 1];
+        let back = cycle(&palette, false).unwrap();
+        let weights: Vec<_> = back.colors.iter().map(|c| c.weight).collect();
+        assert_eq!(weights, [MAX_WEIGHT, 1]);
+    }
+}
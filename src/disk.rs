@@ -0,0 +1,216 @@
+use crossterm::style::Color;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::banner;
+
+// --- Disk Usage ---
+/// How often [`Gauge::refresh`] looks at the filesystem again.
+pub const CHECK_EVERY: Duration = Duration::from_secs(10);
+
+/// Fractions of the filesystem used from which the gauge turns yellow,
+/// then red.
+pub const WARN_AT: f64 = 0.8;
+pub const FULL_AT: f64 = 0.9;
+
+// Cells in the bar
+const BAR_WIDTH: u8 = 16;
+
+/// How much of a filesystem is in use, as `df` counts it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// Bytes in use, whoever they're in use by
+    pub used: u64,
+    /// Bytes in use plus the bytes an ordinary user could still write
+    pub total: u64,
+}
+
+impl Usage {
+    /// The fraction in use, from 0 to 1.
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        // Close enough for a gauge at any size
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = self.used as f64 / self.total as f64;
+        fraction.clamp(0.0, 1.0)
+    }
+
+    // The gauge's color: green, then yellow from `WARN_AT` and red from `FULL_AT`
+    fn color(&self) -> Color {
+        let fraction = self.fraction();
+        if fraction >= FULL_AT {
+            Color::Red
+        } else if fraction >= WARN_AT {
+            Color::Yellow
+        } else {
+            Color::Green
+        }
+    }
+}
+
+/// Reads the usage of the filesystem `path` is on.
+///
+/// # Errors
+/// Returns an error if the filesystem can't be queried.
+#[cfg(unix)]
+pub fn usage(path: &Path) -> io::Result<Usage> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    // SAFETY: statvfs is plain data, so all zeroes is a valid value for it
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: the path is NUL-terminated and `stats` is valid to write to
+    if unsafe { libc::statvfs(c_path.as_ptr(), &raw mut stats) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // The fields are narrower than u64 on some targets
+    #[allow(clippy::useless_conversion)]
+    let (block, blocks, free, available) = (
+        u64::from(stats.f_frsize),
+        u64::from(stats.f_blocks),
+        u64::from(stats.f_bfree),
+        u64::from(stats.f_bavail),
+    );
+    let used = blocks.saturating_sub(free);
+    // Like `df`, blocks kept back for root count as neither used nor free
+    let total = used.saturating_add(available);
+    Ok(Usage {
+        used: used.saturating_mul(block),
+        total: total.saturating_mul(block),
+    })
+}
+
+#[cfg(not(unix))]
+pub fn usage(_path: &Path) -> io::Result<Usage> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "disk usage needs a Unix system",
+    ))
+}
+
+/// A bar gauge of how full a filesystem is, for
+/// [`Bouncer::set_banner`](crate::Bouncer::set_banner).
+///
+/// It's green, then [`WARN_AT`] yellow and [`FULL_AT`] red, to nag about
+/// cleaning up `/nix/store`.
+pub struct Gauge {
+    path: PathBuf,
+    usage: Usage,
+    checked: Instant,
+}
+
+impl Gauge {
+    /// Starts watching the filesystem `path` is on.
+    ///
+    /// # Errors
+    /// Returns an error if the filesystem can't be queried.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let usage = usage(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            usage,
+            checked: Instant::now(),
+        })
+    }
+
+    /// How full the filesystem was at the last look.
+    #[must_use]
+    pub const fn usage(&self) -> Usage {
+        self.usage
+    }
+
+    /// The color for how full it is.
+    #[must_use]
+    pub fn color(&self) -> Color {
+        self.usage.color()
+    }
+
+    /// Looks at the filesystem again, at most once every [`CHECK_EVERY`];
+    /// returns true if the usage changed. A failed look keeps the last one.
+    pub fn refresh(&mut self) -> bool {
+        if self.checked.elapsed() < CHECK_EVERY {
+            return false;
+        }
+        self.checked = Instant::now();
+        match usage(&self.path) {
+            Ok(usage) if usage != self.usage => {
+                self.usage = usage;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The gauge: the path, a bar and the percentage, over how many bytes
+    /// are in use out of how many.
+    #[must_use]
+    pub fn card(&self, ascii: bool) -> Vec<String> {
+        let (full, empty) = if ascii { ('#', '.') } else { ('█', '░') };
+        let fraction = self.usage.fraction();
+        let cells = nearest(fraction, BAR_WIDTH);
+        let bar: String = std::iter::repeat_n(full, usize::from(cells))
+            .chain(std::iter::repeat_n(empty, usize::from(BAR_WIDTH - cells)))
+            .collect();
+        let percent = nearest(fraction, 100);
+        let gauge = format!("{} {bar} {percent:>3}%", self.path.display());
+        let sizes = format!("{} of {}", size(self.usage.used), size(self.usage.total));
+        banner::framed(&[&gauge, &sizes], ascii)
+    }
+}
+
+// `fraction` of `whole`, to the nearest whole number
+fn nearest(fraction: f64, whole: u8) -> u8 {
+    (0..=whole)
+        .rev()
+        .find(|&n| fraction.mul_add(f64::from(whole), 0.5) >= f64::from(n))
+        .unwrap_or(0)
+}
+
+// Bytes in the largest unit that keeps it under 1000
+fn size(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "kB", "MB", "GB", "TB", "PB"];
+    let (mut whole, mut tenths) = (bytes, 0);
+    let mut unit = 0;
+    while whole >= 1000 && unit < UNITS.len() - 1 {
+        tenths = whole % 1000 / 100;
+        whole /= 1000;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{whole} {}", UNITS[unit])
+    } else {
+        format!("{whole}.{tenths} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gauge(used: u64, total: u64) -> Gauge {
+        Gauge {
+            path: PathBuf::from("/"),
+            usage: Usage { used, total },
+            checked: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn card_fills_the_bar_and_changes_color() {
+        let half = gauge(512, 1024);
+        let card = half.card(true);
+        assert_eq!(card[1], "| / ########........  50% |");
+        assert_eq!(half.color(), Color::Green);
+        assert_eq!(gauge(85, 100).color(), Color::Yellow);
+        assert_eq!(gauge(95, 100).color(), Color::Red);
+        // An empty filesystem is no fuller than none at all
+        assert_eq!(gauge(0, 0).card(true)[1], "| / ................   0% |");
+    }
+}
@@ -0,0 +1,187 @@
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, stdout};
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::app::Options;
+use crate::bitmap::{self, CELL_HEIGHT, CELL_WIDTH};
+use crate::{Bouncer, Rect};
+
+// --- Framebuffer ---
+// 60 frames a second
+const FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+// An open framebuffer and its layout, as the kernel reports it in sysfs
+struct Framebuffer {
+    file: File,
+    width: usize,
+    height: usize,
+    bytes_per_pixel: usize,
+    // Bytes from the start of one row to the next
+    stride: usize,
+}
+
+impl Framebuffer {
+    fn open(device: &Path) -> io::Result<Self> {
+        let invalid = |what: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: unreadable {what}", device.display()),
+            )
+        };
+        let name = device.file_name().ok_or_else(|| invalid("device name"))?;
+        let sysfs = PathBuf::from("/sys/class/graphics").join(name);
+        let read = |attr: &str| -> io::Result<String> {
+            fs::read_to_string(sysfs.join(attr))
+                .map(|text| text.trim().to_string())
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", device.display())))
+        };
+
+        // `virtual_size` is `width,height`
+        let size = read("virtual_size")?;
+        let (width, height) = size
+            .split_once(',')
+            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+            .ok_or_else(|| invalid("size"))?;
+        let bytes_per_pixel = match read("bits_per_pixel")?.as_str() {
+            "32" => 4,
+            "16" => 2,
+            bits => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    format!(
+                        "{}: {bits} bits per pixel isn't supported",
+                        device.display()
+                    ),
+                ));
+            }
+        };
+        let stride = read("stride")?.parse().map_err(|_| invalid("stride"))?;
+
+        let file = OpenOptions::new()
+            .write(true)
+            .open(device)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", device.display())))?;
+        Ok(Self {
+            file,
+            width,
+            height,
+            bytes_per_pixel,
+            stride,
+        })
+    }
+
+    // Copies 0RGB pixels to the screen, converting to its pixel format
+    fn show(&self, pixels: &[u32], bytes: &mut Vec<u8>) -> io::Result<()> {
+        bytes.clear();
+        for row in pixels.chunks(self.width) {
+            for &pixel in row {
+                if self.bytes_per_pixel == 4 {
+                    // XRGB8888, stored little-endian as B, G, R, X
+                    bytes.extend_from_slice(&pixel.to_le_bytes());
+                } else {
+                    // RGB565
+                    let [b, g, r, _] = pixel.to_le_bytes();
+                    let packed =
+                        u16::from(r >> 3) << 11 | u16::from(g >> 2) << 5 | u16::from(b >> 3);
+                    bytes.extend_from_slice(&packed.to_le_bytes());
+                }
+            }
+            // Rows may be padded past the visible width
+            bytes.resize(
+                bytes.len() + self.stride - self.width * self.bytes_per_pixel,
+                0,
+            );
+        }
+        self.file.write_all_at(bytes, 0)
+    }
+}
+
+// Keeps the console's keyboard raw and its cursor hidden while drawing
+struct Console;
+
+impl Console {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        let console = Self;
+        execute!(stdout(), cursor::Hide)?;
+        Ok(console)
+    }
+}
+
+impl Drop for Console {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), cursor::Show);
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Runs the animation straight on a Linux framebuffer device such as
+/// `/dev/fb0`, for a virtual console with no terminal emulator, until
+/// `q`, Esc or Ctrl+C. `c` changes color and `s` the symbol.
+///
+/// Keys are read from the console the program runs in. Only the symbol is
+/// taken from `options`; the terminal-only extras don't apply here.
+///
+/// # Errors
+/// Returns an error if the device can't be opened, has a pixel format
+/// other than 16 or 32 bits, or can't be written to.
+pub fn run(options: &Options, device: &Path) -> io::Result<()> {
+    let framebuffer = Framebuffer::open(device)?;
+    let cols = u16::try_from(framebuffer.width / CELL_WIDTH).unwrap_or(u16::MAX);
+    let rows = u16::try_from(framebuffer.height / CELL_HEIGHT).unwrap_or(u16::MAX);
+    let area = Rect::new(0, 0, cols, rows);
+    let mut bouncer = Bouncer::try_new(cols, rows)?;
+    if let Some(mode) = options.symbol {
+        bouncer.mode = mode;
+        if let Some(color) = mode.color() {
+            bouncer.set_color(color);
+        }
+    }
+    bouncer.set_area(area);
+
+    let _console = Console::enter()?;
+    let mut pixels = vec![0; framebuffer.width * framebuffer.height];
+    let mut bytes = Vec::new();
+    let mut last = Instant::now();
+    'frames: loop {
+        while event::poll(Duration::ZERO)? {
+            let Event::Key(KeyEvent {
+                code, modifiers, ..
+            }) = event::read()?
+            else {
+                continue;
+            };
+            match code {
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break 'frames,
+                KeyCode::Char('q') | KeyCode::Esc => break 'frames,
+                KeyCode::Char('c') => bouncer.cycle_color(),
+                KeyCode::Char('s') => {
+                    bouncer.cycle_symbol();
+                    // The new symbol may be a different size
+                    bouncer.set_area(area);
+                }
+                _ => {}
+            }
+        }
+
+        let now = Instant::now();
+        bouncer.step(now - last);
+        last = now;
+
+        bitmap::paint(&bouncer, &mut pixels, framebuffer.width);
+        framebuffer.show(&pixels, &mut bytes)?;
+        std::thread::sleep(FRAME_TIME.saturating_sub(now.elapsed()));
+    }
+
+    // Leave the console blank rather than showing the last frame
+    pixels.fill(0);
+    framebuffer.show(&pixels, &mut bytes)
+}
@@ -0,0 +1,267 @@
+use std::io;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+// --- News Feed ---
+/// How often the feed is fetched again.
+pub const REFRESH: Duration = Duration::from_mins(15);
+
+/// The headlines of an RSS or Atom feed, newest first, one shown at a time.
+///
+/// The feed is fetched on a background thread, now and then every
+/// [`REFRESH`], so a slow network never holds up the animation.
+pub struct Feed {
+    updates: Receiver<io::Result<Vec<String>>>,
+    headlines: Vec<String>,
+    showing: usize,
+    /// Bounces per headline, and the count when the current one went up
+    every: u64,
+    shown_at: u64,
+    error: Option<String>,
+}
+
+impl Feed {
+    /// Starts fetching `url`, moving on to the next headline every `every`
+    /// bounces.
+    #[must_use]
+    pub fn watch(url: String, every: u64) -> Self {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            loop {
+                if tx.send(fetch(&url)).is_err() {
+                    // Nobody is watching any more
+                    return;
+                }
+                thread::sleep(REFRESH);
+            }
+        });
+        Self {
+            updates: rx,
+            headlines: Vec::new(),
+            showing: 0,
+            every: every.max(1),
+            shown_at: 0,
+            error: None,
+        }
+    }
+
+    /// The headline to show: until there are any, what's holding them up.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        match (self.headlines.get(self.showing), &self.error) {
+            (Some(headline), _) => headline,
+            (None, Some(error)) => error,
+            (None, None) => "Fetching headlines...",
+        }
+    }
+
+    /// Takes in a fresh fetch if one came in, and moves on to the next
+    /// headline once `bounces` is far enough past the last change; returns
+    /// true if [`text`](Self::text) changed.
+    ///
+    /// A failed fetch keeps the headlines from before; it only shows while
+    /// there are none.
+    pub fn update(&mut self, bounces: u64) -> bool {
+        let was = self.text().to_string();
+        for update in self.updates.try_iter() {
+            match update {
+                Ok(headlines) if headlines.is_empty() => {
+                    self.error = Some("The feed has no headlines".to_string());
+                }
+                Ok(headlines) => {
+                    // Back to the top when there's news
+                    if headlines.first() != self.headlines.first() {
+                        self.showing = 0;
+                        self.shown_at = bounces;
+                    }
+                    self.showing %= headlines.len();
+                    self.headlines = headlines;
+                }
+                Err(e) => self.error = Some(format!("Couldn't fetch the feed: {e}")),
+            }
+        }
+        if !self.headlines.is_empty() && bounces >= self.shown_at + self.every {
+            self.showing = (self.showing + 1) % self.headlines.len();
+            self.shown_at = bounces;
+        }
+        self.text() != was
+    }
+}
+
+/// Fetches `url` and reads the headlines out of it.
+///
+/// # Errors
+/// Returns an error if the request fails.
+pub fn fetch(url: &str) -> io::Result<Vec<String>> {
+    let text = ureq::get(url)
+        .call()
+        .and_then(|mut reply| reply.body_mut().read_to_string())
+        .map_err(io::Error::other)?;
+    Ok(parse(&text))
+}
+
+/// The titles of an RSS feed's `<item>`s or an Atom feed's `<entry>`s, in
+/// the order they're listed, which is newest first in practice.
+///
+/// This isn't a full XML parser: it finds the first `<title>` inside each
+/// item, and decodes CDATA sections and the standard entities in it.
+#[must_use]
+pub fn parse(text: &str) -> Vec<String> {
+    let mut headlines = Vec::new();
+    for tag in ["item", "entry"] {
+        let mut rest = text;
+        while let Some(item) = element(rest, tag) {
+            let content = &rest[item.clone()];
+            rest = &rest[item.end..];
+            if let Some(title) = element(content, "title") {
+                let title = decode(&content[title]);
+                if !title.is_empty() {
+                    headlines.push(title);
+                }
+            }
+        }
+        if !headlines.is_empty() {
+            break;
+        }
+    }
+    headlines
+}
+
+// Where the content of the first `<tag>` element in `text` is, whatever
+// attributes it has. Its end is where `</tag>` starts
+fn element(text: &str, tag: &str) -> Option<std::ops::Range<usize>> {
+    let open = format!("<{tag}");
+    let mut from = 0;
+    loop {
+        let at = from + text[from..].find(&open)?;
+        let after = at + open.len();
+        // `<item>` or `<item ...>`, not `<itemized>`
+        match text[after..].chars().next()? {
+            '>' | ' ' | '\t' | '\r' | '\n' => {
+                let start = after + text[after..].find('>')? + 1;
+                let end = start + text[start..].find(&format!("</{tag}>"))?;
+                return Some(start..end);
+            }
+            _ => from = after,
+        }
+    }
+}
+
+// Takes out a CDATA wrapper and any tags, decodes entities and puts the
+// whitespace on one line
+fn decode(text: &str) -> String {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("<![CDATA[")
+        .and_then(|t| t.strip_suffix("]]>"))
+        .unwrap_or(text);
+    let mut plain = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if in_tag => {}
+            c => plain.push(c),
+        }
+    }
+    entities(&plain)
+        .split(|c: char| c.is_whitespace() || c.is_control())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find('&') {
+        decoded.push_str(&rest[..at]);
+        rest = &rest[at..];
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((end, entity(&rest[1..end])?)));
+        if let Some((end, c)) = entity {
+            decoded.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::Sender;
+
+    fn feed(every: u64) -> (Sender<io::Result<Vec<String>>>, Feed) {
+        let (tx, rx) = mpsc::channel();
+        let feed = Feed {
+            updates: rx,
+            headlines: Vec::new(),
+            showing: 0,
+            every,
+            shown_at: 0,
+            error: None,
+        };
+        (tx, feed)
+    }
+
+    fn headlines(titles: &[&str]) -> Vec<String> {
+        titles.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn rotates_headlines_every_few_bounces() {
+        let (tx, mut feed) = feed(3);
+        assert!(!feed.update(0));
+        tx.send(Ok(headlines(&["one", "two"]))).unwrap();
+        assert!(feed.update(0));
+        assert_eq!(feed.text(), "one");
+        // Nothing to lay out again until it's time for the next one
+        assert!(!feed.update(2));
+        assert!(feed.update(3));
+        assert_eq!(feed.text(), "two");
+        assert!(feed.update(6));
+        assert_eq!(feed.text(), "one");
+        // News starts from the top; a failed fetch keeps what's there
+        tx.send(Ok(headlines(&["zero", "one", "two"]))).unwrap();
+        tx.send(Err(io::Error::other("offline"))).unwrap();
+        assert!(feed.update(7));
+        assert_eq!(feed.text(), "zero");
+    }
+
+    #[test]
+    fn reads_rss_and_atom_titles() {
+        let rss = "<rss><channel><title>Site</title>\
+            <item><title><![CDATA[Snow & ice]]></title></item>\
+            <item><title>Tom &amp; Jerry &#x2014; again</title></item></channel></rss>";
+        assert_eq!(parse(rss), ["Snow & ice", "Tom & Jerry — again"]);
+        let atom = "<feed><entry><title type=\"text\">First</title></entry></feed>";
+        assert_eq!(parse(atom), ["First"]);
+        assert!(parse("<html></html>").is_empty());
+    }
+}
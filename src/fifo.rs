@@ -0,0 +1,160 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::command::Command;
+
+// --- Command FIFO ---
+/// Commands written a line at a time to a named pipe, the same ones the `:`
+/// prompt takes, so scripts can drive the animation:
+/// `echo 'symbol arch' > PATH`.
+///
+/// The pipe is read on a thread of its own for as long as this is alive,
+/// and opened again each time a writer closes it.
+pub struct Fifo {
+    commands: Receiver<Result<Command, String>>,
+}
+
+impl Fifo {
+    /// Listens on the named pipe at `path`, making it first if there's
+    /// nothing there yet.
+    ///
+    /// # Errors
+    /// Returns an error if `path` is something other than a named pipe, or
+    /// it can't be made.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        match std::fs::metadata(path) {
+            Ok(meta) if is_fifo(&meta) => {}
+            Ok(_) => {
+                return Err(with_path(
+                    path,
+                    &io::Error::new(io::ErrorKind::InvalidInput, "not a named pipe"),
+                ));
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                make(path).map_err(|e| with_path(path, &e))?;
+            }
+            Err(e) => return Err(with_path(path, &e)),
+        }
+        let (send, commands) = mpsc::channel();
+        let path = path.to_path_buf();
+        std::thread::spawn(move || listen(&path, &send));
+        Ok(Self { commands })
+    }
+
+    /// Commands received since the last call, oldest first, or why a line
+    /// wasn't one. Blank lines are skipped.
+    pub fn commands(&self) -> impl Iterator<Item = Result<Command, String>> + '_ {
+        self.commands.try_iter()
+    }
+}
+
+// Reads lines from each writer in turn until the app is gone or the pipe
+// can't be opened any more. Opening waits for a writer, so this mostly sleeps
+fn listen(path: &Path, send: &Sender<Result<Command, String>>) {
+    while let Ok(file) = File::open(path) {
+        for line in BufReader::new(file).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if send.send(line.parse()).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_fifo(meta: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+
+    meta.file_type().is_fifo()
+}
+
+#[cfg(not(unix))]
+const fn is_fifo(_meta: &std::fs::Metadata) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn make(path: &Path) -> io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    // SAFETY: the path is NUL-terminated
+    if unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make(_path: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "named pipes need a Unix system",
+    ))
+}
+
+fn with_path(path: &Path, e: &io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::time::{Duration, Instant};
+
+    use crate::SymbolMode;
+
+    // Waits a little while for what's been written to come through
+    fn received(fifo: &Fifo, count: usize) -> Vec<Result<Command, String>> {
+        let mut got = Vec::new();
+        let until = Instant::now() + Duration::from_secs(5);
+        while got.len() < count && Instant::now() < until {
+            got.extend(fifo.commands());
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        got
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reads_commands_from_each_writer_in_turn() {
+        let dir = std::env::temp_dir().join(format!("snowflake-fifo-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("commands");
+        let _ = std::fs::remove_file(&path);
+        let fifo = Fifo::open(&path).unwrap();
+
+        let mut writer = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        writer.write_all(b"symbol arch\n\nspeed 100\n").unwrap();
+        drop(writer);
+        let got = received(&fifo, 2);
+        assert_eq!(got[0], Ok(Command::Symbol(SymbolMode::Arch)));
+        assert!(got[1].is_err());
+
+        // A second writer is read as well as the first
+        let mut writer = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        writer.write_all(b"seed 42\n").unwrap();
+        drop(writer);
+        assert_eq!(received(&fifo, 1), [Ok(Command::Seed(42))]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_a_file_that_isnt_a_pipe() {
+        let path = std::env::temp_dir().join(format!("snowflake-fifo-{}.txt", std::process::id()));
+        std::fs::write(&path, "symbol arch\n").unwrap();
+        let err = Fifo::open(&path).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
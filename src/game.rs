@@ -0,0 +1,281 @@
+use crossterm::{
+    cursor,
+    event::KeyCode,
+    queue,
+    style::{self, Color},
+};
+use std::io::{self, Write};
+
+use crate::{Bouncer, Rect, rng};
+
+// --- Versus ---
+/// Two-player keep-away: each player guards one side wall with a paddle,
+/// and scores when the flake gets past the other player's.
+///
+/// The field's top row holds the score; the paddles run down its left and
+/// right columns, and the flake bounces in between.
+pub struct Versus {
+    field: Rect,
+    /// Top row of each paddle, left then right
+    paddles: (u16, u16),
+    scores: (u32, u32),
+}
+
+/// How many rows each paddle covers.
+pub const PADDLE_HEIGHT: u16 = 5;
+
+impl Versus {
+    #[must_use]
+    pub const fn new(field: Rect) -> Self {
+        let mut game = Self {
+            field,
+            paddles: (0, 0),
+            scores: (0, 0),
+        };
+        game.resize(field);
+        game
+    }
+
+    /// Moves the game into a new field, recentering the paddles.
+    pub const fn resize(&mut self, field: Rect) {
+        self.field = field;
+        let middle = self.playfield().y + self.playfield().height.saturating_sub(PADDLE_HEIGHT) / 2;
+        self.paddles = (middle, middle);
+    }
+
+    // Rows below the scoreboard
+    const fn playfield(&self) -> Rect {
+        Rect::new(
+            self.field.x,
+            self.field.y.saturating_add(1),
+            self.field.width,
+            self.field.height.saturating_sub(1),
+        )
+    }
+
+    /// Where the flake may go: the playfield between the two paddle columns.
+    #[must_use]
+    pub const fn ball_area(&self) -> Rect {
+        let field = self.playfield();
+        Rect::new(
+            field.x.saturating_add(1),
+            field.y,
+            field.width.saturating_sub(2),
+            field.height,
+        )
+    }
+
+    /// Left and right scores.
+    #[must_use]
+    pub const fn scores(&self) -> (u32, u32) {
+        self.scores
+    }
+
+    /// Moves a paddle for `w`/`s` (left) or Up/Down (right); returns
+    /// false for keys the game doesn't use.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        let top = self.playfield().y;
+        let bottom = (top + self.playfield().height).saturating_sub(PADDLE_HEIGHT);
+        let (paddle, step) = match code {
+            KeyCode::Char('w') => (&mut self.paddles.0, -1),
+            KeyCode::Char('s') => (&mut self.paddles.0, 1),
+            KeyCode::Up => (&mut self.paddles.1, -1),
+            KeyCode::Down => (&mut self.paddles.1, 1),
+            _ => return false,
+        };
+        *paddle = paddle
+            .saturating_add_signed(step)
+            .clamp(top, bottom.max(top));
+        true
+    }
+
+    /// Scores a side-wall hit the paddle missed, then serves again from the center.
+    ///
+    /// `before` is the flake's velocity before its last update; a flipped
+    /// horizontal direction means it just hit a side wall.
+    pub fn referee(&mut self, bouncer: &mut Bouncer, before: (i32, i32)) {
+        let (dx, _) = bouncer.velocity();
+        if dx.signum() == before.0.signum() {
+            return;
+        }
+
+        let (_, y) = bouncer.position();
+        let (_, h) = bouncer.logo_size();
+        let covers = |top: u16| y < top + PADDLE_HEIGHT && top < y + h;
+        // Moving left before the flip means it hit the left wall
+        if before.0 < 0 && !covers(self.paddles.0) {
+            self.scores.1 += 1;
+            self.serve(bouncer);
+        } else if before.0 > 0 && !covers(self.paddles.1) {
+            self.scores.0 += 1;
+            self.serve(bouncer);
+        }
+    }
+
+    fn serve(&self, bouncer: &mut Bouncer) {
+        let area = self.ball_area();
+        let (w, h) = bouncer.logo_size();
+        bouncer.set_position(
+            area.x + area.width.saturating_sub(w) / 2,
+            area.y + area.height.saturating_sub(h) / 2,
+        );
+        let dir = |flip: bool| if flip { 1 } else { -1 };
+        bouncer.set_velocity(dir(rng()), dir(rng()));
+    }
+
+    /// Draws the scoreboard and both paddles.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&self, w: &mut impl Write) -> io::Result<()> {
+        let score = format!("P1 {}  :  {} P2", self.scores.0, self.scores.1);
+        let len = u16::try_from(score.len()).unwrap_or(u16::MAX);
+        let left = self.field.x + self.field.width.saturating_sub(len) / 2;
+        queue!(
+            w,
+            cursor::MoveTo(left, self.field.y),
+            style::SetForegroundColor(Color::White),
+            style::Print(score)
+        )?;
+
+        let field = self.playfield();
+        let right = (field.x + field.width).saturating_sub(1);
+        for row in field.y..field.y + field.height {
+            let cell = |top: u16| {
+                if (top..top + PADDLE_HEIGHT).contains(&row) {
+                    '█'
+                } else {
+                    ' '
+                }
+            };
+            queue!(
+                w,
+                cursor::MoveTo(field.x, row),
+                style::Print(cell(self.paddles.0)),
+                cursor::MoveTo(right, row),
+                style::Print(cell(self.paddles.1))
+            )?;
+        }
+        queue!(w, style::ResetColor)?;
+        Ok(())
+    }
+}
+
+// --- Snake ---
+/// Tron-style survival: the flake leaves a trail behind it, and the run is
+/// over as soon as it moves onto any part of that trail.
+///
+/// The trail is a buffer with one cell per screen cell of the area; the
+/// score is how many cells it covers.
+pub struct Snake {
+    area: Rect,
+    trail: Vec<bool>,
+    score: u32,
+    /// Trail cells laid since the last draw
+    fresh: Vec<(u16, u16)>,
+    /// Repaint the whole trail, e.g. after the screen was cleared
+    redraw: bool,
+}
+
+impl Snake {
+    #[must_use]
+    pub fn new(area: Rect) -> Self {
+        let mut game = Self {
+            area,
+            trail: Vec::new(),
+            score: 0,
+            fresh: Vec::new(),
+            redraw: false,
+        };
+        game.resize(area);
+        game
+    }
+
+    /// Moves the game into a new area. The old trail doesn't fit, so this
+    /// starts over.
+    pub fn resize(&mut self, area: Rect) {
+        self.area = area;
+        self.reset();
+    }
+
+    /// Clears the trail and score for another run.
+    pub fn reset(&mut self) {
+        self.trail = vec![false; usize::from(self.area.width) * usize::from(self.area.height)];
+        self.score = 0;
+        self.fresh.clear();
+    }
+
+    /// Cells covered by the trail.
+    #[must_use]
+    pub const fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Repaints the whole trail on the next [`draw`](Self::draw).
+    pub const fn redraw(&mut self) {
+        self.redraw = true;
+    }
+
+    // Index into `trail`, for cells inside the area
+    fn cell(&self, x: u16, y: u16) -> Option<usize> {
+        self.area.contains(x, y).then(|| {
+            usize::from(y - self.area.y) * usize::from(self.area.width)
+                + usize::from(x - self.area.x)
+        })
+    }
+
+    /// Lays trail where the flake just left and checks where it arrived;
+    /// returns true if it ran into its own trail.
+    ///
+    /// `before` is the flake's position before its last update.
+    pub fn step(&mut self, bouncer: &Bouncer, before: (u16, u16)) -> bool {
+        let (width, height) = bouncer.logo_size();
+        let (x, y) = bouncer.position();
+        let old = Rect::new(before.0, before.1, width, height);
+        let new = Rect::new(x, y, width, height);
+
+        // Cells the logo still covers are neither trail nor a collision
+        let mut crashed = false;
+        for row in old.y.min(new.y)..old.y.max(new.y).saturating_add(height) {
+            for col in old.x.min(new.x)..old.x.max(new.x).saturating_add(width) {
+                let Some(i) = self.cell(col, row) else {
+                    continue;
+                };
+                match (old.contains(col, row), new.contains(col, row)) {
+                    (true, false) if !self.trail[i] => {
+                        self.trail[i] = true;
+                        self.score += 1;
+                        self.fresh.push((col, row));
+                    }
+                    (false, true) => crashed |= self.trail[i],
+                    _ => {}
+                }
+            }
+        }
+        crashed
+    }
+
+    /// Draws the trail laid since the last call, or all of it after
+    /// [`redraw`](Self::redraw).
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&mut self, w: &mut impl Write) -> io::Result<()> {
+        if self.redraw {
+            self.redraw = false;
+            self.fresh.clear();
+            for (i, _) in self.trail.iter().enumerate().filter(|&(_, &t)| t) {
+                let width = usize::from(self.area.width);
+                let col = u16::try_from(i % width).unwrap_or(u16::MAX);
+                let row = u16::try_from(i / width).unwrap_or(u16::MAX);
+                self.fresh.push((self.area.x + col, self.area.y + row));
+            }
+        }
+        queue!(w, style::SetForegroundColor(Color::DarkGrey))?;
+        for (col, row) in self.fresh.drain(..) {
+            queue!(w, cursor::MoveTo(col, row), style::Print('•'))?;
+        }
+        queue!(w, style::ResetColor)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use gilrs::{Axis, Button, EventType, Gilrs};
+use std::io;
+
+use crate::keymap::Action;
+
+// --- Gamepad Input ---
+/// Every connected gamepad, read without blocking once per frame.
+///
+/// Buttons map to the same actions as keys: South (A/Cross) cycles the
+/// symbol and West goes back, East cycles the color, Start shows the help
+/// and the D-pad pushes the velocity. The left stick nudges the symbol, and
+/// the triggers speed it up or slow it down.
+pub struct Gamepad {
+    gilrs: Gilrs,
+}
+
+// Stick deflection below this is treated as resting
+const DEAD_ZONE: f32 = 0.5;
+// A trigger held all the way multiplies (or divides) the speed by this
+const TRIGGER_RANGE: f64 = 3.0;
+
+impl Gamepad {
+    /// Starts listening for gamepads, including ones plugged in later.
+    ///
+    /// # Errors
+    /// Returns an error if the platform's gamepad support can't start.
+    pub fn open() -> io::Result<Self> {
+        let gilrs = Gilrs::new().map_err(|e| io::Error::other(format!("gamepad: {e}")))?;
+        Ok(Self { gilrs })
+    }
+
+    /// Drains pending events and returns the actions for buttons pressed since
+    /// the last call.
+    pub fn actions(&mut self) -> Vec<Action> {
+        let mut actions = Vec::new();
+        while let Some(event) = self.gilrs.next_event() {
+            let EventType::ButtonPressed(button, _) = event.event else {
+                continue;
+            };
+            actions.extend(match button {
+                Button::South => Some(Action::CycleSymbol),
+                Button::West => Some(Action::CycleSymbolBack),
+                Button::East => Some(Action::CycleColor),
+                Button::Start => Some(Action::Help),
+                Button::DPadLeft => Some(Action::PushLeft),
+                Button::DPadRight => Some(Action::PushRight),
+                Button::DPadUp => Some(Action::PushUp),
+                Button::DPadDown => Some(Action::PushDown),
+                _ => None,
+            });
+        }
+        actions
+    }
+
+    /// Which way the left stick points, one cell per axis, or `(0, 0)` at rest.
+    ///
+    /// Screen rows grow downward, so pushing the stick up gives a negative `dy`.
+    #[must_use]
+    pub fn stick(&self) -> (i32, i32) {
+        let (x, y) = self.gilrs.gamepads().fold((0.0, 0.0), |(x, y), (_, pad)| {
+            (
+                x + pad.value(Axis::LeftStickX),
+                y + pad.value(Axis::LeftStickY),
+            )
+        });
+        let cell = |v: f32| {
+            if v > DEAD_ZONE {
+                1
+            } else if v < -DEAD_ZONE {
+                -1
+            } else {
+                0
+            }
+        };
+        (cell(x), -cell(y))
+    }
+
+    /// How many times faster than normal to run: up to three times as fast with
+    /// the right trigger held down, a third as fast with the left.
+    #[must_use]
+    pub fn throttle(&self) -> f64 {
+        let trigger = |button| {
+            self.gilrs
+                .gamepads()
+                .filter_map(|(_, pad)| {
+                    pad.button_data(button)
+                        .map(gilrs::ev::state::ButtonData::value)
+                })
+                .fold(0.0_f32, f32::max)
+        };
+        let faster = f64::from(trigger(Button::RightTrigger2)).mul_add(TRIGGER_RANGE - 1.0, 1.0);
+        let slower = f64::from(trigger(Button::LeftTrigger2)).mul_add(TRIGGER_RANGE - 1.0, 1.0);
+        faster / slower
+    }
+}
@@ -0,0 +1,159 @@
+use std::io;
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use crate::banner;
+
+// --- Git Status ---
+/// How often [`watch`] runs `git status` again.
+pub const REFRESH: Duration = Duration::from_secs(10);
+
+/// The state of the repository the program was started in.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Status {
+    /// The branch checked out, or `None` with a detached HEAD
+    pub branch: Option<String>,
+    /// The first few characters of the commit checked out, if there is one yet
+    pub commit: Option<String>,
+    /// Commits ahead of and behind the upstream, if the branch has one
+    pub ahead_behind: Option<(u32, u32)>,
+    /// Files changed, staged or untracked
+    pub dirty: usize,
+}
+
+impl Status {
+    /// A small card of the status for
+    /// [`Bouncer::set_banner`](crate::Bouncer::set_banner): the branch,
+    /// how far it is from its upstream, and how many files are dirty.
+    #[must_use]
+    pub fn card(&self, ascii: bool) -> Vec<String> {
+        let head = match (&self.branch, &self.commit) {
+            (Some(branch), _) if ascii => format!("branch {branch}"),
+            (Some(branch), _) => format!("⎇ {branch}"),
+            (None, Some(commit)) => format!("detached at {commit}"),
+            (None, None) => "detached".to_string(),
+        };
+        let upstream = match self.ahead_behind {
+            Some((ahead, behind)) if ascii => format!("ahead {ahead}, behind {behind}"),
+            Some((ahead, behind)) => format!("↑{ahead} ↓{behind}"),
+            None => "no upstream".to_string(),
+        };
+        let dirty = match self.dirty {
+            0 => "clean".to_string(),
+            1 => "1 dirty file".to_string(),
+            n => format!("{n} dirty files"),
+        };
+        banner::framed(&[&head, &upstream, &dirty], ascii)
+    }
+}
+
+/// Runs `git status` in the current directory.
+///
+/// # Errors
+/// Returns an error if git can't be run, or with what it said if the
+/// directory isn't inside a repository.
+pub fn status() -> io::Result<Status> {
+    let output = Command::new("git")
+        .args(["status", "--porcelain=v2", "--branch"])
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("git: {e}")))?;
+    if !output.status.success() {
+        let said = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!("git: {}", said.trim())));
+    }
+    Ok(parse(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Reads `git status --porcelain=v2 --branch` output.
+#[must_use]
+pub fn parse(text: &str) -> Status {
+    let mut status = Status::default();
+    for line in text.lines() {
+        if let Some(header) = line.strip_prefix("# ") {
+            let (key, value) = header.split_once(' ').unwrap_or((header, ""));
+            match key {
+                "branch.oid" if value != "(initial)" => {
+                    status.commit = Some(value.chars().take(7).collect());
+                }
+                "branch.head" if value != "(detached)" => {
+                    status.branch = Some(value.to_string());
+                }
+                "branch.ab" => {
+                    let mut counts = value
+                        .split_whitespace()
+                        .map(|n| n.trim_start_matches(['+', '-']).parse().ok());
+                    if let (Some(Some(ahead)), Some(Some(behind))) = (counts.next(), counts.next())
+                    {
+                        status.ahead_behind = Some((ahead, behind));
+                    }
+                }
+                _ => {}
+            }
+        } else if !line.is_empty() && !line.starts_with('!') {
+            // Changed, renamed, unmerged and untracked entries, one per file
+            status.dirty += 1;
+        }
+    }
+    status
+}
+
+/// Runs [`status`] again every [`REFRESH`] on a background thread, so a git
+/// that's slow on a big repository never holds up the animation.
+///
+/// Only statuses that differ from the one before are sent, starting from
+/// `last`. Failed runs are skipped; the card keeps showing the last status.
+#[must_use]
+pub fn watch(mut last: Status) -> Receiver<Status> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        loop {
+            thread::sleep(REFRESH);
+            let Ok(status) = status() else {
+                continue;
+            };
+            if status != last {
+                if tx.send(status.clone()).is_err() {
+                    // Nobody is watching any more
+                    return;
+                }
+                last = status;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_shows_the_branch_upstream_and_dirty_files() {
+        let status = Status {
+            branch: Some("main".to_string()),
+            commit: Some("abc1234".to_string()),
+            ahead_behind: Some((2, 1)),
+            dirty: 3,
+        };
+        assert_eq!(
+            status.card(true),
+            [
+                "+-------------------+",
+                "| branch main       |",
+                "| ahead 2, behind 1 |",
+                "| 3 dirty files     |",
+                "+-------------------+",
+            ]
+        );
+        let detached = Status {
+            commit: Some("abc1234".to_string()),
+            ..Status::default()
+        };
+        let card = detached.card(false);
+        assert_eq!(card[1], "│ detached at abc1234 │");
+        assert_eq!(card[2], "│ no upstream         │");
+        assert_eq!(card[3], "│ clean               │");
+    }
+}
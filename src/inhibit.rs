@@ -0,0 +1,56 @@
+use std::io;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+// --- Sleep Inhibitor ---
+// How long systemd-inhibit gets to fail before the lock counts as taken
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// A systemd-logind inhibitor lock against idling and sleep, held for as
+/// long as this is, so the screen doesn't blank in the middle of a demo.
+///
+/// The lock belongs to a `systemd-inhibit` child waiting on its stdin. It's
+/// let go on drop, and even if this process dies without dropping it, since
+/// the child then reads end of file and quits.
+pub struct Inhibitor {
+    child: Child,
+}
+
+impl Inhibitor {
+    /// Takes the lock.
+    ///
+    /// # Errors
+    /// Returns an error if `systemd-inhibit` isn't installed or logind won't
+    /// hand out the lock, as without systemd.
+    pub fn take() -> io::Result<Self> {
+        let mut child = Command::new("systemd-inhibit")
+            .args([
+                "--what=idle:sleep",
+                "--who=snowflake-bounce",
+                "--why=Bouncing a logo",
+                "--mode=block",
+                "cat",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| io::Error::new(e.kind(), format!("systemd-inhibit: {e}")))?;
+        thread::sleep(SETTLE);
+        if let Some(status) = child.try_wait()? {
+            return Err(io::Error::other(format!(
+                "systemd-inhibit couldn't take the lock ({status})"
+            )));
+        }
+        Ok(Self { child })
+    }
+}
+
+impl Drop for Inhibitor {
+    fn drop(&mut self) {
+        // Closing its stdin lets it finish by itself; waiting reaps it
+        drop(self.child.stdin.take());
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,463 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+// --- Actions ---
+/// Something the user can ask the animation to do, independent of which key does it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    Quit,
+    CycleColor,
+    CycleSymbol,
+    CycleSymbolBack,
+    MiddleFinger,
+    Arch,
+    PickSymbol,
+    PickColor,
+    Help,
+    CommandPrompt,
+    ReloadConfig,
+    NudgeLeft,
+    NudgeDown,
+    NudgeUp,
+    NudgeRight,
+    PushLeft,
+    PushDown,
+    PushUp,
+    PushRight,
+}
+
+impl Action {
+    /// Every action, in the order the help overlay lists them.
+    pub const ALL: [Self; 19] = [
+        Self::CycleColor,
+        Self::PickColor,
+        Self::CycleSymbol,
+        Self::CycleSymbolBack,
+        Self::PickSymbol,
+        Self::Arch,
+        Self::MiddleFinger,
+        Self::NudgeLeft,
+        Self::NudgeDown,
+        Self::NudgeUp,
+        Self::NudgeRight,
+        Self::PushLeft,
+        Self::PushDown,
+        Self::PushUp,
+        Self::PushRight,
+        Self::CommandPrompt,
+        Self::ReloadConfig,
+        Self::Help,
+        Self::Quit,
+    ];
+
+    /// The name used for this action in the config file.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::CycleColor => "cycle_color",
+            Self::CycleSymbol => "cycle_symbol",
+            Self::CycleSymbolBack => "cycle_symbol_back",
+            Self::MiddleFinger => "middle_finger",
+            Self::Arch => "arch",
+            Self::PickSymbol => "pick_symbol",
+            Self::PickColor => "pick_color",
+            Self::Help => "help",
+            Self::CommandPrompt => "command_prompt",
+            Self::ReloadConfig => "reload_config",
+            Self::NudgeLeft => "nudge_left",
+            Self::NudgeDown => "nudge_down",
+            Self::NudgeUp => "nudge_up",
+            Self::NudgeRight => "nudge_right",
+            Self::PushLeft => "push_left",
+            Self::PushDown => "push_down",
+            Self::PushUp => "push_up",
+            Self::PushRight => "push_right",
+        }
+    }
+
+    /// A short description for the help overlay.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::CycleColor => "change color",
+            Self::CycleSymbol => "next symbol",
+            Self::CycleSymbolBack => "previous symbol",
+            Self::MiddleFinger => "easter egg",
+            Self::Arch => "Arch Linux logo",
+            Self::PickSymbol => "pick a symbol",
+            Self::PickColor => "pick a color or theme",
+            Self::Help => "show this help",
+            Self::CommandPrompt => "type a command",
+            Self::ReloadConfig => "reload the config file",
+            Self::NudgeLeft => "nudge left",
+            Self::NudgeDown => "nudge down",
+            Self::NudgeUp => "nudge up",
+            Self::NudgeRight => "nudge right",
+            Self::PushLeft => "push velocity left",
+            Self::PushDown => "push velocity down",
+            Self::PushUp => "push velocity up",
+            Self::PushRight => "push velocity right",
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|a| a.name() == s)
+            .ok_or_else(|| format!("unknown action `{s}`"))
+    }
+}
+
+// --- Keys ---
+/// A key plus the modifiers that matter for matching it.
+///
+/// Shift is folded into the character itself (`S` rather than `Shift+s`),
+/// so it's only kept for non-character keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl Key {
+    #[must_use]
+    pub const fn new(code: KeyCode) -> Self {
+        Self {
+            code,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    const fn normalized(code: KeyCode, mut modifiers: KeyModifiers) -> Self {
+        if let KeyCode::Char(_) = code {
+            modifiers = modifiers.difference(KeyModifiers::SHIFT);
+        }
+        Self {
+            code,
+            modifiers: modifiers.intersection(
+                KeyModifiers::CONTROL
+                    .union(KeyModifiers::ALT)
+                    .union(KeyModifiers::SHIFT),
+            ),
+        }
+    }
+}
+
+impl From<KeyEvent> for Key {
+    fn from(event: KeyEvent) -> Self {
+        Self::normalized(event.code, event.modifiers)
+    }
+}
+
+impl FromStr for Key {
+    type Err = String;
+
+    /// Parses names like `q`, `Esc`, `Tab`, `F1` or `Ctrl+c`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = s;
+        // Split modifiers off the front, leaving a lone `+` usable as a key
+        while let Some((m, tail)) = rest.split_once('+').filter(|(_, t)| !t.is_empty()) {
+            modifiers |= match m.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier `{m}` in `{s}`")),
+            };
+            rest = tail;
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match rest.to_ascii_lowercase().as_str() {
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "enter" | "return" => KeyCode::Enter,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                f if f.starts_with('f') => f[1..]
+                    .parse()
+                    .map(KeyCode::F)
+                    .map_err(|_| format!("unknown key `{s}`"))?,
+                _ => return Err(format!("unknown key `{s}`")),
+            },
+        };
+        Ok(Self::normalized(code, modifiers))
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::BackTab => write!(f, "BackTab"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+// --- Keymap ---
+/// Translates key presses into [`Action`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: BTreeMap<Action, Vec<Key>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bind = |keys: &[KeyCode]| keys.iter().map(|&c| Key::new(c)).collect();
+        let bindings = BTreeMap::from([
+            (Action::Quit, bind(&[KeyCode::Char('q'), KeyCode::Esc])),
+            (Action::CycleColor, bind(&[KeyCode::Char('c')])),
+            (Action::CycleSymbol, bind(&[KeyCode::Char('s')])),
+            // Shift+S already opens the picker
+            (
+                Action::CycleSymbolBack,
+                vec![Key {
+                    code: KeyCode::Char('s'),
+                    modifiers: KeyModifiers::ALT,
+                }],
+            ),
+            (Action::MiddleFinger, bind(&[KeyCode::Char('f')])),
+            (Action::Arch, bind(&[KeyCode::Char('a')])),
+            (Action::PickSymbol, bind(&[KeyCode::Char('S')])),
+            (Action::PickColor, bind(&[KeyCode::Char('C')])),
+            (Action::Help, bind(&[KeyCode::Char('?')])),
+            (Action::CommandPrompt, bind(&[KeyCode::Char(':')])),
+            (Action::ReloadConfig, bind(&[KeyCode::Char('r')])),
+            (Action::NudgeLeft, bind(&[KeyCode::Char('h')])),
+            (Action::NudgeDown, bind(&[KeyCode::Char('j')])),
+            (Action::NudgeUp, bind(&[KeyCode::Char('k')])),
+            (Action::NudgeRight, bind(&[KeyCode::Char('l')])),
+            (Action::PushLeft, bind(&[KeyCode::Char('H')])),
+            (Action::PushDown, bind(&[KeyCode::Char('J')])),
+            (Action::PushUp, bind(&[KeyCode::Char('K')])),
+            (Action::PushRight, bind(&[KeyCode::Char('L')])),
+        ]);
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// Replaces the keys bound to an action.
+    pub fn bind(&mut self, action: Action, keys: Vec<Key>) {
+        self.bindings.insert(action, keys);
+    }
+
+    /// Applies `action = key(s)` overrides, e.g. from the config file's `[keys]` table.
+    ///
+    /// # Errors
+    /// Returns a message naming the first unknown action or unparseable key,
+    /// or a key that ends up bound to two actions along with both of them.
+    pub fn apply<'a>(
+        &mut self,
+        overrides: impl IntoIterator<Item = (&'a str, &'a [String])>,
+    ) -> Result<(), String> {
+        for (name, keys) in overrides {
+            let action = name.parse()?;
+            let keys = keys.iter().map(|k| k.parse()).collect::<Result<_, _>>()?;
+            self.bind(action, keys);
+        }
+        match self.clash() {
+            Some((key, first, second)) => Err(format!(
+                "`{key}` is bound to both {} and {}",
+                first.name(),
+                second.name()
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // The first key bound to two actions, with both of them; otherwise a
+    // press would only ever do whichever comes first
+    fn clash(&self) -> Option<(Key, Action, Action)> {
+        let mut bound = HashMap::new();
+        for (&action, keys) in &self.bindings {
+            for &key in keys {
+                if let Some(first) = bound.insert(key, action)
+                    && first != action
+                {
+                    return Some((key, first, action));
+                }
+            }
+        }
+        None
+    }
+
+    /// The action bound to a key press, if any.
+    #[must_use]
+    pub fn action(&self, event: KeyEvent) -> Option<Action> {
+        let key = Key::from(event);
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(&key))
+            .map(|(&action, _)| action)
+    }
+
+    /// The keys bound to an action.
+    #[must_use]
+    pub fn keys(&self, action: Action) -> &[Key] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+}
+
+// --- Key Sequences ---
+/// ↑ ↑ ↓ ↓ ← → ← → B A
+pub const KONAMI: [KeyCode; 10] = [
+    KeyCode::Up,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Char('b'),
+    KeyCode::Char('a'),
+];
+
+/// Watches key presses for a fixed sequence typed in a row.
+#[derive(Debug, Clone)]
+pub struct Sequence {
+    pattern: &'static [KeyCode],
+    recent: VecDeque<KeyCode>,
+}
+
+impl Sequence {
+    #[must_use]
+    pub fn new(pattern: &'static [KeyCode]) -> Self {
+        Self {
+            pattern,
+            recent: VecDeque::with_capacity(pattern.len()),
+        }
+    }
+
+    /// Records a key press; returns true when it completes the sequence.
+    pub fn feed(&mut self, code: KeyCode) -> bool {
+        if self.recent.len() == self.pattern.len() {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(code);
+        if self.recent.iter().eq(self.pattern) {
+            self.recent.clear();
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(overrides: &[(&str, &[&str])]) -> Result<Keymap, String> {
+        let owned: Vec<(&str, Vec<String>)> = overrides
+            .iter()
+            .map(|&(name, keys)| (name, keys.iter().map(ToString::to_string).collect()))
+            .collect();
+        let mut keymap = Keymap::default();
+        keymap.apply(owned.iter().map(|(name, keys)| (*name, keys.as_slice())))?;
+        Ok(keymap)
+    }
+
+    #[test]
+    fn parses_key_names_and_modifiers() {
+        let key = |code, modifiers| Key { code, modifiers };
+        assert_eq!("q".parse(), Ok(Key::new(KeyCode::Char('q'))));
+        assert_eq!("Esc".parse(), Ok(Key::new(KeyCode::Esc)));
+        assert_eq!("pagedown".parse(), Ok(Key::new(KeyCode::PageDown)));
+        assert_eq!("F12".parse(), Ok(Key::new(KeyCode::F(12))));
+        assert_eq!("Space".parse(), Ok(Key::new(KeyCode::Char(' '))));
+        assert_eq!(
+            "Ctrl+Alt+x".parse(),
+            Ok(key(
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            ))
+        );
+        // A lone `+` is a key, and Shift is folded into characters
+        assert_eq!(
+            "Alt++".parse(),
+            Ok(key(KeyCode::Char('+'), KeyModifiers::ALT))
+        );
+        assert_eq!("Shift+S".parse(), Ok(Key::new(KeyCode::Char('S'))));
+        assert_eq!(
+            "Shift+Tab".parse(),
+            Ok(key(KeyCode::Tab, KeyModifiers::SHIFT))
+        );
+        assert_eq!(
+            "Hyper+x".parse::<Key>(),
+            Err("unknown modifier `Hyper` in `Hyper+x`".to_string())
+        );
+        for bad in ["", "nope", "Fx"] {
+            assert_eq!(bad.parse::<Key>(), Err(format!("unknown key `{bad}`")));
+        }
+    }
+
+    #[test]
+    fn keys_display_as_they_parse() {
+        for name in ["q", "Esc", "F1", "Ctrl+c", "Alt+s", "Space"] {
+            let key: Key = name.parse().unwrap();
+            assert_eq!(key.to_string().parse(), Ok(key), "{name}");
+        }
+    }
+
+    #[test]
+    fn overrides_replace_an_actions_keys() {
+        let keymap = apply(&[("help", &["F1", "?"])]).unwrap();
+        assert_eq!(
+            keymap.keys(Action::Help),
+            [Key::new(KeyCode::F(1)), Key::new(KeyCode::Char('?'))]
+        );
+        let press = KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE);
+        assert_eq!(keymap.action(press), Some(Action::Help));
+        assert_eq!(
+            apply(&[("fly", &["x"])]).unwrap_err(),
+            "fly".parse::<Action>().unwrap_err()
+        );
+        assert!(apply(&[("help", &["Hyper+x"])]).is_err());
+    }
+
+    #[test]
+    fn rejects_a_key_bound_to_two_actions() {
+        let err = apply(&[("quit", &["c"])]).unwrap_err();
+        assert_eq!(err, "`c` is bound to both quit and cycle_color");
+        // Moving the other action off the key first is fine
+        let keymap = apply(&[("quit", &["c"]), ("cycle_color", &["x"])]).unwrap();
+        assert_eq!(keymap.keys(Action::Quit), [Key::new(KeyCode::Char('c'))]);
+        // As is listing a key twice for the same action
+        assert!(apply(&[("help", &["?", "?"])]).is_ok());
+    }
+}
@@ -0,0 +1,134 @@
+use crossterm::{
+    cursor, queue,
+    style::{self, Color},
+};
+use std::io::{self, Write};
+
+use crate::Rect;
+
+// --- Layers ---
+/// A colored character in one cell, or `None` for a blank one.
+pub type Cell = Option<(char, Color)>;
+
+/// Stands in the cell a wide character like an emoji spills into, which
+/// the character itself already covers.
+pub const WIDE_TAIL: char = '\0';
+
+/// Tracks what the screen shows in each cell of an area, so something
+/// repainted every frame only writes the cells that changed.
+///
+/// Build the whole frame as one [`Cell`] per cell of the area, row by row,
+/// and hand it to [`draw`](Self::draw).
+pub struct Layer {
+    area: Rect,
+    shown: Vec<Cell>,
+}
+
+impl Layer {
+    #[must_use]
+    pub fn new(area: Rect) -> Self {
+        Self {
+            area,
+            shown: vec![None; Self::cells(area)],
+        }
+    }
+
+    /// Cells in `area`, the length of a frame for it.
+    #[must_use]
+    pub fn cells(area: Rect) -> usize {
+        usize::from(area.width) * usize::from(area.height)
+    }
+
+    #[must_use]
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Moves into a new area, which starts out blank.
+    pub fn resize(&mut self, area: Rect) {
+        self.area = area;
+        self.shown = vec![None; Self::cells(area)];
+    }
+
+    /// Forgets what's on screen, for after it was cleared.
+    pub fn redraw(&mut self) {
+        self.shown.fill(None);
+    }
+
+    /// Index of a cell inside the area, into a frame.
+    #[must_use]
+    pub fn index(&self, x: u16, y: u16) -> Option<usize> {
+        self.area.contains(x, y).then(|| {
+            usize::from(y - self.area.y) * usize::from(self.area.width)
+                + usize::from(x - self.area.x)
+        })
+    }
+
+    /// Shows `frame`, writing only the cells that differ from the last one.
+    /// Runs of changed cells in the same color go out as one move and one
+    /// print.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    ///
+    /// # Panics
+    /// Panics if `frame` isn't one cell per cell of the area.
+    pub fn draw(&mut self, w: &mut impl Write, frame: Vec<Cell>) -> io::Result<()> {
+        assert_eq!(frame.len(), self.shown.len(), "frame doesn't fit the layer");
+        let width = usize::from(self.area.width);
+        if width == 0 {
+            return Ok(());
+        }
+        let mut run = String::new();
+        let mut run_color = None;
+        for (row, (shown, wanted)) in
+            (self.area.y..).zip(self.shown.chunks(width).zip(frame.chunks(width)))
+        {
+            let mut run_start = self.area.x;
+            for (col, (was, now)) in (self.area.x..).zip(shown.iter().zip(wanted)) {
+                // A change of color ends the run too
+                let color = now.map(|(_, color)| color);
+                if !run.is_empty() && (was == now || color != run_color) {
+                    print_run(w, run_start, row, run_color, &run)?;
+                    run.clear();
+                }
+                // Printing the wide character moved past its tail already
+                let tail = now.is_some_and(|(c, _)| c == WIDE_TAIL);
+                if was != now && !tail {
+                    if run.is_empty() {
+                        run_start = col;
+                        run_color = color;
+                    }
+                    run.push(now.map_or(' ', |(c, _)| c));
+                }
+            }
+            if !run.is_empty() {
+                print_run(w, run_start, row, run_color, &run)?;
+                run.clear();
+            }
+        }
+        self.shown = frame;
+        Ok(())
+    }
+}
+
+// Blanks are printed without a color
+fn print_run(
+    w: &mut impl Write,
+    x: u16,
+    y: u16,
+    color: Option<Color>,
+    run: &str,
+) -> io::Result<()> {
+    queue!(w, cursor::MoveTo(x, y))?;
+    if let Some(color) = color {
+        queue!(
+            w,
+            style::SetForegroundColor(color),
+            style::Print(run),
+            style::ResetColor
+        )
+    } else {
+        queue!(w, style::Print(run))
+    }
+}
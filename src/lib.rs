@@ -1,106 +1,1565 @@
 use crossterm::{
     cursor, queue,
-    style::{self, Color},
+    style::{self, Attribute, Color},
     terminal,
 };
 use rand::distributions::{Distribution, Standard};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
-use std::cell::RefCell;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
 use std::io::{self, Write};
+use std::rc::Rc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::morph::Morph;
+use crate::motion::Motion;
+
+pub mod achievements;
+pub mod app;
+pub mod aquarium;
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod banner;
+#[cfg(any(
+    feature = "window",
+    feature = "xscreensaver",
+    all(feature = "fbdev", target_os = "linux")
+))]
+mod bitmap;
+pub mod collage;
+pub mod command;
+pub mod config;
+pub mod disk;
+#[cfg(all(feature = "fbdev", target_os = "linux"))]
+pub mod fbdev;
+#[cfg(feature = "rss")]
+pub mod feed;
+pub mod fifo;
+pub mod game;
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+pub mod git;
+pub mod inhibit;
+pub mod keymap;
+pub mod layer;
+pub mod meter;
+#[cfg(feature = "midi")]
+pub mod midi;
+pub mod moon;
+pub mod morph;
+pub mod motion;
+pub mod net;
+pub mod pack;
+#[cfg(feature = "price")]
+pub mod price;
+pub mod rainbow;
+pub mod service;
+pub mod session;
+pub mod snow;
+pub mod sound;
+pub mod system;
+pub mod tail;
+pub mod theme;
+pub mod trajectory;
+pub mod ui;
+#[cfg(feature = "weather")]
+pub mod weather;
+#[cfg(feature = "window")]
+pub mod window;
+#[cfg(feature = "xscreensaver")]
+pub mod xscreensaver;
 
 // --- RNG Helper  ---
 thread_local! {
     static RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_entropy());
 }
 
-fn rng<T>() -> T
-where
-    Standard: Distribution<T>,
-{
-    RNG.with(|rng| (*rng).borrow_mut().r#gen::<T>())
-}
+fn rng<T>() -> T
+where
+    Standard: Distribution<T>,
+{
+    RNG.with(|rng| (*rng).borrow_mut().r#gen::<T>())
+}
+
+/// Reseeds this thread's random number generator, making the colors and
+/// start positions that follow repeatable.
+pub fn seed(seed: u64) {
+    RNG.with(|rng| *rng.borrow_mut() = SmallRng::seed_from_u64(seed));
+}
+
+// --- Symbol Enums ---
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolMode {
+    SnowflakeSmall,
+    SnowflakeLarge,
+    NixOS,
+    Arch,
+    Bsd,
+    Apple,
+    Nyan,
+    MiddleFinger,
+    /// Art set with [`Bouncer::set_banner`]. Having none of its own, it's
+    /// left out of [`ALL`](Self::ALL), so cycling and names skip it.
+    Banner,
+    /// One of the [`pack::packs`] loaded at startup, by index
+    Pack(usize),
+}
+
+impl SymbolMode {
+    /// Every built-in symbol, in menu order.
+    pub const ALL: [Self; 8] = [
+        Self::SnowflakeSmall,
+        Self::SnowflakeLarge,
+        Self::NixOS,
+        Self::Arch,
+        Self::Bsd,
+        Self::Apple,
+        Self::Nyan,
+        Self::MiddleFinger,
+    ];
+
+    /// Every available symbol in menu order: the built-in ones, then any
+    /// packs loaded with [`pack::load`].
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        pack::symbols().unwrap_or(&Self::ALL)
+    }
+
+    /// The kebab-case name used on the command line and in the config file.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::SnowflakeSmall => "snowflake-small",
+            Self::SnowflakeLarge => "snowflake-large",
+            Self::NixOS => "nixos",
+            Self::Arch => "arch",
+            Self::Bsd => "bsd",
+            Self::Apple => "apple",
+            Self::Nyan => "nyan",
+            Self::MiddleFinger => "middle-finger",
+            Self::Banner => "banner",
+            Self::Pack(i) => pack::packs().get(i).map_or("pack", |p| p.name),
+        }
+    }
+
+    /// The color a logo is usually seen in, to start in instead of the
+    /// default; `None` for symbols without one.
+    #[must_use]
+    pub fn color(self) -> Option<Color> {
+        match self {
+            Self::NixOS => Some(Color::Blue),
+            Self::Arch => Some(Color::Cyan),
+            Self::Bsd => Some(Color::Red),
+            Self::Apple => Some(Color::Grey),
+            Self::SnowflakeSmall
+            | Self::SnowflakeLarge
+            | Self::Nyan
+            | Self::MiddleFinger
+            | Self::Banner => None,
+            Self::Pack(i) => pack::packs().get(i).and_then(|p| p.color),
+        }
+    }
+
+    /// The colors that go with the symbol, like NixOS's blues, cycled
+    /// through while it's showing unless colors were picked some other way.
+    #[must_use]
+    pub const fn scheme(self) -> Option<theme::Theme> {
+        match self {
+            Self::SnowflakeSmall | Self::SnowflakeLarge => Some(theme::ICE),
+            Self::NixOS => Some(theme::NIXOS),
+            Self::Arch => Some(theme::ARCH),
+            Self::Bsd => Some(theme::BSD),
+            Self::Apple => Some(theme::APPLE),
+            Self::Nyan | Self::MiddleFinger | Self::Banner | Self::Pack(_) => None,
+        }
+    }
+
+    /// The parts of the symbol drawn in a second color, for those that
+    /// have them.
+    #[must_use]
+    pub fn tones(self) -> Option<&'static Tones> {
+        // The darker blue, fitted to the terminal like the scheme it goes with
+        static NIXOS: LazyLock<Tones> = LazyLock::new(|| {
+            let blue = theme::ColorDepth::detect().fit(Color::Rgb {
+                r: 0x52,
+                g: 0x77,
+                b: 0xc3,
+            });
+            Tones::new(NIXOS_TONES, blue)
+        });
+        match self {
+            Self::NixOS => Some(&NIXOS),
+            Self::Pack(i) => pack::packs().get(i).and_then(|p| p.tones.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// The symbol's art, or with `ascii` its plain ASCII stand-in: the
+    /// first frame of one that moves, and nothing for [`Banner`](Self::Banner).
+    #[must_use]
+    pub fn art(self, ascii: bool) -> Art {
+        if ascii {
+            return match self {
+                Self::SnowflakeSmall => &["*"],
+                Self::SnowflakeLarge => &["  *  ", " *** ", "  *  "],
+                Self::MiddleFinger => &["_|_"],
+                Self::Arch => ARCH_ASCII,
+                // Already plain ASCII
+                Self::NixOS => NIXOS_LOGO,
+                Self::Bsd => BSD_DAEMON,
+                Self::Apple => APPLE_LOGO,
+                Self::Nyan => NYAN_FRAMES[0],
+                Self::Banner => &[],
+                // The full art stands in when a pack has no plain version
+                Self::Pack(i) => pack::packs()
+                    .get(i)
+                    .map_or(&[], |p| p.ascii.unwrap_or(p.art)),
+            };
+        }
+        match self {
+            Self::SnowflakeSmall => &["❄"],
+            Self::SnowflakeLarge => &["  ❄  ", " ❄❄❄ ", "  ❄  "],
+            Self::NixOS => NIXOS_LOGO,
+            Self::MiddleFinger => &["🖕"],
+            Self::Arch => ARCH_LOGO,
+            Self::Bsd => BSD_DAEMON,
+            Self::Apple => APPLE_LOGO,
+            Self::Nyan => NYAN_FRAMES[0],
+            Self::Banner => &[],
+            Self::Pack(i) => pack::packs().get(i).map_or(&[], |p| p.art),
+        }
+    }
+
+    /// The small and large sizes of the symbol, for the ones drawn in both.
+    #[must_use]
+    pub const fn sizes(self) -> Option<(Self, Self)> {
+        match self {
+            Self::SnowflakeSmall | Self::SnowflakeLarge => {
+                Some((Self::SnowflakeSmall, Self::SnowflakeLarge))
+            }
+            _ => None,
+        }
+    }
+
+    /// The frames of the symbol turning, for the small ones that can spin.
+    #[must_use]
+    pub const fn spin_frames(self, ascii: bool) -> Option<&'static [Art]> {
+        match (self, ascii) {
+            (Self::SnowflakeSmall, false) => Some(SPIN_SMALL),
+            (Self::SnowflakeSmall, true) => Some(SPIN_SMALL_ASCII),
+            (Self::SnowflakeLarge, false) => Some(SPIN_LARGE),
+            (Self::SnowflakeLarge, true) => Some(SPIN_LARGE_ASCII),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::all().iter().position(|&m| m == self).unwrap_or(0)
+    }
+
+    /// The symbol after this one in [`all`](Self::all), wrapping around.
+    #[must_use]
+    pub fn next(self) -> Self {
+        let all = Self::all();
+        all[(self.index() + 1) % all.len()]
+    }
+
+    /// The symbol before this one in [`all`](Self::all), wrapping around.
+    #[must_use]
+    pub fn prev(self) -> Self {
+        let all = Self::all();
+        all[(self.index() + all.len() - 1) % all.len()]
+    }
+
+    /// Looks a symbol up by [`name`](Self::name), ignoring case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|m| m.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl std::fmt::Display for SymbolMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for SymbolMode {
+    type Err = String;
+
+    /// Parses a [`name`](Self::name) like `arch` or `snowflake-large`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| format!("unknown symbol `{s}`"))
+    }
+}
+
+// By name, so packs are saved the same way as the built-in symbols. The
+// banner has a name too, for resuming a banner run
+impl Serialize for SymbolMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for SymbolMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        if name == Self::Banner.name() {
+            return Ok(Self::Banner);
+        }
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// Lets clap list and complete the same names as everywhere else
+impl clap::ValueEnum for SymbolMode {
+    fn value_variants<'a>() -> &'a [Self] {
+        Self::all()
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(clap::builder::PossibleValue::new(self.name()))
+    }
+}
+
+// --- Play Area ---
+/// A rectangle of terminal cells, with its top-left corner at (`x`, `y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    #[must_use]
+    pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Shrinks the rectangle by `n` cells on every side.
+    #[must_use]
+    pub const fn inset(self, n: u16) -> Self {
+        Self {
+            x: self.x.saturating_add(n),
+            y: self.y.saturating_add(n),
+            width: self.width.saturating_sub(n.saturating_mul(2)),
+            height: self.height.saturating_sub(n.saturating_mul(2)),
+        }
+    }
+
+    /// Whether the cell at (`x`, `y`) is inside the rectangle.
+    #[must_use]
+    pub const fn contains(self, x: u16, y: u16) -> bool {
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+
+    /// The smallest rectangle covering both this one and `other`; an empty
+    /// one covers nothing, wherever it is.
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        if other.width == 0 || other.height == 0 {
+            return self;
+        }
+        if self.width == 0 || self.height == 0 {
+            return other;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = self
+            .x
+            .saturating_add(self.width)
+            .max(other.x.saturating_add(other.width));
+        let bottom = self
+            .y
+            .saturating_add(self.height)
+            .max(other.y.saturating_add(other.height));
+        Self::new(x, y, right - x, bottom - y)
+    }
+
+    /// The part of this rectangle that lies inside `bounds`.
+    #[must_use]
+    pub fn clip(self, bounds: Self) -> Self {
+        let x = self
+            .x
+            .clamp(bounds.x, bounds.x.saturating_add(bounds.width));
+        let y = self
+            .y
+            .clamp(bounds.y, bounds.y.saturating_add(bounds.height));
+        let right = self
+            .x
+            .saturating_add(self.width)
+            .min(bounds.x.saturating_add(bounds.width));
+        let bottom = self
+            .y
+            .saturating_add(self.height)
+            .min(bounds.y.saturating_add(bounds.height));
+        Self {
+            x,
+            y,
+            width: right.saturating_sub(x),
+            height: bottom.saturating_sub(y),
+        }
+    }
+}
+
+impl std::str::FromStr for Rect {
+    type Err = String;
+
+    /// Parses `x,y,width,height`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<u16> = s
+            .split(',')
+            .map(|p| p.trim().parse::<u16>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("`{s}`: {e}"))?;
+        match parts[..] {
+            [x, y, width, height] => Ok(Self::new(x, y, width, height)),
+            _ => Err(format!("`{s}`: expected x,y,width,height")),
+        }
+    }
+}
+
+/// One coordinate of a [`StartPos`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coord {
+    /// Cells in from the play area's left or top edge
+    Cell(u16),
+    /// How far across the room the logo has: 0 touches the left or top
+    /// wall, 100 the right or bottom one
+    Percent(u16),
+}
+
+impl Coord {
+    // Offset from the area's edge, when the logo has `room` cells to move in
+    fn resolve(self, room: u16) -> u16 {
+        match self {
+            Self::Cell(n) => n.min(room),
+            Self::Percent(p) => {
+                u16::try_from(u32::from(room) * u32::from(p.min(100)) / 100).unwrap_or(room)
+            }
+        }
+    }
+}
+
+/// Where the logo starts, for [`Bouncer::place`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartPos {
+    pub x: Coord,
+    pub y: Coord,
+}
+
+impl std::str::FromStr for StartPos {
+    type Err = String;
+
+    /// Parses `x,y`, each a cell count like `10` or a percentage like `50%`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coord = |part: &str| {
+            let part = part.trim();
+            let (digits, percent) = part
+                .strip_suffix('%')
+                .map_or((part, false), |digits| (digits, true));
+            let n: u16 = digits.parse().map_err(|e| format!("`{s}`: {e}"))?;
+            match (percent, n) {
+                (true, 0..=100) => Ok(Coord::Percent(n)),
+                (true, _) => Err(format!("`{s}`: percentages go up to 100%")),
+                (false, _) => Ok(Coord::Cell(n)),
+            }
+        };
+        match s.split(',').collect::<Vec<_>>()[..] {
+            [x, y] => Ok(Self {
+                x: coord(x)?,
+                y: coord(y)?,
+            }),
+            _ => Err(format!("`{s}`: expected x,y")),
+        }
+    }
+}
+
+/// Which way the logo starts moving, in cells per tick on each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Direction {
+    pub dx: i32,
+    pub dy: i32,
+}
+
+impl std::str::FromStr for Direction {
+    type Err = String;
+
+    /// Parses a compass point (`ne`, `sw`, `n`, ...) or `dx,dy` like `2,-1`,
+    /// where negative is left or up.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let compass = match s.trim().to_ascii_lowercase().as_str() {
+            "n" => Some((0, -1)),
+            "ne" => Some((1, -1)),
+            "e" => Some((1, 0)),
+            "se" => Some((1, 1)),
+            "s" => Some((0, 1)),
+            "sw" => Some((-1, 1)),
+            "w" => Some((-1, 0)),
+            "nw" => Some((-1, -1)),
+            _ => None,
+        };
+        let (dx, dy) = if let Some(d) = compass {
+            d
+        } else {
+            let parts: Vec<i32> = s
+                .split(',')
+                .map(|p| p.trim().parse::<i32>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| format!("`{s}`: expected a compass point or dx,dy"))?;
+            match parts[..] {
+                [dx, dy] => (dx, dy),
+                _ => return Err(format!("`{s}`: expected a compass point or dx,dy")),
+            }
+        };
+        let max = Bouncer::MAX_SPEED;
+        if (dx, dy) == (0, 0) {
+            Err(format!("`{s}`: the logo has to move somewhere"))
+        } else if dx.abs() > max || dy.abs() > max {
+            Err(format!("`{s}`: at most {max} cells a tick on each axis"))
+        } else {
+            Ok(Self { dx, dy })
+        }
+    }
+}
+
+// --- Stats ---
+/// Running totals of what a [`Bouncer`] has hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Stats {
+    /// Wall hits; a corner counts as two
+    pub bounces: u64,
+    /// Hits on two walls at once
+    pub corners: u64,
+}
+
+// --- Observers ---
+/// A wall of the play area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wall {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Callbacks for what happens to a [`Bouncer`], so embedders can hang their
+/// own side effects (sounds, logging, stream events) off the animation.
+///
+/// Every method does nothing by default; implement the ones you need and
+/// register with [`Bouncer::add_observer`].
+pub trait Observer {
+    /// The logo hit `wall`. A corner hit reports both walls, then [`on_corner`](Self::on_corner).
+    fn on_bounce(&mut self, _wall: Wall) {}
+    /// The logo hit two walls at once.
+    fn on_corner(&mut self) {}
+    /// The logo changed color, on a bounce or otherwise.
+    fn on_color_change(&mut self, _color: Color) {}
+    /// The play area changed.
+    fn on_resize(&mut self, _area: Rect) {}
+    /// A tick ran, leaving the logo at `sample`.
+    fn on_tick(&mut self, _sample: trajectory::Sample) {}
+}
+
+// --- Logos ---
+/// A symbol's lines of art, static so drawing a frame never allocates it.
+pub type Art = &'static [&'static str];
+
+/// Lines of art as a [`Bouncer`] draws them: a symbol's [`Art`], or art
+/// made while running, like a banner's.
+///
+/// Cloning never copies the lines, so the art of the last frame can be
+/// kept to erase it by, and made art is freed once nothing draws it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lines {
+    Static(Art),
+    Owned(Rc<[String]>),
+}
+
+impl Lines {
+    /// No lines at all.
+    pub const EMPTY: Self = Self::Static(&[]);
+
+    /// How many lines there are.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Static(art) => art.len(),
+            Self::Owned(lines) => lines.len(),
+        }
+    }
+
+    /// Whether there are no lines.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Line `i`, if there is one.
+    #[must_use]
+    pub fn get(&self, i: usize) -> Option<&str> {
+        match self {
+            Self::Static(art) => art.get(i).copied(),
+            Self::Owned(lines) => lines.get(i).map(String::as_str),
+        }
+    }
+
+    /// The lines from the top.
+    #[must_use]
+    pub const fn iter(&self) -> LinesIter<'_> {
+        LinesIter {
+            lines: self,
+            next: 0,
+        }
+    }
+}
+
+impl From<Art> for Lines {
+    fn from(art: Art) -> Self {
+        Self::Static(art)
+    }
+}
+
+impl From<Vec<String>> for Lines {
+    fn from(lines: Vec<String>) -> Self {
+        Self::Owned(lines.into())
+    }
+}
+
+impl<'a> IntoIterator for &'a Lines {
+    type Item = &'a str;
+    type IntoIter = LinesIter<'a>;
+
+    fn into_iter(self) -> LinesIter<'a> {
+        self.iter()
+    }
+}
+
+/// The lines of [`Lines`], from [`Lines::iter`].
+#[derive(Debug, Clone)]
+pub struct LinesIter<'a> {
+    lines: &'a Lines,
+    next: usize,
+}
+
+impl<'a> Iterator for LinesIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let line = self.lines.get(self.next)?;
+        self.next += 1;
+        Some(line)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.lines.len().saturating_sub(self.next);
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for LinesIter<'_> {}
+
+const NIXOS_LOGO: Art = &[
+    "          ::::.    ':::::     ::::'          ",
+    "          ':::::    ':::::.  ::::'           ",
+    "            :::::     '::::.:::::            ",
+    "      .......:::::..... ::::::::             ",
+    "     ::::::::::::::::::. ::::::    ::::.     ",
+    "    ::::::::::::::::::::: :::::.  .::::'     ",
+    "           .....           ::::' :::::'      ",
+    "          :::::            '::' :::::'       ",
+    " ........:::::               ' :::::::::::.  ",
+    ":::::::::::::                 :::::::::::::  ",
+    " ::::::::::: ..              :::::           ",
+    "     .::::: .:::            :::::            ",
+    "    .:::::  :::::          '''''    .....    ",
+    "    :::::   ':::::.  ......:::::::::::::'    ",
+    "     :::     ::::::. ':::::::::::::::::'     ",
+    "            .:::::::: '::::::::::            ",
+    "           .::::''::::.     '::::.           ",
+    "          .::::'   ::::.     '::::.          ",
+    "         .::::      ::::      '::::.         ",
+];
+
+// Which lambdas of the NixOS logo are its darker blue, marked `2`
+const NIXOS_TONES: Art = &[
+    "          11111    222222     22222          ",
+    "          111111    2222222  22222           ",
+    "            11111     22222222222            ",
+    "      11111111111111111 22222222             ",
+    "     1111111111111111111 222222    11111     ",
+    "    111111111111111111111 222222  111111     ",
+    "           22222           22222 111111      ",
+    "          22222            2222 111111       ",
+    " 2222222222222               2 111111111111  ",
+    "2222222222222                 1111111111111  ",
+    " 22222222222 11              22222           ",
+    "     222222 1111            22222            ",
+    "    222222  11111          22222    11111    ",
+    "    22222   1111111  22222222222222222222    ",
+    "     222     1111111 2222222222222222222     ",
+    "            111111111 22222222222            ",
+    "           111111111111     222222           ",
+    "          111111   11111     222222          ",
+    "         11111      1111      222222         ",
+];
+
+const ARCH_LOGO: Art = &[
+    "                      ▄                       ",
+    "                     ▟█▙                      ",
+    "                    ▟███▙                     ",
+    "                   ▟█████▙                    ",
+    "                  ▟███████▙                   ",
+    "                 ▂▔▀▜██████▙                  ",
+    "                ▟██▅▂▝▜█████▙                 ",
+    "               ▟█████████████▙                ",
+    "              ▟███████████████▙               ",
+    "             ▟█████████████████▙              ",
+    "            ▟███████████████████▙             ",
+    "           ▟█████████▛▀▀▜████████▙            ",
+    "          ▟████████▛      ▜███████▙           ",
+    "         ▟█████████        ████████▙          ",
+    "        ▟██████████        █████▆▅▄▃▂         ",
+    "       ▟██████████▛        ▜█████████▙        ",
+    "      ▟██████▀▀▀              ▀▀██████▙       ",
+    "     ▟███▀▘                       ▝▀███▙      ",
+    "    ▟▛▀                               ▀▜▙     ",
+];
+
+// The Arch logo in plain ASCII, for terminals without block characters
+const ARCH_ASCII: Art = &[
+    "                      .                       ",
+    "                     /#\\                      ",
+    "                    /###\\                     ",
+    "                   /#####\\                    ",
+    "                  /#######\\                   ",
+    "                 _-\"#######\\                  ",
+    "                /###_'######\\                 ",
+    "               /#############\\                ",
+    "              /###############\\               ",
+    "             /#################\\              ",
+    "            /###################\\             ",
+    "           /##########\"\"#########\\            ",
+    "          /#########      ########\\           ",
+    "         /#########        ########\\          ",
+    "        /##########        #######.__         ",
+    "       /###########        ##########\\        ",
+    "      /######\"\"\"              \"\"######\\       ",
+    "     /###\"'                       '\"###\\      ",
+    "    /#\"                               \"#\\     ",
+];
+
+// Beastie, the BSD daemon
+const BSD_DAEMON: Art = &[
+    "               ,        ,         ",
+    "              /(        )`        ",
+    "              \\ \\___   / |        ",
+    "              /- _  `-/  '        ",
+    "             (/\\/ \\ \\   /\\        ",
+    "             / /   | `    \\       ",
+    "             O O   ) /    |       ",
+    "             `-^--'`<     '       ",
+    "            (_.)  _  )   /        ",
+    "             `.___/`    /         ",
+    "               `-----' /          ",
+    "  <----.     __ / __   \\          ",
+    "  <----|====O)))==) \\) /====      ",
+    "  <----'    `--' `.__,' \\         ",
+    "               |        |         ",
+    "                \\       /       /\\",
+    "           ______( (_  / \\______/ ",
+    "         ,'  ,-----'   |          ",
+    "         `--{__________)          ",
+];
+
+const APPLE_LOGO: Art = &[
+    "                    'c.       ",
+    "                 ,xNMM.       ",
+    "               .OMMMMo        ",
+    "               OMMM0,         ",
+    "     .;loddo:' loolloddol;.   ",
+    "   cKMMMMMMMMMMNWMMMMMMMMMM0: ",
+    " .KMMMMMMMMMMMMMMMMMMMMMMMWd. ",
+    " XMMMMMMMMMMMMMMMMMMMMMMMX.   ",
+    ";MMMMMMMMMMMMMMMMMMMMMMMM:    ",
+    ":MMMMMMMMMMMMMMMMMMMMMMMM:    ",
+    ".MMMMMMMMMMMMMMMMMMMMMMMMX.   ",
+    " kMMMMMMMMMMMMMMMMMMMMMMMMWd. ",
+    " .XMMMMMMMMMMMMMMMMMMMMMMMMMMk",
+    "  .XMMMMMMMMMMMMMMMMMMMMMMMMK.",
+    "    kMMMMMMMMMMMMMMMMMMMMMMd  ",
+    "     ;KMMMMMMMWXXWMMMMMMMk.   ",
+    "       .cooc,.    .,coo:.     ",
+];
+
+// Nyan cat paddling through the air, one frame per pose. Every frame is the
+// same size, so moving from one to the next only rewrites what changed
+const NYAN_FRAMES: &[Art] = &[
+    &[
+        " ,------,   ",
+        " |   /\\_/\\  ",
+        "~|__( ^ .^) ",
+        "  \"\"  \"\"    ",
+    ],
+    &[
+        " ,------,   ",
+        " |   /\\_/\\  ",
+        "-|__( ^ .^) ",
+        "   \"\" \"\"    ",
+    ],
+];
+
+// The small symbols turning, for `set_spin`: each frame the same size, a
+// snowflake's variants suggesting it rotating
+const SPIN_SMALL: &[Art] = &[&["❄"], &["❅"], &["❆"], &["❅"]];
+const SPIN_SMALL_ASCII: &[Art] = &[&["|"], &["/"], &["-"], &["\\"]];
+const SPIN_LARGE: &[Art] = &[&["  ❄  ", " ❄❄❄ ", "  ❄  "], &[" ❅ ❅ ", "  ❄  ", " ❅ ❅ "]];
+const SPIN_LARGE_ASCII: &[Art] = &[&["  |  ", " -*- ", "  |  "], &[" \\ / ", "  *  ", " / \\ "]];
+
+// Ticks for one slow swing of `set_pulse` from small to large and back
+const PULSE_TICKS: usize = 80;
+
+// Ticks each frame of a multi-frame symbol stays up for
+const FRAME_TICKS: usize = 3;
+
+// Spaces for erasing, printed a slice at a time
+const BLANK: &str = "                                                ";
+
+// Erases `width` cells at the cursor without moving it (ECH), leaving them
+// in the terminal's own background
+fn erase_cells(w: &mut impl Write, width: usize) -> io::Result<()> {
+    if width > 0 {
+        write!(w, "\x1b[{width}X")?;
+    }
+    Ok(())
+}
+
+// Prints `width` spaces at the cursor
+fn print_blank(w: &mut impl Write, mut width: usize) -> io::Result<()> {
+    while width > 0 {
+        let n = width.min(BLANK.len());
+        queue!(w, style::Print(&BLANK[..n]))?;
+        width -= n;
+    }
+    Ok(())
+}
+
+// --- Bouncer Struct ---
+/// A stretch of one line of the banner drawn in a color of its own, like
+/// today's date on a calendar. `col` and `len` count characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accent {
+    pub row: usize,
+    pub col: usize,
+    pub len: usize,
+    pub color: Color,
+}
+
+/// The cells of a symbol drawn in a second color, like the darker lambdas
+/// of the NixOS logo.
+///
+/// Made from a mask the shape of the art, with a `2` in every cell in
+/// the second color; the rest are in the logo's own color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tones {
+    // Per line of the art, whether each character is in `color`: looked up
+    // for every cell of every frame, so worked out once up front
+    mask: Vec<Vec<bool>>,
+    color: Color,
+}
+
+impl Tones {
+    /// Tones with the cells marked `2` in `mask` drawn in `color`.
+    #[must_use]
+    pub fn new(mask: Art, color: Color) -> Self {
+        Self {
+            mask: mask
+                .iter()
+                .map(|line| line.chars().map(|c| c == '2').collect())
+                .collect(),
+            color,
+        }
+    }
+
+    /// The second color.
+    #[must_use]
+    pub const fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Whether the character at `col` of line `row` is in the second color.
+    #[must_use]
+    pub fn is_second(&self, row: usize, col: usize) -> bool {
+        self.mask
+            .get(row)
+            .and_then(|line| line.get(col))
+            .is_some_and(|&second| second)
+    }
+}
+
+// The logo's color, the attribute standing in for it without colors, where
+// clicking it goes, and the parts drawn in other colors
+type Look = (
+    Color,
+    Option<Attribute>,
+    Option<&'static str>,
+    Option<Accent>,
+    Option<&'static Tones>,
+);
+
+// Each bool is a separate drawing choice the caller makes
+#[allow(clippy::struct_excessive_bools)]
+pub struct Bouncer {
+    x: u16,
+    y: u16,
+    prev_x: u16,
+    prev_y: u16,
+    dx: i32,
+    dy: i32,
+    // Manual offset queued by `nudge`, applied on the next `update`
+    nudge: (i32, i32),
+    color: Color,
+    palette: Vec<Color>,
+    // Where going through the palette in order has got to, or `None` to pick at random
+    cycle_index: Option<usize>,
+    min_x: u16,
+    min_y: u16,
+    max_x: u16,
+    max_y: u16,
+    stats: Stats,
+    // Where, in which look and with which art the last draw left the logo
+    drawn: RefCell<Option<(u16, u16, Look, Lines)>>,
+    // Set when the screen may no longer show the last draw, e.g. after a clear
+    stale: Cell<bool>,
+    // Time passed to `step` that didn't add up to a whole tick yet
+    carry: Duration,
+    // Multipliers on each axis's movement, and the part-cells they've built up
+    axis_speed: (f64, f64),
+    axis_carry: (f64, f64),
+    // Share of its speed the logo keeps through a wall hit, and the share
+    // of full speed it's down to
+    restitution: f64,
+    energy: f64,
+    // Part-cells per tick of velocity `pull` has built up on each axis
+    impulse: (f64, f64),
+    observers: Vec<Box<dyn Observer>>,
+    // A path followed instead of bouncing, and ticks along it so far
+    motion: Option<Box<dyn Motion>>,
+    motion_tick: u64,
+    // Draw plain ASCII stand-ins for the symbols
+    ascii: bool,
+    // Show bounces with bold and reverse video instead of colors
+    monochrome: bool,
+    // Draw the logo in bold, for high contrast
+    bold: bool,
+    // Never draw a background, for see-through terminals
+    transparent: bool,
+    // Turn the small symbols as they go
+    spin: bool,
+    // Swing between the symbol's sizes
+    pulse: bool,
+    // Dissolve from one symbol into the next, and the switch under way
+    morphs: bool,
+    morph: RefCell<Option<Morph>>,
+    // The symbol the last draw showed, to tell when it switched
+    drawn_mode: Cell<Option<SymbolMode>>,
+    // Whether the logo was put somewhere since the last draw, not moved there
+    jumped: Cell<bool>,
+    // Colors the logo fades through top to bottom, in place of its own
+    gradient: Option<theme::Gradient>,
+    // Ticks run so far, picking the frame of symbols that have several
+    age: usize,
+    // What `SymbolMode::Banner` draws, and the part of it in its own color
+    banner: Lines,
+    accent: Option<Accent>,
+    // Hyperlink on every symbol, over any a pack has of its own
+    link: Option<&'static str>,
+    pub mode: SymbolMode,
+}
+
+impl Bouncer {
+    /// Fastest speed `bias_velocity` allows on either axis, in cells per tick.
+    pub const MAX_SPEED: i32 = 4;
+
+    /// Slowest and fastest multipliers [`set_axis_speed`](Self::set_axis_speed) takes.
+    pub const AXIS_SPEED_RANGE: (f64, f64) = (0.1, 4.0);
+
+    /// Fastest [`pull`](Self::pull) speeds the logo up to on either axis, in
+    /// cells per tick; keys and the gamepad can still push it faster.
+    pub const PULL_SPEED: i32 = 2;
+
+    /// Least and most bounciness [`set_restitution`](Self::set_restitution) takes.
+    pub const RESTITUTION_RANGE: (f64, f64) = (0.5, 1.0);
+
+    /// Share of full speed a logo losing speed slows to before it's
+    /// launched again at full speed.
+    pub const RELAUNCH_SPEED: f64 = 0.2;
+
+    /// One fixed animation step: moving one cell per tick is 20 cells a second.
+    pub const TICK: Duration = Duration::from_millis(50);
+
+    /// Terminal size, as (columns, lines), [`new`](Self::new) falls back
+    /// to when the real one can't be read.
+    pub const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
+    /// Creates a bouncer sized to the terminal, or
+    /// [`DEFAULT_SIZE`](Self::DEFAULT_SIZE) if its size can't be read.
+    #[must_use]
+    pub fn new() -> Self {
+        let (cols, lines) = terminal::size().unwrap_or(Self::DEFAULT_SIZE);
+        Self::try_new(cols, lines).unwrap_or_else(|_| {
+            let (cols, lines) = Self::DEFAULT_SIZE;
+            Self::sized(cols, lines)
+        })
+    }
+
+    /// Creates a bouncer for a `w` x `h` terminal, starting somewhere random
+    /// where the logo fits. A terminal too small for the logo keeps it in
+    /// the top-left corner rather than failing.
+    ///
+    /// # Errors
+    /// Returns an `InvalidInput` error if `w` or `h` is zero.
+    pub fn try_new(w: u16, h: u16) -> io::Result<Self> {
+        if w == 0 || h == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("a {w}x{h} terminal has no room to draw in"),
+            ));
+        }
+        Ok(Self::sized(w, h))
+    }
+
+    fn sized(w: u16, h: u16) -> Self {
+        let mut bouncer = Self {
+            x: 0,
+            y: 0,
+            prev_x: 0,
+            prev_y: 0,
+            dx: if rng::<bool>() { 1 } else { -1 },
+            dy: if rng::<bool>() { 1 } else { -1 },
+            nudge: (0, 0),
+            color: Color::Blue,
+            palette: theme::DEFAULT.colors.to_vec(),
+            cycle_index: None,
+            min_x: 0,
+            min_y: 0,
+            max_x: 0,
+            max_y: 0,
+            stats: Stats::default(),
+            carry: Duration::ZERO,
+            axis_speed: (1.0, 1.0),
+            axis_carry: (0.0, 0.0),
+            restitution: 1.0,
+            energy: 1.0,
+            impulse: (0.0, 0.0),
+            drawn: RefCell::new(None),
+            stale: Cell::new(false),
+            observers: Vec::new(),
+            motion: None,
+            motion_tick: 0,
+            ascii: false,
+            monochrome: false,
+            bold: false,
+            transparent: false,
+            spin: false,
+            pulse: false,
+            morphs: false,
+            morph: RefCell::new(None),
+            drawn_mode: Cell::new(None),
+            jumped: Cell::new(false),
+            gradient: None,
+            age: 0,
+            banner: Lines::EMPTY,
+            accent: None,
+            link: None,
+            mode: SymbolMode::NixOS,
+        };
+        bouncer.resize(w, h);
+
+        // Anywhere the logo fits without touching a wall
+        let area = bouncer.bounds();
+        let (width, height) = bouncer.logo_size();
+        let offset = |space: u16, size: u16| match space.saturating_sub(size) {
+            0 | 1 => 0,
+            free => 1 + rng::<u16>() % (free - 1),
+        };
+        let x = area.x + offset(area.width, width);
+        let y = area.y + offset(area.height, height);
+        bouncer.set_position(x, y);
+        (bouncer.prev_x, bouncer.prev_y) = (x, y);
+        bouncer
+    }
+
+    /// Switches to the next symbol in [`SymbolMode::all`], wrapping around.
+    pub fn cycle_symbol(&mut self) {
+        self.mode = self.mode.next();
+    }
+
+    /// Switches to the previous symbol in [`SymbolMode::all`], wrapping around.
+    pub fn cycle_symbol_back(&mut self) {
+        self.mode = self.mode.prev();
+    }
+
+    /// Switches to a random symbol other than the current one.
+    pub fn shuffle_symbol(&mut self) {
+        let others: Vec<_> = SymbolMode::all()
+            .iter()
+            .copied()
+            .filter(|&m| m != self.mode)
+            .collect();
+        if !others.is_empty() {
+            self.mode = others[rng::<usize>() % others.len()];
+        }
+    }
+
+    /// Switches to a random color from the [`palette`](Self::palette), or
+    /// to the next one with [`set_in_order`](Self::set_in_order).
+    pub fn cycle_color(&mut self) {
+        if self.palette.is_empty() {
+            return;
+        }
+        let len = self.palette.len();
+        let index = self.cycle_index.map_or_else(rng::<usize>, |i| i + 1) % len;
+        if self.cycle_index.is_some() {
+            self.cycle_index = Some(index);
+        }
+        self.set_color(self.palette[index]);
+    }
+
+    /// Goes through the palette in order from its first color, instead of
+    /// picking at random.
+    pub const fn set_in_order(&mut self, in_order: bool) {
+        self.cycle_index = if in_order { Some(0) } else { None };
+    }
+
+    /// The logo's current color.
+    #[must_use]
+    pub const fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Recolors the logo; the next wall hit still picks from the palette.
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+        self.notify(|o| o.on_color_change(color));
+    }
+
+    /// Registers callbacks for bounces, corners, color changes and resizes.
+    pub fn add_observer(&mut self, observer: impl Observer + 'static) {
+        self.observers.push(Box::new(observer));
+    }
+
+    fn notify(&mut self, mut event: impl FnMut(&mut dyn Observer)) {
+        for observer in &mut self.observers {
+            event(observer.as_mut());
+        }
+    }
+
+    /// Colors that `cycle_color` and wall hits pick from.
+    #[must_use]
+    pub fn palette(&self) -> &[Color] {
+        &self.palette
+    }
+
+    /// Replaces the palette; the current color is kept until the next change.
+    pub fn set_palette(&mut self, colors: &[Color]) {
+        self.palette = colors.to_vec();
+        if self.cycle_index.is_some() {
+            self.cycle_index = Some(0);
+        }
+    }
+
+    /// Top-left corner of the logo.
+    #[must_use]
+    pub const fn position(&self) -> (u16, u16) {
+        (self.x, self.y)
+    }
+
+    /// Moves the logo's top-left corner; the old spot is still erased on the
+    /// next draw. With [`set_morph`](Self::set_morph) on, the logo dissolves
+    /// out of the old spot and into the new one.
+    pub const fn set_position(&mut self, x: u16, y: u16) {
+        *self.jumped.get_mut() |= x != self.x || y != self.y;
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Moves the logo to `at` in the play area, with the far wall as far as
+    /// it goes, so `100%,100%` sits in the bottom-right corner.
+    pub fn place(&mut self, at: StartPos) {
+        let area = self.bounds();
+        let (width, height) = self.logo_size();
+        let x = area.x + at.x.resolve(area.width.saturating_sub(width));
+        let y = area.y + at.y.resolve(area.height.saturating_sub(height));
+        self.set_position(x, y);
+        // Nothing to erase from a spot it never was
+        (self.prev_x, self.prev_y) = (x, y);
+    }
+
+    /// Cells moved per tick, as (`dx`, `dy`).
+    #[must_use]
+    pub const fn velocity(&self) -> (i32, i32) {
+        (self.dx, self.dy)
+    }
+
+    /// Sets the cells moved per tick. Nothing stops either axis being zero,
+    /// but the logo then never bounces off that pair of walls.
+    pub const fn set_velocity(&mut self, dx: i32, dy: i32) {
+        self.dx = dx;
+        self.dy = dy;
+    }
+
+    /// Multipliers on how fast the logo moves along each axis, as (`x`, `y`).
+    #[must_use]
+    pub const fn axis_speed(&self) -> (f64, f64) {
+        self.axis_speed
+    }
+
+    /// Scales movement along each axis on its own, e.g. `(1.0, 0.5)` to
+    /// halve only the vertical speed, which looks right in tall terminals.
+    /// Fractions move the logo a cell on only some ticks. Both are clamped
+    /// to [`AXIS_SPEED_RANGE`](Self::AXIS_SPEED_RANGE).
+    pub const fn set_axis_speed(&mut self, x: f64, y: f64) {
+        let (min, max) = Self::AXIS_SPEED_RANGE;
+        self.axis_speed = (x.clamp(min, max), y.clamp(min, max));
+    }
+
+    /// Scales each axis's [`axis_speed`](Self::axis_speed) by its own
+    /// random factor from `min` to `max`, so logos started together don't
+    /// move in lockstep. The results are clamped like any other.
+    pub fn randomize_axis_speed(&mut self, (min, max): (f64, f64)) {
+        let factor = || (max - min).mul_add(rng::<f64>(), min);
+        let (x, y) = self.axis_speed;
+        self.set_axis_speed(x * factor(), y * factor());
+    }
+
+    /// Share of its speed the logo keeps through each wall hit.
+    #[must_use]
+    pub const fn restitution(&self) -> f64 {
+        self.restitution
+    }
+
+    /// Sets how bouncy the walls are: 1 keeps the logo at full speed, and
+    /// less slows it by that factor every hit until it's down to
+    /// [`RELAUNCH_SPEED`](Self::RELAUNCH_SPEED), when it's launched again
+    /// at full speed. Clamped to [`RESTITUTION_RANGE`](Self::RESTITUTION_RANGE).
+    pub const fn set_restitution(&mut self, restitution: f64) {
+        let (min, max) = Self::RESTITUTION_RANGE;
+        self.restitution = restitution.clamp(min, max);
+        self.energy = 1.0;
+    }
+
+    /// Share of full speed the logo is moving at, which only wall hits
+    /// with a [`restitution`](Self::restitution) under 1 bring down.
+    #[must_use]
+    pub const fn energy(&self) -> f64 {
+        self.energy
+    }
+
+    // Takes off the speed a wall hit costs, and launches the logo again
+    // once it has all but stopped
+    fn lose_energy(&mut self) {
+        self.energy *= self.restitution;
+        if self.energy < Self::RELAUNCH_SPEED {
+            self.energy = 1.0;
+        }
+    }
+
+    // Whole cells of movement each axis has built up this tick
+    fn axis_steps(&mut self) -> (i32, i32) {
+        fn whole(carry: &mut f64, speed: f64) -> i32 {
+            *carry += speed;
+            // Under 1 left over plus at most 4 added, so never 5 or more
+            let steps = (1..=4)
+                .take_while(|&n| f64::from(n) <= *carry)
+                .last()
+                .unwrap_or(0);
+            *carry -= f64::from(steps);
+            steps
+        }
+        (
+            whole(&mut self.axis_carry.0, self.axis_speed.0 * self.energy),
+            whole(&mut self.axis_carry.1, self.axis_speed.1 * self.energy),
+        )
+    }
+
+    /// The current symbol's lines, as [`draw`](Self::draw) would write them.
+    #[must_use]
+    pub fn logo_lines(&self) -> Lines {
+        self.get_logo_lines()
+    }
+
+    /// Middle of the logo, in cells from the top-left of the screen.
+    #[must_use]
+    pub fn center(&self) -> (f64, f64) {
+        let (w, h) = self.get_logo_dimensions();
+        (
+            f64::from(self.x) + f64::from(w) / 2.0,
+            f64::from(self.y) + f64::from(h) / 2.0,
+        )
+    }
+
+    /// Width and height of the current symbol, in cells.
+    #[must_use]
+    pub fn logo_size(&self) -> (u16, u16) {
+        let (w, h) = self.get_logo_dimensions();
+        (
+            u16::try_from(w).unwrap_or(u16::MAX),
+            u16::try_from(h).unwrap_or(u16::MAX),
+        )
+    }
+
+    /// Bounces and corners hit so far.
+    #[must_use]
+    pub const fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Replaces the running totals, e.g. when resuming a session.
+    pub const fn set_stats(&mut self, stats: Stats) {
+        self.stats = stats;
+    }
+
+    /// The area the logo bounces around in, as last given to
+    /// [`set_area`](Self::set_area) or [`resize`](Self::resize).
+    #[must_use]
+    pub const fn bounds(&self) -> Rect {
+        Rect::new(
+            self.min_x,
+            self.min_y,
+            self.max_x.saturating_sub(self.min_x),
+            self.max_y.saturating_sub(self.min_y),
+        )
+    }
+
+    /// The symbol being drawn.
+    #[must_use]
+    pub const fn mode(&self) -> SymbolMode {
+        self.mode
+    }
+
+    pub const fn set_mode(&mut self, mode: SymbolMode) {
+        self.mode = mode;
+    }
+
+    /// Whether the symbols are drawn as plain ASCII.
+    #[must_use]
+    pub const fn ascii(&self) -> bool {
+        self.ascii
+    }
+
+    /// Draws plain ASCII stand-ins for the symbols, for terminals that
+    /// can't show `❄`, emoji or block characters.
+    pub const fn set_ascii(&mut self, ascii: bool) {
+        self.ascii = ascii;
+    }
+
+    /// Whether bounces show as attribute changes rather than colors.
+    #[must_use]
+    pub const fn monochrome(&self) -> bool {
+        self.monochrome
+    }
+
+    /// Switches the logo between bold and reverse video on every wall hit,
+    /// for when colors are turned off and a color change wouldn't show.
+    pub const fn set_monochrome(&mut self, monochrome: bool) {
+        self.monochrome = monochrome;
+    }
+
+    /// Draws the logo in bold, for high contrast; monochrome bounces still
+    /// switch to reverse video.
+    pub const fn set_bold(&mut self, bold: bool) {
+        self.bold = bold;
+    }
+
+    /// Spins the small symbols that have [`spin_frames`](SymbolMode::spin_frames)
+    /// as they bounce; the others are drawn as usual.
+    pub const fn set_spin(&mut self, spin: bool) {
+        self.spin = spin;
+    }
+
+    /// Grows and shrinks symbols that have [`sizes`](SymbolMode::sizes)
+    /// between them, large while a slow sine wave is above zero and small
+    /// while it's below.
+    pub const fn set_pulse(&mut self, pulse: bool) {
+        self.pulse = pulse;
+    }
+
+    // The size `set_pulse` wants now, when there's one to want
+    fn pulse_size(&self) -> Option<SymbolMode> {
+        let (small, large) = self.mode.sizes().filter(|_| self.pulse)?;
+        let into = u32::try_from(self.age % PULSE_TICKS).unwrap_or(0);
+        let period = u32::try_from(PULSE_TICKS).unwrap_or(1);
+        let wave = (std::f64::consts::TAU * f64::from(into) / f64::from(period)).sin();
+        Some(if wave >= 0.0 { large } else { small })
+    }
+
+    /// Switches to `mode` about the logo's center, rather than its corner
+    /// as [`set_mode`](Self::set_mode) does. It's kept clear of the walls
+    /// like a nudge, so growing isn't a wall hit; a symbol the area can't
+    /// hold is left for later, and the mode stays as it was.
+    pub fn resize_symbol(&mut self, mode: SymbolMode) {
+        let (old_width, old_height) = self.get_logo_dimensions();
+        let was = self.mode;
+        self.mode = mode;
+        let (width, height) = self.get_logo_dimensions();
+        let (min_x, min_y) = (i32::from(self.min_x), i32::from(self.min_y));
+        let (max_x, max_y) = (i32::from(self.max_x), i32::from(self.max_y));
+        if width >= max_x - min_x || height >= max_y - min_y {
+            self.mode = was;
+            return;
+        }
+        let place = |at: u16, old: i32, new: i32, min: i32, max: i32| {
+            let last = (max - new - 1).max(min);
+            u16::try_from((i32::from(at) + (old - new) / 2).clamp(min, last)).unwrap_or(0)
+        };
+        self.x = place(self.x, old_width, width, min_x, max_x);
+        self.y = place(self.y, old_height, height, min_y, max_y);
+    }
+
+    /// Dissolves from one symbol into the next over [`morph::FRAMES`]
+    /// draws when it switches, rather than swapping at once, and out of one
+    /// spot and into another when the logo is put somewhere new.
+    pub const fn set_morph(&mut self, morphs: bool) {
+        self.morphs = morphs;
+    }
+
+    /// Never draws a background, so a see-through terminal stays see-through
+    /// behind the logo: what was drawn is erased rather than printed over
+    /// with spaces, and underlining stands in for reverse video.
+    pub const fn set_transparent(&mut self, transparent: bool) {
+        self.transparent = transparent;
+    }
 
-// --- Symbol Enums ---
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SymbolMode {
-    SnowflakeSmall,
-    SnowflakeLarge,
-    NixOS,
-    Arch,
-    MiddleFinger,
-}
+    #[must_use]
+    pub const fn transparent(&self) -> bool {
+        self.transparent
+    }
 
-// --- Bouncer Struct ---
-pub struct Bouncer {
-    x: u16,
-    y: u16,
-    prev_x: u16,
-    prev_y: u16,
-    dx: i32,
-    dy: i32,
-    color: Color,
-    max_x: u16,
-    max_y: u16,
-    pub mode: SymbolMode,
-}
+    // Blanks out `width` cells at the cursor, in the way the terminal wants
+    fn blank(&self, w: &mut impl Write, width: usize) -> io::Result<()> {
+        if self.transparent {
+            erase_cells(w, width)
+        } else {
+            print_blank(w, width)
+        }
+    }
 
-impl Bouncer {
+    /// Where clicking the logo goes, in terminals that support OSC 8
+    /// hyperlinks: the one set with [`set_link`](Self::set_link), or else
+    /// the symbol pack's own.
     #[must_use]
-    /// # Panics
-    /// Panics if the internal `try_from` conversion fails, which should be impossible
-    /// due to the modulo arithmetic ensuring values are within `u16` range.
-    pub fn new() -> Self {
-        // Use crossterm to get size, defaulting to 80x24 if it fails
-        let (cols, lines) = terminal::size().unwrap_or((80, 24));
+    pub fn link(&self) -> Option<&'static str> {
+        self.link.or_else(|| match self.mode {
+            SymbolMode::Pack(i) => pack::packs().get(i).and_then(|p| p.link),
+            _ => None,
+        })
+    }
 
-        let max_x = cols.saturating_sub(1);
-        let max_y = lines.saturating_sub(1);
+    /// Makes the logo a hyperlink to `url`, whatever the symbol. Terminals
+    /// without OSC 8 support just draw the logo as usual.
+    pub const fn set_link(&mut self, url: Option<&'static str>) {
+        self.link = url;
+    }
 
-        // Random start position, safely cast to i32 for math, then back to u16
-        // We use slightly smaller bounds to ensure we don't start off-screen
-        let start_x_pos_i32 = rng::<i32>().abs() % (i32::from(max_x) - 50).max(5) + 2;
-        let start_y_i32 = rng::<i32>().abs() % (i32::from(max_y) - 25).max(5) + 2;
+    fn look(&self) -> Look {
+        let attribute = if !self.monochrome {
+            self.bold.then_some(Attribute::Bold)
+        } else if self.stats.bounces.is_multiple_of(2) {
+            Some(Attribute::Bold)
+        } else if self.transparent {
+            Some(Attribute::Underlined)
+        } else {
+            Some(Attribute::Reverse)
+        };
+        (
+            self.color,
+            attribute,
+            self.link(),
+            self.accent(),
+            self.tones(),
+        )
+    }
 
-        let start_x = u16::try_from(start_x_pos_i32).unwrap();
-        let start_y = u16::try_from(start_y_i32).unwrap();
+    /// Cells moved per tick along the faster axis.
+    #[must_use]
+    pub fn speed(&self) -> u32 {
+        self.dx.unsigned_abs().max(self.dy.unsigned_abs())
+    }
 
-        Self {
-            x: start_x,
-            y: start_y,
-            prev_x: start_x,
-            prev_y: start_y,
-            dx: if rng::<bool>() { 1 } else { -1 },
-            dy: if rng::<bool>() { 1 } else { -1 },
-            color: Color::Blue,
-            max_x,
-            max_y,
-            mode: SymbolMode::NixOS,
-        }
+    /// Moves the bouncer by (`dx`, `dy`) cells on the next update, without bouncing.
+    pub const fn nudge(&mut self, dx: i32, dy: i32) {
+        self.nudge.0 += dx;
+        self.nudge.1 += dy;
     }
 
-    #[allow(clippy::match_same_arms)]
-    pub const fn cycle_symbol(&mut self) {
-        self.mode = match self.mode {
-            SymbolMode::SnowflakeSmall => SymbolMode::SnowflakeLarge,
-            SymbolMode::SnowflakeLarge => SymbolMode::NixOS,
-            SymbolMode::NixOS => SymbolMode::SnowflakeSmall,
-            SymbolMode::MiddleFinger => SymbolMode::SnowflakeSmall,
-            SymbolMode::Arch => SymbolMode::NixOS,
+    /// Pushes the velocity by (`ddx`, `ddy`) cells per tick.
+    ///
+    /// An axis never stops: pushing through zero flips its direction instead, and
+    /// the speed on each axis is capped at [`MAX_SPEED`](Self::MAX_SPEED).
+    pub fn bias_velocity(&mut self, ddx: i32, ddy: i32) {
+        let push = |v: i32, d: i32| {
+            let mut n = v + d;
+            if n == 0 {
+                n = d.signum();
+            }
+            n.clamp(-Self::MAX_SPEED, Self::MAX_SPEED)
         };
+        self.dx = push(self.dx, ddx);
+        self.dy = push(self.dy, ddy);
     }
 
-    pub fn cycle_color(&mut self) {
-        let colors = [
-            Color::Green,
-            Color::Blue,
-            Color::White,
-            Color::Yellow,
-            Color::Cyan,
-            Color::Magenta,
-            Color::Red,
-        ];
-        self.color = colors[rng::<usize>() % colors.len()];
+    /// Pulls the logo's center toward (`x`, `y`) by `strength` cells per
+    /// tick, or pushes it away when `strength` is negative. Called once a
+    /// tick, the pull builds up and turns into a
+    /// [`bias_velocity`](Self::bias_velocity) push each time it makes a
+    /// whole cell per tick, so it bends the path rather than taking over.
+    /// It never speeds an axis past [`PULL_SPEED`](Self::PULL_SPEED).
+    pub fn pull(&mut self, (x, y): (f64, f64), strength: f64) {
+        let (cx, cy) = self.center();
+        let (ox, oy) = (x - cx, y - cy);
+        let distance = ox.hypot(oy);
+        // Already there, and no telling which way is away
+        if distance < 1.0 {
+            return;
+        }
+        self.impulse.0 += strength * ox / distance;
+        self.impulse.1 += strength * oy / distance;
+        let whole = |impulse: &mut f64, v: i32| {
+            let d = if *impulse >= 1.0 {
+                1
+            } else if *impulse <= -1.0 {
+                -1
+            } else {
+                return 0;
+            };
+            *impulse -= f64::from(d);
+            // Past its top speed a pull can only slow the logo down
+            let faster = (v + d).abs() > v.abs();
+            if faster && (v + d).abs() > Self::PULL_SPEED {
+                0
+            } else {
+                d
+            }
+        };
+        let ddx = whole(&mut self.impulse.0, self.dx);
+        let ddy = whole(&mut self.impulse.1, self.dy);
+        if (ddx, ddy) != (0, 0) {
+            self.bias_velocity(ddx, ddy);
+        }
     }
 
     // Internal helper to pick a random color (same logic as cycle_color)
@@ -115,186 +1574,767 @@ impl Bouncer {
         self.mode = SymbolMode::Arch;
     }
 
+    /// Sets the art [`SymbolMode::Banner`] draws. Every line should be the
+    /// same width. Art made for an earlier banner is freed once it's been
+    /// erased.
+    pub fn set_banner(&mut self, art: impl Into<Lines>) {
+        self.banner = art.into();
+    }
+
+    /// The symbol's [`Tones`], unless the logo is all one look: without
+    /// colors, in a gradient or in high contrast bold.
+    #[must_use]
+    pub fn tones(&self) -> Option<&'static Tones> {
+        if self.monochrome || self.bold || self.gradient.is_some() {
+            return None;
+        }
+        self.mode.tones()
+    }
+
+    /// The gradient the logo is drawn in, unless it's without colors.
+    #[must_use]
+    pub const fn gradient(&self) -> Option<theme::Gradient> {
+        if self.monochrome { None } else { self.gradient }
+    }
+
+    /// Draws the logo fading from one color at the top to another at the
+    /// bottom, in place of its own color, which still changes on bounces
+    /// for everything else that follows it.
+    pub const fn set_gradient(&mut self, gradient: Option<theme::Gradient>) {
+        self.gradient = gradient;
+    }
+
+    /// The part of the banner drawn in a color of its own, if it's showing.
+    #[must_use]
+    pub fn accent(&self) -> Option<Accent> {
+        self.accent.filter(|_| self.mode == SymbolMode::Banner)
+    }
+
+    /// Draws part of the banner art in another color. It stands out in
+    /// reverse video when that's the logo's color too, and underlined
+    /// without colors.
+    pub const fn set_accent(&mut self, accent: Option<Accent>) {
+        self.accent = accent;
+    }
+
+    /// Advances the animation by `dt` of real time, running one
+    /// [`update`](Self::update) per whole [`TICK`](Self::TICK) and carrying
+    /// the rest over to the next call. Returns how many ticks ran.
+    ///
+    /// Movement speed is then the same whatever the frame rate: calling this
+    /// more often just runs fewer ticks per call.
+    pub fn step(&mut self, dt: Duration) -> u32 {
+        self.run_ticks(dt, |bouncer| {
+            bouncer.update();
+            true
+        })
+    }
+
+    /// Like [`step`](Self::step), but each tick [`follow`](Self::follow)s
+    /// the next of `samples` instead of running physics. Once they run
+    /// out, no more ticks run.
+    pub fn step_along(
+        &mut self,
+        dt: Duration,
+        samples: &mut impl Iterator<Item = trajectory::Sample>,
+    ) -> u32 {
+        self.run_ticks(dt, |bouncer| {
+            samples
+                .next()
+                .map(|sample| bouncer.follow(sample))
+                .is_some()
+        })
+    }
+
+    // Runs `tick` once per whole tick in `dt`, until it returns false
+    fn run_ticks(&mut self, dt: Duration, mut tick: impl FnMut(&mut Self) -> bool) -> u32 {
+        self.carry += dt;
+        // The next draw erases where the logo was before this step, not mid-way
+        let before = (self.x, self.y);
+        let mut ticks = 0;
+        while self.carry >= Self::TICK {
+            self.carry -= Self::TICK;
+            if !tick(self) {
+                self.carry = Duration::ZERO;
+                break;
+            }
+            ticks += 1;
+        }
+        if ticks > 1 {
+            (self.prev_x, self.prev_y) = before;
+        }
+        ticks
+    }
+
+    /// Runs one tick from a recording instead of physics: the logo moves to
+    /// where `sample` says, at its velocity. An axis whose direction flipped
+    /// counts as a bounce off the wall it was heading for, so stats, color
+    /// changes and observers go as they did while recording.
+    pub fn follow(&mut self, sample: trajectory::Sample) {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+        self.age = self.age.wrapping_add(1);
+        let (dx, dy) = sample.velocity;
+        let hit_x = dx.signum() * self.dx.signum() < 0;
+        let hit_y = dy.signum() * self.dy.signum() < 0;
+        if hit_x {
+            self.bounce(if self.dx < 0 { Wall::Left } else { Wall::Right });
+        }
+        if hit_y {
+            self.bounce(if self.dy < 0 { Wall::Top } else { Wall::Bottom });
+        }
+        self.count_hits(hit_x, hit_y);
+        (self.x, self.y) = sample.position;
+        (self.dx, self.dy) = sample.velocity;
+        self.notify(|o| o.on_tick(sample));
+    }
+
+    fn bounce(&mut self, wall: Wall) {
+        self.notify(|o| o.on_bounce(wall));
+        self.change_color();
+    }
+
+    fn count_hits(&mut self, hit_x: bool, hit_y: bool) {
+        self.stats.bounces += u64::from(hit_x) + u64::from(hit_y);
+        // Both walls in the same tick: the DVD-logo moment
+        if hit_x && hit_y {
+            self.stats.corners += 1;
+            self.notify(|o| o.on_corner());
+        }
+    }
+
+    /// Runs one fixed tick of movement; see [`step`](Self::step) for
+    /// frame-rate independent updates.
     pub fn update(&mut self) {
         // Save old position for erasing
         self.prev_x = self.x;
         self.prev_y = self.y;
+        self.age = self.age.wrapping_add(1);
+        if let Some(mode) = self.pulse_size()
+            && mode != self.mode
+        {
+            self.resize_symbol(mode);
+        }
+        if self.motion.is_some() {
+            self.follow_motion();
+            return;
+        }
+
+        let (logo_width_i32, logo_h_i32) = self.get_logo_dimensions();
+
+        // Apply any manual nudge first, clamped so it never triggers a bounce
+        let (mut x, mut y) = (i32::from(self.x), i32::from(self.y));
+        if self.nudge != (0, 0) {
+            let (min_x, min_y) = (i32::from(self.min_x), i32::from(self.min_y));
+            let last_x = (i32::from(self.max_x) - logo_width_i32 - 1).max(min_x);
+            let last_y = (i32::from(self.max_y) - logo_h_i32 - 1).max(min_y);
+            x = (x + self.nudge.0).clamp(min_x, last_x);
+            y = (y + self.nudge.1).clamp(min_y, last_y);
+            self.nudge = (0, 0);
+        }
 
         // Calculate candidate new position as signed integers
-        let mut nx = i32::from(self.x) + self.dx;
-        let mut ny = i32::from(self.y) + self.dy;
+        let (step_x, step_y) = self.axis_steps();
+        let mut nx = x + self.dx * step_x;
+        let mut ny = y + self.dy * step_y;
 
-        let (logo_width_i32, logo_h_i32) = self.get_logo_dimensions();
+        let mut hit_x = true;
+        let mut hit_y = true;
 
-        // Bounce X
-        if nx <= 0 {
-            nx = 0;
+        // Bounce X, unless it's a tick this axis sits out
+        if step_x == 0 {
+            hit_x = false;
+        } else if nx <= i32::from(self.min_x) {
+            nx = i32::from(self.min_x);
             self.dx = -self.dx;
-            self.change_color();
+            self.bounce(Wall::Left);
         } else if nx + logo_width_i32 >= i32::from(self.max_x) {
-            nx = i32::from(self.max_x) - logo_width_i32;
+            // Stay in the area even when the logo is too big for it
+            nx = (i32::from(self.max_x) - logo_width_i32).max(i32::from(self.min_x));
             self.dx = -self.dx;
-            self.change_color();
+            self.bounce(Wall::Right);
+        } else {
+            hit_x = false;
         }
 
         // Bounce Y
-        if ny <= 0 {
-            ny = 0;
+        if step_y == 0 {
+            hit_y = false;
+        } else if ny <= i32::from(self.min_y) {
+            ny = i32::from(self.min_y);
             self.dy = -self.dy;
-            self.change_color();
+            self.bounce(Wall::Top);
         } else if ny + logo_h_i32 >= i32::from(self.max_y) {
-            ny = i32::from(self.max_y) - logo_h_i32;
+            ny = (i32::from(self.max_y) - logo_h_i32).max(i32::from(self.min_y));
             self.dy = -self.dy;
-            self.change_color();
+            self.bounce(Wall::Bottom);
+        } else {
+            hit_y = false;
+        }
+
+        self.count_hits(hit_x, hit_y);
+        if hit_x || hit_y {
+            self.lose_energy();
         }
 
         self.x = u16::try_from(nx).unwrap_or(u16::MAX);
         self.y = u16::try_from(ny).unwrap_or(u16::MAX);
+        let sample = trajectory::Sample::of(self);
+        self.notify(|o| o.on_tick(sample));
+    }
+
+    /// Follows `motion`'s path from its start instead of bouncing, or goes
+    /// back to bouncing for `None`. The logo still changes color, and counts
+    /// a bounce, each time the path turns it back across or down, as if a
+    /// wall had.
+    pub fn set_motion(&mut self, motion: Option<Box<dyn Motion>>) {
+        self.motion = motion;
+        self.motion_tick = 0;
+    }
+
+    // Moves the logo a tick along its `motion` path; the velocity becomes
+    // the step just taken, and turning back on an axis counts as a hit on
+    // the wall it was heading for
+    fn follow_motion(&mut self) {
+        let Some(motion) = &self.motion else {
+            return;
+        };
+        let (x, y) = motion.position(self.motion_tick, self.bounds(), self.logo_size());
+        self.motion_tick = self.motion_tick.wrapping_add(1);
+        self.nudge = (0, 0);
+        let turn = |v: &mut i32, step: i32| {
+            let turned = step != 0 && step.signum() != v.signum();
+            if step != 0 {
+                *v = step;
+            }
+            turned
+        };
+        let heading = (self.dx, self.dy);
+        let hit_x = turn(&mut self.dx, i32::from(x) - i32::from(self.x));
+        let hit_y = turn(&mut self.dy, i32::from(y) - i32::from(self.y));
+        if hit_x {
+            self.bounce(if heading.0 < 0 {
+                Wall::Left
+            } else {
+                Wall::Right
+            });
+        }
+        if hit_y {
+            self.bounce(if heading.1 < 0 {
+                Wall::Top
+            } else {
+                Wall::Bottom
+            });
+        }
+        self.count_hits(hit_x, hit_y);
+        (self.x, self.y) = (x, y);
+        let sample = trajectory::Sample::of(self);
+        self.notify(|o| o.on_tick(sample));
     }
 
     /// Resizes the animation area.
     ///
-    /// # Panics
-    /// Panics if the calculated dimensions are too large for `u16` (unlikely in normal terminals).
+    /// A terminal too small for the logo keeps it in the top-left corner,
+    /// as [`try_new`](Self::try_new) does.
     pub fn resize(&mut self, w: u16, h: u16) {
-        self.max_x = w.saturating_sub(1);
-        self.max_y = h.saturating_sub(1);
+        // Keeps the last column and row free, as the animation always has
+        self.set_area(Rect::new(0, 0, w.saturating_sub(1), h.saturating_sub(1)));
+    }
 
-        let (logo_width, logo_h) = self.get_logo_dimensions();
+    /// Confines the animation to `area`, for borders, margins and status bars.
+    ///
+    /// The logo always fits entirely inside `area` when it can, and sits in
+    /// its top-left corner when it can't.
+    pub fn set_area(&mut self, area: Rect) {
+        let was = (self.x, self.y);
+        self.min_x = area.x;
+        self.min_y = area.y;
+        // One past the last usable cell: the logo bounces once it touches these
+        self.max_x = area.x.saturating_add(area.width);
+        self.max_y = area.y.saturating_add(area.height);
 
-        // Clamp CURRENT position if terminal shrank
-        // Cast 'x' and 'max_x' UP to the larger type (assuming logo_width is usize or i32)
-        if i32::from(self.x) + logo_width >= i32::from(self.max_x) {
-            self.x =
-                u16::try_from(i32::from(self.max_x).saturating_sub(logo_width).max(0)).unwrap();
-        }
-        if i32::from(self.y) + logo_h >= i32::from(self.max_y) {
-            self.y = u16::try_from(i32::from(self.max_y).saturating_sub(logo_h).max(0)).unwrap();
-        }
+        // Clamp the current and previous positions if the area shrank
+        let (width, height) = self.logo_size();
+        let last_x = self.max_x.saturating_sub(width);
+        let last_y = self.max_y.saturating_sub(height);
+        self.x = self.x.min(last_x);
+        self.y = self.y.min(last_y);
+        self.prev_x = self.prev_x.min(last_x);
+        self.prev_y = self.prev_y.min(last_y);
 
-        // Clamp PREVIOUS position safely too
-        if i32::from(self.prev_x) + logo_width >= i32::from(self.max_x) {
-            self.prev_x =
-                u16::try_from(i32::from(self.max_x).saturating_sub(logo_width).max(0)).unwrap();
-        }
-        if i32::from(self.prev_y) + logo_h >= i32::from(self.max_y) {
-            self.prev_y =
-                u16::try_from(i32::from(self.max_y).saturating_sub(logo_h).max(0)).unwrap();
-        }
+        // Pull positions left of or above the area back inside it
+        self.x = self.x.max(self.min_x);
+        self.y = self.y.max(self.min_y);
+        self.prev_x = self.prev_x.max(self.min_x);
+        self.prev_y = self.prev_y.max(self.min_y);
+        *self.jumped.get_mut() |= (self.x, self.y) != was;
+
+        self.notify(|o| o.on_resize(area));
     }
 
-    // Helper: Dimensions are i32 for easy math, but small enough to fit u16
-    #[allow(clippy::match_same_arms)]
-    const fn get_logo_dimensions(&self) -> (i32, i32) {
-        match self.mode {
-            SymbolMode::SnowflakeSmall => (1, 1),
-            SymbolMode::SnowflakeLarge => (5, 3),
-            SymbolMode::NixOS => (46, 19),
-            SymbolMode::MiddleFinger => (2, 1),
-            SymbolMode::Arch => (46, 19),
-        }
+    // Helper: Dimensions are i32 for easy math, but small enough to fit u16.
+    // Measured in terminal cells, so wide glyphs like emoji count double
+    fn get_logo_dimensions(&self) -> (i32, i32) {
+        let lines = self.get_logo_lines();
+        let width = lines.iter().map(UnicodeWidthStr::width).max().unwrap_or(0);
+        (
+            i32::try_from(width).unwrap_or(i32::MAX),
+            i32::try_from(lines.len()).unwrap_or(i32::MAX),
+        )
     }
 
-    fn get_logo_lines(&self) -> Vec<&str> {
+    fn get_logo_lines(&self) -> Lines {
         match self.mode {
-            SymbolMode::SnowflakeSmall => vec!["❄"],
-            SymbolMode::SnowflakeLarge => vec!["  ❄  ", " ❄❄❄ ", "  ❄  "],
-            SymbolMode::NixOS => vec![
-                "          ::::.    ':::::     ::::'          ",
-                "          ':::::    ':::::.  ::::'           ",
-                "            :::::     '::::.:::::            ",
-                "      .......:::::..... ::::::::             ",
-                "     ::::::::::::::::::. ::::::    ::::.     ",
-                "    ::::::::::::::::::::: :::::.  .::::'     ",
-                "           .....           ::::' :::::'      ",
-                "          :::::            '::' :::::'       ",
-                " ........:::::               ' :::::::::::.  ",
-                ":::::::::::::                 :::::::::::::  ",
-                " ::::::::::: ..              :::::           ",
-                "     .::::: .:::            :::::            ",
-                "    .:::::  :::::          '''''    .....    ",
-                "    :::::   ':::::.  ......:::::::::::::'    ",
-                "     :::     ::::::. ':::::::::::::::::'     ",
-                "            .:::::::: '::::::::::            ",
-                "           .::::''::::.     '::::.           ",
-                "          .::::'   ::::.     '::::.          ",
-                "         .::::      ::::      '::::.         ",
-            ],
-            SymbolMode::MiddleFinger => vec!["🖕"],
-            SymbolMode::Arch => vec![
-                "                      ▄                       ",
-                "                     ▟█▙                      ",
-                "                    ▟███▙                     ",
-                "                   ▟█████▙                    ",
-                "                  ▟███████▙                   ",
-                "                 ▂▔▀▜██████▙                  ",
-                "                ▟██▅▂▝▜█████▙                 ",
-                "               ▟█████████████▙                ",
-                "              ▟███████████████▙               ",
-                "             ▟█████████████████▙              ",
-                "            ▟███████████████████▙             ",
-                "           ▟█████████▛▀▀▜████████▙            ",
-                "          ▟████████▛      ▜███████▙           ",
-                "         ▟█████████        ████████▙          ",
-                "        ▟██████████        █████▆▅▄▃▂         ",
-                "       ▟██████████▛        ▜█████████▙        ",
-                "      ▟██████▀▀▀              ▀▀██████▙       ",
-                "     ▟███▀▘                       ▝▀███▙      ",
-                "    ▟▛▀                               ▀▜▙     ",
-            ],
+            SymbolMode::Nyan => {
+                let frame = self.age / FRAME_TICKS % NYAN_FRAMES.len();
+                Lines::Static(NYAN_FRAMES[frame])
+            }
+            SymbolMode::Banner => self.banner.clone(),
+            mode => Lines::Static(
+                mode.spin_frames(self.ascii)
+                    .filter(|_| self.spin)
+                    .map_or_else(
+                        || mode.art(self.ascii),
+                        |frames| frames[self.age / FRAME_TICKS % frames.len()],
+                    ),
+            ),
         }
     }
 
     /// Draws the current state to the writer.
     ///
+    /// When the screen still shows the last frame, only the cells that
+    /// changed are written: the parts of the old position the logo moved off
+    /// are erased, and cells the new position shares with the old one are
+    /// left alone if they hold the same character. Call
+    /// [`redraw`](Self::redraw) after clearing the screen.
+    ///
+    /// Output is queued but not flushed, so a frame made of several parts
+    /// can go out with one flush; wrap the terminal in a
+    /// [`BufWriter`](std::io::BufWriter) to batch the writes themselves.
+    ///
     /// # Errors
     /// Returns an error if writing to the output fails.
-    ///
-    /// # Panics
-    /// Panics if the internal logic for logo dimensions fails (should be impossible).
     pub fn draw(&self, w: &mut impl Write) -> io::Result<()> {
-        let (logo_width_i32, logo_height_i32) = self.get_logo_dimensions();
-        let logo_lines = self.get_logo_lines();
+        if self.draw_morph(w)? {
+            return Ok(());
+        }
+        let lines = self.get_logo_lines();
+        let width = lines.iter().map(UnicodeWidthStr::width).max().unwrap_or(0);
+        let drawn = self.drawn.borrow().clone();
+        // Erase where the logo was last drawn, which is further back than
+        // the previous step if frames went undrawn
+        let old = drawn
+            .as_ref()
+            .map_or((self.prev_x, self.prev_y), |&(x, y, ..)| (x, y));
+        // Cells can only be matched up when the old and new art are the same
+        // size and every character is one cell wide, and all one look
+        let single_width = |art: &Lines| {
+            art.iter()
+                .all(|l| l.chars().count() == width && l.width() == width)
+        };
+        let incremental = !self.stale.get()
+            && self.accent().is_none()
+            && self.gradient().is_none()
+            && drawn.as_ref().is_some_and(|(.., look, was)| {
+                *look == self.look() && was.len() == lines.len() && single_width(was)
+            })
+            && single_width(&lines);
+        match &drawn {
+            Some((.., was)) if incremental => self.draw_changes(w, old, was)?,
+            Some((.., was)) => self.draw_all(w, old, was)?,
+            None => self.draw_all(w, old, &lines)?,
+        }
+        *self.drawn.borrow_mut() = Some((self.x, self.y, self.look(), lines));
+        self.stale.set(false);
+        Ok(())
+    }
 
-        let logo_width = u16::try_from(logo_width_i32).unwrap();
-        let logo_height = u16::try_from(logo_height_i32).unwrap();
+    /// Makes the next [`draw`](Self::draw) write the whole logo, for when
+    /// the screen no longer shows the last frame.
+    pub fn redraw(&self) {
+        self.stale.set(true);
+    }
+
+    /// The cells the logo took up when it was last drawn, unless the screen
+    /// may no longer show them.
+    #[must_use]
+    pub fn drawn_rect(&self) -> Option<Rect> {
+        self.morph
+            .borrow()
+            .as_ref()
+            .map_or_else(|| self.drawn_logo(), Morph::last)
+    }
+
+    // Draws the next frame of a morph, starting one if the symbol switched
+    // or the logo jumped since the last draw, and says whether it did. The
+    // frame after the last erases what's left for a normal draw.
+    fn draw_morph(&self, w: &mut impl Write) -> io::Result<bool> {
+        let switched = self.drawn_mode.replace(Some(self.mode)) != Some(self.mode);
+        let jumped = self.jumped.replace(false);
+        if self.stale.get() {
+            // What it was morphing from is gone with the rest of the screen
+            *self.morph.borrow_mut() = None;
+        }
+        if self.morphs
+            && self.morph.borrow().is_none()
+            && let Some((x, y, _, was)) = self.drawn.borrow().clone()
+        {
+            let was = if self.stale.get() { Lines::EMPTY } else { was };
+            if jumped {
+                *self.morph.borrow_mut() = Some(Morph::teleport(was, (x, y)));
+            } else if switched && !was.is_empty() {
+                *self.morph.borrow_mut() = Some(Morph::new(was, (x, y)));
+            }
+        }
+        let Some(mut morph) = self.morph.take() else {
+            return Ok(false);
+        };
+        if let Some(last) = morph.last() {
+            self.erase_rect(w, last)?;
+        } else if let Some(drawn) = self.drawn_logo() {
+            self.erase_rect(w, drawn)?;
+        }
+        if morph.done() {
+            *self.drawn.borrow_mut() = None;
+            return Ok(false);
+        }
+        let (area, lines) = morph.next_frame(&self.get_logo_lines(), (self.x, self.y));
+        for (line, y) in lines.iter().zip(area.y..) {
+            if y >= self.max_y.min(65534) {
+                break;
+            }
+            queue!(w, cursor::MoveTo(area.x, y))?;
+            self.set_look(w)?;
+            queue!(w, style::Print(line))?;
+            self.reset_look(w)?;
+        }
+        *self.morph.borrow_mut() = Some(morph);
+        self.stale.set(false);
+        Ok(true)
+    }
+
+    // Where the last frame that wasn't a morph left the logo, if it's still on screen
+    fn drawn_logo(&self) -> Option<Rect> {
+        self.drawn
+            .borrow()
+            .as_ref()
+            .filter(|_| !self.stale.get())
+            .map(|&(x, y, _, ref lines)| {
+                let width = lines.iter().map(UnicodeWidthStr::width).max().unwrap_or(0);
+                Rect::new(
+                    x,
+                    y,
+                    u16::try_from(width).unwrap_or(u16::MAX),
+                    u16::try_from(lines.len()).unwrap_or(u16::MAX),
+                )
+            })
+    }
+
+    fn erase_rect(&self, w: &mut impl Write, rect: Rect) -> io::Result<()> {
+        for y in rect.y
+            ..rect
+                .y
+                .saturating_add(rect.height)
+                .min(self.max_y.min(65534))
+        {
+            queue!(w, cursor::MoveTo(rect.x, y))?;
+            self.blank(w, usize::from(rect.width))?;
+        }
+        Ok(())
+    }
+
+    // Erases the whole rectangle `was` took up at `old`, then writes the whole logo
+    fn draw_all(&self, w: &mut impl Write, old: (u16, u16), was: &Lines) -> io::Result<()> {
+        let logo_lines = self.get_logo_lines();
+        let old_width = was.iter().map(UnicodeWidthStr::width).max().unwrap_or(0);
+        let old_height = u16::try_from(was.len()).unwrap_or(u16::MAX);
 
         // 1. Erase old position safely
-        let erase_str = " ".repeat(logo_width as usize);
-        for i in 0..logo_height {
+        for i in 0..old_height {
             // Clamp to prevent crossterm internal overflow (it does y+1 internally)
-            if let Some(draw_y) = self.prev_y.checked_add(i) {
+            if let Some(draw_y) = old.1.checked_add(i) {
                 // CRITICAL: Ensure we're within terminal bounds AND below u16::MAX - 1
                 // (crossterm adds 1 internally for 1-indexed terminals)
                 if draw_y < self.max_y.min(65534) {
-                    queue!(
-                        w,
-                        cursor::MoveTo(self.prev_x.min(self.max_x.min(65534)), draw_y),
-                        style::Print(&erase_str)
-                    )?;
+                    queue!(w, cursor::MoveTo(old.0.min(self.max_x.min(65534)), draw_y))?;
+                    self.blank(w, old_width)?;
                 }
             }
         }
 
         // 2. Draw new position safely
         for (i, line) in logo_lines.iter().enumerate() {
-            if let Some(draw_y) = self.y.checked_add(u16::try_from(i).unwrap()) {
+            if let Some(draw_y) = u16::try_from(i).ok().and_then(|i| self.y.checked_add(i)) {
                 // CRITICAL: Same bounds check
                 if draw_y < self.max_y.min(65534) {
-                    queue!(
-                        w,
-                        cursor::MoveTo(self.x.min(self.max_x.min(65534)), draw_y),
-                        style::SetForegroundColor(self.color),
-                        style::Print(line),
-                        style::ResetColor
-                    )?;
+                    queue!(w, cursor::MoveTo(self.x.min(self.max_x.min(65534)), draw_y),)?;
+                    self.set_row_look(w, i, logo_lines.len())?;
+                    match (self.accent().filter(|a| a.row == i), self.tones()) {
+                        (Some(accent), _) => self.print_accented(w, line, accent)?,
+                        (None, Some(tones)) => self.print_toned(w, line, i, tones)?,
+                        (None, None) => queue!(w, style::Print(line))?,
+                    }
+                    self.reset_look(w)?;
                 }
             }
         }
+        Ok(())
+    }
+
+    // Colors and attributes both come off again with `ResetColor`, the
+    // link with `reset_look`
+    fn set_look(&self, w: &mut impl Write) -> io::Result<()> {
+        let (color, attribute, link, ..) = self.look();
+        queue!(w, style::SetForegroundColor(color))?;
+        if let Some(attribute) = attribute {
+            queue!(w, style::SetAttribute(attribute))?;
+        }
+        if let Some(url) = link {
+            queue!(w, style::Print(format!("\x1b]8;;{url}\x1b\\")))?;
+        }
+        Ok(())
+    }
+
+    // The look with row `row` of a gradient, when there's one
+    fn set_row_look(&self, w: &mut impl Write, row: usize, rows: usize) -> io::Result<()> {
+        self.set_look(w)?;
+        if let Some(gradient) = self.gradient() {
+            queue!(w, style::SetForegroundColor(gradient.at(row, rows)))?;
+        }
+        Ok(())
+    }
 
-        w.flush()?;
+    fn reset_look(&self, w: &mut impl Write) -> io::Result<()> {
+        queue!(w, style::ResetColor)?;
+        if self.look().2.is_some() {
+            queue!(w, style::Print("\x1b]8;;\x1b\\"))?;
+        }
         Ok(())
     }
+
+    // Prints `line` with the accented characters in their own color, going
+    // back to the logo's look after them
+    fn print_accented(&self, w: &mut impl Write, line: &str, accent: Accent) -> io::Result<()> {
+        let mut chars = line.chars();
+        let before: String = chars.by_ref().take(accent.col).collect();
+        let marked: String = chars.by_ref().take(accent.len).collect();
+        let after: String = chars.collect();
+        queue!(w, style::Print(before))?;
+        if self.monochrome {
+            queue!(w, style::SetAttribute(Attribute::Underlined))?;
+        } else {
+            queue!(w, style::SetForegroundColor(accent.color))?;
+            if accent.color == self.color {
+                let attribute = if self.transparent {
+                    Attribute::Underlined
+                } else {
+                    Attribute::Reverse
+                };
+                queue!(w, style::SetAttribute(attribute))?;
+            }
+        }
+        queue!(w, style::Print(marked), style::ResetColor)?;
+        self.set_row_look(w, accent.row, self.get_logo_lines().len())?;
+        queue!(w, style::Print(after))
+    }
+
+    // Prints line `row` of the art, switching between the logo's color and
+    // the second tone wherever the mask does
+    fn print_toned(
+        &self,
+        w: &mut impl Write,
+        line: &str,
+        row: usize,
+        tones: &Tones,
+    ) -> io::Result<()> {
+        let mut in_second = false;
+        // Byte offset of the run in one tone still to print
+        let mut run = 0;
+        for (i, (at, c)) in line.char_indices().enumerate() {
+            // Blanks show no color, so they join whichever run they're in
+            let second = if c == ' ' {
+                in_second
+            } else {
+                tones.is_second(row, i)
+            };
+            if second != in_second {
+                queue!(w, style::Print(&line[run..at]))?;
+                run = at;
+                self.switch_tone(w, second, tones)?;
+                in_second = second;
+            }
+        }
+        queue!(w, style::Print(&line[run..]))
+    }
+
+    // Colors what's printed next in the second tone, or back in the logo's
+    fn switch_tone(&self, w: &mut impl Write, second: bool, tones: &Tones) -> io::Result<()> {
+        let color = if second { tones.color } else { self.color };
+        queue!(w, style::SetForegroundColor(color))
+    }
+
+    // Erases only what the logo moved off, and writes only the cells that
+    // differ from `was`, the art drawn at `old`
+    fn draw_changes(&self, w: &mut impl Write, old: (u16, u16), was: &Lines) -> io::Result<()> {
+        let (width, height) = self.logo_size();
+        let lines = self.get_logo_lines();
+        let old = Rect::new(old.0, old.1, width, height);
+        let new = Rect::new(self.x, self.y, width, height);
+        // Same bounds as `draw_all`, keeping clear of crossterm's y+1
+        let last_row = self.max_y.min(65534);
+        let last_col = self.max_x.min(65534);
+
+        // 1. Erase the old cells the new rectangle doesn't cover
+        for row in old.y..old.y.saturating_add(height).min(last_row) {
+            let (start, end) = (old.x, old.x.saturating_add(width).min(last_col));
+            let spans = if new.contains(new.x, row) {
+                // Only the strips to either side of the new position
+                [
+                    (start, end.min(new.x)),
+                    (start.max(new.x.saturating_add(width)), end),
+                ]
+            } else {
+                [(start, end), (end, end)]
+            };
+            for (from, to) in spans {
+                if from < to {
+                    queue!(w, cursor::MoveTo(from, row))?;
+                    self.blank(w, usize::from(to - from))?;
+                }
+            }
+        }
+
+        // 2. Write the new cells that differ from what the old logo left there
+        self.set_look(w)?;
+        let tones = self.tones();
+        // Whether the cell at `col`, `row` is in the second tone of a logo drawn at `at`
+        let second = |at: Rect, col: u16, row: u16| {
+            tones.is_some_and(|t| {
+                at.contains(col, row)
+                    && t.is_second(usize::from(row - at.y), usize::from(col - at.x))
+            })
+        };
+        let mut in_second = false;
+        // Each run of changed cells in one tone goes out as one move and one print
+        let mut run = String::new();
+        for (line, row) in lines.iter().zip(new.y..new.y.saturating_add(height)) {
+            if row >= last_row {
+                break;
+            }
+            // Columns of this line to the left of the old logo have nothing under them
+            let lead = usize::from(old.x.saturating_sub(new.x));
+            let mut under = was
+                .get(usize::from(row.wrapping_sub(old.y)))
+                .filter(|_| old.contains(old.x, row))
+                .map(|l| l.chars().skip(usize::from(new.x.saturating_sub(old.x))));
+
+            let mut run_start = new.x;
+            let mut run_tone = false;
+            for (i, c) in line.chars().enumerate() {
+                let col = new.x.saturating_add(u16::try_from(i).unwrap_or(u16::MAX));
+                // Anything the old logo didn't cover is already blank
+                let before = match &mut under {
+                    Some(chars) if i >= lead => chars.next(),
+                    _ => None,
+                };
+                let tone = second(new, col, row);
+                // The same character can still be in the other tone now
+                let changed =
+                    before.unwrap_or(' ') != c || (c != ' ' && tone != second(old, col, row));
+                if changed && col < 65534 {
+                    if !run.is_empty() && tone != run_tone {
+                        queue!(w, cursor::MoveTo(run_start, row), style::Print(&run))?;
+                        run.clear();
+                    }
+                    if run.is_empty() {
+                        run_start = col;
+                        run_tone = tone;
+                        if let Some(tones) = tones.filter(|_| tone != in_second) {
+                            self.switch_tone(w, tone, tones)?;
+                            in_second = tone;
+                        }
+                    }
+                    run.push(c);
+                } else if !run.is_empty() {
+                    queue!(w, cursor::MoveTo(run_start, row), style::Print(&run))?;
+                    run.clear();
+                }
+            }
+            if !run.is_empty() {
+                queue!(w, cursor::MoveTo(run_start, row), style::Print(&run))?;
+                run.clear();
+            }
+        }
+        self.reset_look(w)
+    }
+}
+
+// --- Frames ---
+impl Bouncer {
+    /// Renders the current state as plain text: `height` lines of `width`
+    /// cells, without colors or escape codes.
+    #[must_use]
+    pub fn render(&self, width: u16, height: u16) -> String {
+        // `None` marks the right half of a wide glyph
+        let mut grid = vec![vec![Some(' '); usize::from(width)]; usize::from(height)];
+        let (x, y) = (usize::from(self.x), usize::from(self.y));
+        for (row, line) in self.get_logo_lines().iter().enumerate() {
+            let Some(cells) = grid.get_mut(y + row) else {
+                break;
+            };
+            let mut col = x;
+            for c in line.chars() {
+                let cell_width = c.width().unwrap_or(0);
+                if cell_width == 0 {
+                    continue;
+                }
+                if let Some(cell) = cells.get_mut(col) {
+                    *cell = Some(c);
+                }
+                for cell in cells.iter_mut().skip(col + 1).take(cell_width - 1) {
+                    *cell = None;
+                }
+                col += cell_width;
+            }
+        }
+        let lines: Vec<String> = grid
+            .into_iter()
+            .map(|row| row.into_iter().flatten().collect())
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Animates in a `width` x `height` area, yielding each frame as
+    /// [`render`](Self::render)ed text, for exporters, tests and servers that
+    /// pull frames rather than drawing to a terminal.
+    ///
+    /// The first frame is the current state; the iterator never ends.
+    pub fn frames(&mut self, width: u16, height: u16) -> Frames<'_> {
+        self.set_area(Rect::new(0, 0, width, height));
+        Frames {
+            bouncer: self,
+            width,
+            height,
+        }
+    }
+}
+
+/// The frames of a [`Bouncer`]'s animation, from [`Bouncer::frames`].
+pub struct Frames<'a> {
+    bouncer: &'a mut Bouncer,
+    width: u16,
+    height: u16,
+}
+
+impl Iterator for Frames<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let frame = self.bouncer.render(self.width, self.height);
+        self.bouncer.update();
+        Some(frame)
+    }
 }
 
 // Implement Default manually since Bouncer::new is not const/simple
@@ -303,3 +2343,268 @@ impl Default for Bouncer {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A bouncer showing `art` as its banner
+    fn showing(art: &[&str]) -> Bouncer {
+        let mut bouncer = Bouncer::sized(40, 12);
+        bouncer.mode = SymbolMode::Banner;
+        bouncer.set_banner(art.iter().map(ToString::to_string).collect::<Vec<_>>());
+        bouncer
+    }
+
+    #[test]
+    fn a_replaced_banner_is_freed_once_erased() {
+        let mut bouncer = showing(&["first"]);
+        let first = match bouncer.logo_lines() {
+            Lines::Owned(lines) => Rc::downgrade(&lines),
+            Lines::Static(_) => panic!("a banner's own art"),
+        };
+        bouncer.draw(&mut io::sink()).unwrap();
+        bouncer.set_banner(vec!["second".to_string()]);
+        // Still there to erase by
+        assert!(first.upgrade().is_some());
+        bouncer.draw(&mut io::sink()).unwrap();
+        assert!(first.upgrade().is_none());
+        assert_eq!(bouncer.logo_lines().get(0), Some("second"));
+    }
+
+    #[test]
+    fn renders_the_banner_where_the_logo_is() {
+        let bouncer = showing(&["ab", "cd"]);
+        let (x, y) = bouncer.position();
+        let frame = bouncer.render(40, 12);
+        let rows: Vec<&str> = frame.lines().collect();
+        assert_eq!(rows.len(), 12);
+        assert_eq!(
+            &rows[usize::from(y)][usize::from(x)..usize::from(x) + 2],
+            "ab"
+        );
+        assert_eq!(
+            &rows[usize::from(y) + 1][usize::from(x)..usize::from(x) + 2],
+            "cd"
+        );
+    }
+
+    #[test]
+    fn a_one_cell_terminal_keeps_the_logo_in_the_corner() {
+        let mut bouncer = Bouncer::try_new(1, 1).unwrap();
+        assert_eq!(bouncer.position(), (0, 0));
+        bouncer.update();
+        bouncer.draw(&mut io::sink()).unwrap();
+        assert_eq!(bouncer.position(), (0, 0));
+        assert!(Bouncer::try_new(0, 1).is_err());
+    }
+
+    #[test]
+    fn resizing_down_to_one_cell_clamps_instead_of_panicking() {
+        let mut bouncer = Bouncer::try_new(80, 24).unwrap();
+        bouncer.draw(&mut io::sink()).unwrap();
+        bouncer.resize(1, 1);
+        assert_eq!(bouncer.position(), (0, 0));
+        bouncer.update();
+        bouncer.draw(&mut io::sink()).unwrap();
+        bouncer.resize(80, 24);
+        bouncer.update();
+        bouncer.draw(&mut io::sink()).unwrap();
+    }
+
+    #[test]
+    fn step_runs_whole_ticks_and_carries_the_rest() {
+        let mut bouncer = showing(&["ab"]);
+        assert_eq!(bouncer.step(Bouncer::TICK * 5 / 2), 2);
+        assert_eq!(bouncer.step(Bouncer::TICK / 2), 1);
+        assert_eq!(bouncer.step(Bouncer::TICK / 2), 0);
+    }
+
+    #[test]
+    fn moves_as_far_whatever_the_frame_rate() {
+        let (mut slow, mut fast) = (showing(&["ab"]), showing(&["ab"]));
+        for bouncer in [&mut slow, &mut fast] {
+            bouncer.set_position(10, 5);
+            bouncer.set_velocity(1, 1);
+        }
+        slow.step(Bouncer::TICK * 20);
+        for _ in 0..40 {
+            fast.step(Bouncer::TICK / 2);
+        }
+        assert_eq!(slow.position(), fast.position());
+        assert_eq!(slow.velocity(), fast.velocity());
+    }
+
+    #[test]
+    fn counts_a_corner_as_two_bounces() {
+        let mut bouncer = showing(&["ab"]);
+        // The area ends at column 39 and row 11
+        bouncer.set_position(36, 5);
+        bouncer.set_velocity(1, 0);
+        bouncer.update();
+        assert_eq!(
+            bouncer.stats(),
+            Stats {
+                bounces: 1,
+                corners: 0
+            }
+        );
+        assert_eq!(bouncer.velocity(), (-1, 0));
+
+        bouncer.set_position(36, 9);
+        bouncer.set_velocity(1, 1);
+        bouncer.update();
+        assert_eq!(
+            bouncer.stats(),
+            Stats {
+                bounces: 3,
+                corners: 1
+            }
+        );
+        assert_eq!(bouncer.velocity(), (-1, -1));
+    }
+
+    #[test]
+    fn each_wall_hit_keeps_the_restitution_share_of_speed() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.set_restitution(0.5);
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            bouncer.set_position(36, 5);
+            bouncer.set_velocity(1, 0);
+            // Slowed down, it may take a few ticks to reach the wall
+            let hits = bouncer.stats().bounces;
+            while bouncer.stats().bounces == hits {
+                bouncer.update();
+            }
+            seen.push(bouncer.energy());
+        }
+        // Under the relaunch speed it's launched again at full speed
+        for (energy, want) in seen.into_iter().zip([0.5, 0.25, 1.0]) {
+            assert!((energy - want).abs() < 1e-9, "{energy} isn't {want}");
+        }
+    }
+
+    #[test]
+    fn restitution_is_clamped_and_restarts_at_full_speed() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.set_restitution(0.0);
+        assert!((bouncer.restitution() - Bouncer::RESTITUTION_RANGE.0).abs() < 1e-9);
+        bouncer.set_position(36, 5);
+        bouncer.set_velocity(1, 0);
+        bouncer.update();
+        assert!(bouncer.energy() < 1.0);
+        bouncer.set_restitution(2.0);
+        assert!((bouncer.restitution() - Bouncer::RESTITUTION_RANGE.1).abs() < 1e-9);
+        assert!((bouncer.energy() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn a_pull_builds_up_into_whole_pushes() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.set_position(10, 5);
+        bouncer.set_velocity(1, 1);
+        let (x, y) = bouncer.center();
+        bouncer.pull((x + 20.0, y), 0.5);
+        assert_eq!(bouncer.velocity(), (1, 1));
+        bouncer.pull((x + 20.0, y), 0.5);
+        assert_eq!(bouncer.velocity(), (2, 1));
+        // Never past the pull's top speed
+        for _ in 0..10 {
+            bouncer.pull((x + 20.0, y), 0.5);
+        }
+        assert_eq!(bouncer.velocity(), (Bouncer::PULL_SPEED, 1));
+    }
+
+    #[test]
+    fn a_push_turns_the_logo_away() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.set_position(10, 5);
+        bouncer.set_velocity(1, 1);
+        let (x, y) = bouncer.center();
+        // Right on the center there's no way to tell which way is away
+        bouncer.pull((x, y), -1.0);
+        assert_eq!(bouncer.velocity(), (1, 1));
+        bouncer.pull((x + 20.0, y), -1.0);
+        assert_eq!(bouncer.velocity(), (-1, 1));
+    }
+
+    // Draws until no morph is left in progress, and says how many draws were morph frames
+    fn morph_frames(bouncer: &Bouncer) -> usize {
+        (0..=morph::FRAMES * 2)
+            .take_while(|_| {
+                bouncer.draw(&mut io::sink()).unwrap();
+                bouncer.morph.borrow().is_some()
+            })
+            .count()
+    }
+
+    #[test]
+    fn switching_symbols_morphs_only_when_asked() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.draw(&mut io::sink()).unwrap();
+        bouncer.set_mode(SymbolMode::NixOS);
+        assert_eq!(morph_frames(&bouncer), 0);
+
+        bouncer.set_morph(true);
+        bouncer.set_mode(SymbolMode::Banner);
+        assert_eq!(morph_frames(&bouncer), morph::FRAMES);
+        assert_eq!(bouncer.drawn_rect(), bouncer.drawn_logo());
+    }
+
+    #[test]
+    fn a_jump_morphs_out_and_back_in() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.set_morph(true);
+        bouncer.set_position(1, 1);
+        bouncer.draw(&mut io::sink()).unwrap();
+        bouncer.set_position(20, 6);
+        assert_eq!(morph_frames(&bouncer), morph::FRAMES);
+        assert_eq!(bouncer.drawn_rect(), Some(Rect::new(20, 6, 2, 1)));
+    }
+
+    #[test]
+    fn a_step_redraws_only_the_cells_that_changed() {
+        let mut bouncer = showing(&["##", "##"]);
+        bouncer.set_position(10, 5);
+        bouncer.set_velocity(1, 0);
+        bouncer.draw(&mut io::sink()).unwrap();
+        bouncer.update();
+        let mut changes = Vec::new();
+        bouncer.draw(&mut changes).unwrap();
+        bouncer.redraw();
+        let mut whole = Vec::new();
+        bouncer.draw(&mut whole).unwrap();
+        // The cell both positions share is left alone
+        assert!(!String::from_utf8_lossy(&changes).contains("##"));
+        assert!(String::from_utf8_lossy(&whole).contains("##"));
+        assert!(changes.len() < whole.len());
+    }
+
+    #[test]
+    fn frames_start_from_the_current_state_and_move_on() {
+        let mut bouncer = showing(&["ab"]);
+        bouncer.set_position(10, 5);
+        bouncer.set_velocity(1, 1);
+        let now = bouncer.render(40, 12);
+        let frames: Vec<String> = bouncer.frames(40, 12).take(3).collect();
+        assert_eq!(frames[0], now);
+        assert_ne!(frames[1], frames[0]);
+        for frame in &frames {
+            assert_eq!(frame.lines().count(), 12);
+            assert!(frame.lines().all(|row| row.chars().count() == 40));
+        }
+        assert_eq!(bouncer.position(), (13, 8));
+    }
+
+    #[test]
+    fn renders_a_wide_glyph_across_two_cells() {
+        let bouncer = showing(&["猫x"]);
+        let (x, y) = bouncer.position();
+        let frame = bouncer.render(40, 12);
+        let row = frame.lines().nth(usize::from(y)).unwrap();
+        assert_eq!(row.width(), 40);
+        assert_eq!(row.chars().nth(usize::from(x)), Some('猫'));
+        assert_eq!(row.chars().nth(usize::from(x) + 1), Some('x'));
+    }
+}
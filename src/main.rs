@@ -1,72 +1,815 @@
-use clap::Parser;
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent},
-    execute,
-    terminal::{self, disable_raw_mode, enable_raw_mode},
-};
-use std::io::stdout;
+use clap::builder::{BoolishValueParser, PossibleValuesParser, TypedValueParser};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use crossterm::style::Color;
+use std::io::Write;
+use std::path::PathBuf;
 use std::time::Duration;
 
-use snowflake_bounce::Bouncer;
+use snowflake_bounce::app::{self, Cursor, Options};
+use snowflake_bounce::command::SPEED_RANGE;
+use snowflake_bounce::config::Config;
+use snowflake_bounce::motion::{Lissajous, Orbit};
+use snowflake_bounce::service::Service;
+use snowflake_bounce::sound::Sound;
+use snowflake_bounce::theme::{self, Theme};
+use snowflake_bounce::ui;
+use snowflake_bounce::{Bouncer, Direction, Rect, StartPos, SymbolMode};
 
 /// A terminal-based screensaver with bouncing snowflakes & other symbols
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
+#[allow(clippy::struct_excessive_bools)]
 struct Args {
-    // Future: Add other options here like --color, --speed, etc.
+    #[command(subcommand)]
+    command: Option<Commands>,
+
+    /// Symbol to start with
+    #[arg(long, value_name = "NAME", env = "SNOWFLAKE_BOUNCE_SYMBOL")]
+    symbol: Option<SymbolMode>,
+
+    /// Spin the snowflakes as they bounce
+    #[arg(long)]
+    spin: bool,
+
+    /// Grow and shrink the snowflake between its small and large sizes
+    #[arg(long)]
+    pulse: bool,
+
+    /// Dissolve from one symbol into the next over a few frames when switching,
+    /// and out and back in when the logo jumps somewhere new
+    #[arg(long)]
+    morph: bool,
+
+    /// Print every symbol with its size and a preview, then exit
+    #[arg(long)]
+    list_symbols: bool,
+
+    /// Animation speed, from 0.1 to 10 times normal
+    #[arg(long, value_name = "X", env = "SNOWFLAKE_BOUNCE_SPEED", value_parser = parse_speed)]
+    speed: Option<f64>,
+
+    /// Multiplier on horizontal speed alone, from 0.1 to 4
+    #[arg(long, value_name = "X", default_value_t = 1.0, value_parser = parse_axis_speed)]
+    speed_x: f64,
+
+    /// Multiplier on vertical speed alone, from 0.1 to 4; try 0.5 in tall terminals
+    #[arg(long, value_name = "Y", default_value_t = 1.0, value_parser = parse_axis_speed)]
+    speed_y: f64,
+
+    /// Share of its speed the logo keeps through each wall hit, from 0.5 to
+    /// 1; under 1 it slows down, then is launched again at full speed
+    #[arg(long, value_name = "R", default_value_t = 1.0, value_parser = parse_bounciness)]
+    bounciness: f64,
+
+    /// Pull the logo toward the mouse pointer, or push it away, while it bounces
+    #[arg(long, value_name = "attract|repel", conflicts_with_all = ["screensaver", "replay"])]
+    cursor: Option<Cursor>,
+
+    /// Follow a Lissajous figure over the whole screen instead of bouncing,
+    /// swinging X times across for every Y times down, e.g. `3:2`
+    #[arg(long, value_name = "X:Y", conflicts_with_all = ["versus", "snake", "replay", "cursor"])]
+    lissajous: Option<Lissajous>,
+
+    /// Circle the middle of the screen instead of bouncing, PERCENT of the
+    /// way out to the walls
+    #[arg(long, value_name = "PERCENT", num_args = 0..=1, default_missing_value = "80", value_parser = clap::value_parser!(u8).range(1..=100), conflicts_with_all = ["versus", "snake", "replay", "cursor", "lissajous"])]
+    orbit: Option<u8>,
+
+    /// Seconds one time round --orbit takes
+    #[arg(long, value_name = "SECS", default_value_t = 20, requires = "orbit", value_parser = clap::value_parser!(u32).range(1..=600))]
+    orbit_period: u32,
+
+    /// Scale the horizontal and vertical speeds by random factors from MIN to MAX, e.g. `0.5-2`
+    #[arg(long, value_name = "MIN-MAX", value_parser = parse_speed_range)]
+    random_speed: Option<(f64, f64)>,
+
+    /// Color to start in, by name or #RRGGBB, shown as near as the terminal can
+    #[arg(long, value_name = "COLOR", value_parser = parse_color)]
+    color: Option<Color>,
+
+    /// Color theme to start with; `cvd-*` are safe for color blindness
+    #[arg(long, visible_alias = "palette", value_name = "NAME", env = "SNOWFLAKE_BOUNCE_THEME", value_parser = theme_parser())]
+    theme: Option<Theme>,
+
+    /// Bounce user@hostname in big letters instead of a symbol
+    #[arg(long, conflicts_with = "symbol")]
+    banner: bool,
+
+    /// Bounce "Good morning, $USER", or afternoon or evening, instead of a symbol
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "text", "aquarium"])]
+    greeting: bool,
+
+    /// Bounce this month's calendar, like `cal`, with today in another color
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "greeting", "text", "aquarium"])]
+    calendar: bool,
+
+    /// Bounce a neofetch-style card: the distro logo beside the hostname,
+    /// OS, kernel, uptime and package count
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "git", "tail", "net", "disk", "text", "aquarium"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "price"))]
+    neofetch: bool,
+
+    /// Bounce today's moon phase, lit the way it looks tonight, with the phase's name
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "neofetch", "git", "tail", "net", "disk", "text", "aquarium"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "price"))]
+    moon: bool,
+
+    /// Bounce a card of the git repository's branch, ahead/behind counts and dirty files
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "text", "aquarium"])]
+    git: bool,
+
+    /// Bounce the last line of FILE, following it as it grows, like `tail -f`
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "git", "text", "aquarium"])]
+    tail: Option<PathBuf>,
+
+    /// Bounce the live download and upload rates, from /proc/net/dev
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "git", "tail", "text", "aquarium"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "price"))]
+    net: bool,
+
+    /// Bounce a gauge of how full the filesystem holding PATH is (/ if left
+    /// out), turning yellow at 80% and red at 90%
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "/", conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "git", "tail", "net", "text", "aquarium"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "price"))]
+    disk: Option<PathBuf>,
+
+    /// Bounce the latest headlines of the RSS or Atom feed at URL
+    #[cfg(feature = "rss")]
+    #[arg(long, value_name = "URL", conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "git", "tail", "text", "aquarium"])]
+    rss: Option<String>,
+
+    /// Move on to the next headline every N bounces
+    #[cfg(feature = "rss")]
+    #[arg(long, value_name = "N", default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+    rss_every: u64,
+
+    /// Bounce the price of SYMBOL, a stock like AAPL or a coin like BTC-USD
+    #[cfg(feature = "price")]
+    #[arg(long, value_name = "SYMBOL", conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "git", "tail", "text", "aquarium"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    price: Option<String>,
+
+    /// Bounce TEXT instead of a symbol, word-wrapped into centered lines
+    #[arg(long, value_name = "TEXT", conflicts_with_all = ["symbol", "banner", "aquarium"])]
+    text: Option<String>,
+
+    /// Wrap --text, --tail and feed headlines to at most N columns
+    #[arg(long, value_name = "N", default_value_t = 40, value_parser = clap::value_parser!(u16).range(1..))]
+    text_width: u16,
+
+    /// Make the logo a clickable link to URL, in terminals with OSC 8 hyperlinks
+    #[arg(long, value_name = "URL", value_parser = parse_link)]
+    link: Option<String>,
+
+    /// Draw the symbols in plain ASCII; the default when the locale isn't UTF-8
+    #[arg(long, env = "SNOWFLAKE_BOUNCE_ASCII", value_parser = BoolishValueParser::new())]
+    ascii: bool,
+
+    /// No colors, as with `NO_COLOR` set: bounces flip between bold and reverse video
+    #[arg(long)]
+    no_color: bool,
+
+    /// Accessibility mode: bold white on black, no dimming or gradients, and the bigger snowflake
+    #[arg(long)]
+    high_contrast: bool,
+
+    /// Half speed, wall hits fading to the next color, and nothing that
+    /// flashes or cycles colors quickly
+    #[arg(long)]
+    reduced_motion: bool,
+
+    /// Never paint a background, so a transparent or blurred terminal shows through
+    #[arg(long)]
+    transparent: bool,
+
+    /// Resume the scene from the last --resume run, and save this one on quit
+    #[arg(long)]
+    resume: bool,
+
+    /// Draw in the normal screen buffer, leaving the last frame on quit
+    #[arg(long)]
+    inline: bool,
+
+    /// Show a status bar with the symbol, color, speed and FPS
+    #[arg(long, env = "SNOWFLAKE_BOUNCE_STATUS", value_parser = BoolishValueParser::new())]
+    status: bool,
+
+    /// Print what drawing cost (frames, bytes, cells changed, frame time) on exit
+    #[arg(long)]
+    stats: bool,
+
+    /// Draw a border around the play area
+    #[arg(long)]
+    border: bool,
+
+    /// Keep N empty cells between the screen edges and the play area
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    margin: u16,
+
+    /// Restrict the animation to a rectangle of the screen
+    #[arg(long, value_name = "X,Y,W,H")]
+    area: Option<Rect>,
+
+    /// Start the logo at X,Y in the play area, in cells or percent, e.g. `50%,50%`
+    #[arg(long, value_name = "X,Y")]
+    start_pos: Option<StartPos>,
+
+    /// Start moving this way: a compass point like `ne`, or DX,DY like `2,-1`
+    #[arg(long, value_name = "DIR", allow_hyphen_values = true)]
+    direction: Option<Direction>,
+
+    /// Attract mode: switch symbol and theme every 10 seconds
+    #[arg(long)]
+    demo: bool,
+
+    /// Switch to a random symbol every SECS seconds
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    shuffle: Option<u64>,
+
+    /// Grow from a snowflake to a large one, then the NixOS logo, then a
+    /// rainbow one, as the bounces mount up
+    #[arg(long, conflicts_with_all = ["symbol", "banner", "greeting", "calendar", "neofetch", "moon", "git", "tail", "net", "disk", "text", "aquarium", "all", "demo", "shuffle", "pulse", "versus", "snake", "replay"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "price"))]
+    evolve: bool,
+
+    /// Quit by itself after TIME, e.g. `90s`, `5m` or `1h30m` (plain numbers are seconds)
+    #[arg(long, value_name = "TIME", env = "SNOWFLAKE_BOUNCE_DURATION", value_parser = parse_duration)]
+    duration: Option<Duration>,
+
+    /// Screensaver behavior: any key press or mouse movement quits
+    #[arg(long, env = "SNOWFLAKE_BOUNCE_SCREENSAVER", value_parser = BoolishValueParser::new(), conflicts_with_all = ["versus", "snake"])]
+    screensaver: bool,
+
+    /// Keep the screen from blanking and the machine from sleeping while it
+    /// runs, with a systemd-logind inhibitor lock
+    #[arg(long)]
+    inhibit: bool,
+
+    /// Show the bounce and corner counts in the terminal's title
+    #[arg(long)]
+    title: bool,
+
+    /// Write the logo's position and velocity on every tick to FILE
+    #[arg(long, value_name = "FILE")]
+    record_path: Option<PathBuf>,
+
+    /// Play back a run written with --record-path instead of bouncing, then quit
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["versus", "snake", "aquarium", "banner"])]
+    replay: Option<PathBuf>,
+
+    /// Two-player keep-away: w/s and Up/Down move the paddles
+    #[arg(long)]
+    versus: bool,
+
+    /// Snake mode: the flake leaves a trail and the run ends if it crosses it
+    #[arg(long, conflicts_with = "versus")]
+    snake: bool,
+
+    /// Aquarium mode: fish swim across the screen instead of a bouncing logo
+    #[arg(long, conflicts_with_all = ["versus", "snake", "symbol", "banner"])]
+    aquarium: bool,
+
+    /// Every symbol bouncing at once, each in its own color
+    #[arg(long, conflicts_with_all = ["versus", "snake", "aquarium", "symbol", "banner", "greeting", "calendar", "neofetch", "moon", "git", "tail", "net", "disk", "text"])]
+    #[cfg_attr(feature = "rss", arg(conflicts_with = "rss"))]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "price"))]
+    all: bool,
+
+    /// With --all, have the logos push one another softly away instead of overlapping
+    #[arg(long, requires = "all")]
+    spread: bool,
+
+    /// What wall hits sound like: `bell`, `none`, or a sample file to play
+    #[arg(
+        long,
+        value_name = "bell|none|FILE",
+        env = "SNOWFLAKE_BOUNCE_SOUND",
+        default_value = "none"
+    )]
+    sound: Sound,
+
+    /// Dim the colors at night and brighten them by day, following the clock
+    #[arg(long)]
+    day_night: bool,
+
+    /// Use the dim night palette whatever the time
+    #[arg(long)]
+    night: bool,
+
+    /// Light snow falling behind the logo
+    #[arg(long, conflicts_with = "snake")]
+    snow: bool,
+
+    /// Snow as hard as it is outside, with the local wind (from wttr.in)
+    #[cfg(feature = "weather")]
+    #[arg(long, conflicts_with = "snake")]
+    weather: bool,
+
+    /// Scroll TEXT along the bottom row
+    #[arg(long, value_name = "TEXT")]
+    ticker: Option<String>,
+
+    /// Scroll the lines of a file along the bottom row
+    #[arg(long, value_name = "PATH", conflicts_with = "ticker")]
+    ticker_file: Option<PathBuf>,
+
+    /// Ticker speed in characters per second
+    #[arg(long, value_name = "CPS", default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+    ticker_speed: u16,
+
+    /// Follow the music: louder runs faster, and beats change the color
+    #[cfg(feature = "audio")]
+    #[arg(long)]
+    audio: bool,
+
+    /// Steer with a gamepad: the left stick moves, triggers change speed
+    #[cfg(feature = "gamepad")]
+    #[arg(long)]
+    gamepad: bool,
+
+    /// Take speed, hue and symbol from a MIDI controller's CC 1-3, on the
+    /// first input port whose name contains PORT (any port if left out)
+    #[cfg(feature = "midi")]
+    #[arg(long, value_name = "PORT", num_args = 0..=1, default_missing_value = "")]
+    midi: Option<String>,
+
+    /// Take `:` prompt commands from the named pipe at PATH, one a line, e.g.
+    /// `echo 'symbol arch' > PATH`; it's made if it isn't there
+    #[arg(long, value_name = "PATH")]
+    fifo: Option<PathBuf>,
+
+    /// Open a desktop window instead of drawing in the terminal
+    #[cfg(feature = "window")]
+    #[arg(long)]
+    window: bool,
+
+    /// Draw straight to a Linux framebuffer device, for a bare console
+    #[cfg(all(feature = "fbdev", target_os = "linux"))]
+    #[arg(long, value_name = "DEVICE", num_args = 0..=1, default_missing_value = "/dev/fb0")]
+    fbdev: Option<PathBuf>,
+
+    /// Config file to use instead of ~/.config/snowflake-bounce/config.toml
+    #[arg(long, value_name = "PATH", env = "SNOWFLAKE_BOUNCE_CONFIG")]
+    config: Option<PathBuf>,
 }
 
-fn main() -> std::io::Result<()> {
-    // Parse CLI args (this handles --version automatically)
-    let _args = Args::parse();
+// One ticker message from a file's non-blank lines
+fn ticker_lines(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("   ◆   ")
+}
 
-    // 1. SETUP
-    // Enable raw mode to read keys byte-by-byte instantly
-    enable_raw_mode()?;
+// Merges the config file with the command line
+fn options(args: &Args) -> std::io::Result<Options> {
+    let config = Config::load(args.config.as_deref())?;
+    let ticker = match &args.ticker_file {
+        Some(path) => Some(ticker_lines(&std::fs::read_to_string(path).map_err(
+            |e| std::io::Error::new(e.kind(), format!("{}: {e}", path.display())),
+        )?)),
+        None => args.ticker.clone(),
+    };
+    Ok(Options {
+        symbol: args.symbol.or(config.symbol),
+        theme: args.theme.or(config.theme()?),
+        // --theme wins over the config's colors, as over its theme
+        colors: if args.theme.is_some() {
+            Vec::new()
+        } else {
+            config.colors()?
+        },
+        color: args.color.or(config.color()?),
+        gradient: config.gradient()?,
+        in_order: args.theme.is_none() && config.cycle.as_ref().is_some_and(|c| c.in_order),
+        banner: args.banner,
+        greeting: args.greeting,
+        calendar: args.calendar,
+        neofetch: args.neofetch,
+        moon: args.moon,
+        git: args.git,
+        tail: args.tail.clone(),
+        net: args.net,
+        disk: args.disk.clone(),
+        #[cfg(feature = "rss")]
+        rss: args.rss.clone(),
+        #[cfg(feature = "rss")]
+        rss_every: args.rss_every,
+        #[cfg(feature = "price")]
+        price: args.price.clone(),
+        text: args.text.clone(),
+        text_width: args.text_width,
+        link: args.link.clone(),
+        ascii: args.ascii || !ui::utf8_locale(),
+        no_color: args.no_color || ui::no_color_env(),
+        high_contrast: args.high_contrast || config.high_contrast == Some(true),
+        reduced_motion: args.reduced_motion,
+        transparent: args.transparent,
+        spin: args.spin,
+        pulse: args.pulse,
+        morph: args.morph,
+        resume: args.resume,
+        inline: args.inline,
+        status: args.status,
+        border: args.border,
+        margin: args.margin,
+        area: args.area,
+        start_pos: args.start_pos,
+        direction: args.direction,
+        demo: args.demo,
+        evolve: args.evolve,
+        shuffle: args.shuffle.map(Duration::from_secs),
+        duration: args.duration,
+        screensaver: args.screensaver,
+        inhibit: args.inhibit,
+        title: args.title,
+        record_path: args.record_path.clone(),
+        replay: args.replay.clone(),
+        versus: args.versus,
+        snake: args.snake,
+        aquarium: args.aquarium,
+        all: args.all,
+        spread: args.spread,
+        sound: args.sound.clone(),
+        day_night: args.day_night,
+        night: args.night,
+        ticker,
+        ticker_speed: args.ticker_speed,
+        #[cfg(feature = "weather")]
+        snow: args.snow || args.weather || config.snow == Some(true),
+        #[cfg(not(feature = "weather"))]
+        snow: args.snow || config.snow == Some(true),
+        #[cfg(feature = "weather")]
+        weather: args.weather,
+        #[cfg(feature = "audio")]
+        audio: args.audio,
+        #[cfg(feature = "gamepad")]
+        gamepad: args.gamepad,
+        #[cfg(feature = "midi")]
+        midi: args.midi.clone(),
+        fifo: args.fifo.clone(),
+        speed: args.speed.or(config.speed),
+        axis_speed: (args.speed_x, args.speed_y),
+        restitution: args.bounciness,
+        cursor: args.cursor,
+        lissajous: args.lissajous,
+        orbit: orbit(args),
+        random_speed: args.random_speed,
+        keymap: config.keymap()?,
+        config_path: args.config.clone(),
+        config,
+    })
+}
 
-    let mut stdout = stdout();
+// Draws wherever the flags ask: a window, a framebuffer or the terminal, or
+// into the window xscreensaver gave
+fn run(args: &Args, options: Options) -> std::io::Result<()> {
+    // Started by xscreensaver, which says where to draw
+    #[cfg(feature = "xscreensaver")]
+    if let Some(window) = snowflake_bounce::xscreensaver::window_from_env() {
+        return snowflake_bounce::xscreensaver::run(&options, window);
+    }
+    #[cfg(feature = "window")]
+    if args.window {
+        return snowflake_bounce::window::run(&options);
+    }
+    #[cfg(all(feature = "fbdev", target_os = "linux"))]
+    if let Some(device) = &args.fbdev {
+        return snowflake_bounce::fbdev::run(&options, device);
+    }
+    if args.stats {
+        let stats = app::run_measured(options)?;
+        // After the terminal is back, so it stays on screen
+        println!("{stats}");
+        return Ok(());
+    }
+    app::run(options)
+}
 
-    // Switch to alternate screen (like vim/htop do) and hide cursor
-    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+// `--orbit`'s percentage as a radius, and its period in ticks
+fn orbit(args: &Args) -> Option<Orbit> {
+    let ticks =
+        Duration::from_secs(args.orbit_period.into()).as_millis() / Bouncer::TICK.as_millis();
+    let period = u32::try_from(ticks).unwrap_or(u32::MAX);
+    args.orbit
+        .map(|percent| Orbit::new(f64::from(percent) / 100.0, period))
+}
+
+// The same range `:speed` takes
+fn parse_speed(s: &str) -> Result<f64, String> {
+    let speed: f64 = s.parse().map_err(|e| format!("`{s}`: {e}"))?;
+    let (min, max) = SPEED_RANGE;
+    if (min..=max).contains(&speed) {
+        Ok(speed)
+    } else {
+        Err(format!("`{s}`: expected a speed from {min} to {max}"))
+    }
+}
+
+fn parse_axis_speed(s: &str) -> Result<f64, String> {
+    let speed: f64 = s.parse().map_err(|e| format!("`{s}`: {e}"))?;
+    let (min, max) = Bouncer::AXIS_SPEED_RANGE;
+    if (min..=max).contains(&speed) {
+        Ok(speed)
+    } else {
+        Err(format!("`{s}`: expected a speed from {min} to {max}"))
+    }
+}
+
+fn parse_bounciness(s: &str) -> Result<f64, String> {
+    let bounciness: f64 = s.parse().map_err(|e| format!("`{s}`: {e}"))?;
+    let (min, max) = Bouncer::RESTITUTION_RANGE;
+    if (min..=max).contains(&bounciness) {
+        Ok(bounciness)
+    } else {
+        Err(format!("`{s}`: expected a bounciness from {min} to {max}"))
+    }
+}
 
-    // 2. STATE
+// It goes inside an escape sequence, which a control character would end
+fn parse_link(s: &str) -> Result<String, String> {
+    if s.contains(char::is_control) {
+        Err("links can't hold control characters".to_string())
+    } else {
+        Ok(s.to_string())
+    }
+}
+
+fn parse_color(s: &str) -> Result<Color, String> {
+    theme::parse_for_terminal(s)
+        .ok_or_else(|| format!("`{s}`: expected a color name like green, or #RRGGBB"))
+}
+
+fn parse_speed_range(s: &str) -> Result<(f64, f64), String> {
+    let (min, max) = s
+        .split_once('-')
+        .ok_or_else(|| format!("`{s}`: expected MIN-MAX, like 0.5-2"))?;
+    let (min, max) = (parse_axis_speed(min.trim())?, parse_axis_speed(max.trim())?);
+    if min > max {
+        return Err(format!("`{s}`: {min} is more than {max}"));
+    }
+    Ok((min, max))
+}
+
+// Parses `90`, `90s`, `5m`, `1h30m` and the like, in whole hours, minutes and seconds
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let mut secs: u64 = 0;
+    let mut digits = String::new();
+    for c in s.trim().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(format!("`{s}`: unknown unit `{c}`, expected h, m or s")),
+        };
+        let n: u64 = digits
+            .parse()
+            .map_err(|_| format!("`{s}`: expected a number before `{c}`"))?;
+        secs = n
+            .checked_mul(unit)
+            .and_then(|n| secs.checked_add(n))
+            .ok_or_else(|| format!("`{s}`: too long"))?;
+        digits.clear();
+    }
+    // A bare number at the end counts as seconds
+    if !digits.is_empty() {
+        let n: u64 = digits.parse().map_err(|e| format!("`{s}`: {e}"))?;
+        secs = secs
+            .checked_add(n)
+            .ok_or_else(|| format!("`{s}`: too long"))?;
+    }
+    if secs == 0 {
+        return Err(format!("`{s}`: expected a time of at least one second"));
+    }
+    Ok(Duration::from_secs(secs))
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Print a completion script for SHELL to stdout
+    Completions { shell: Shell },
+    /// Print the settings in effect, after the config file, environment
+    /// and flags, as a config file
+    DumpConfig,
+    /// Write a systemd unit that runs the animation on a console from boot
+    InstallService {
+        /// The console to run on
+        #[arg(long, default_value = "tty2")]
+        tty: String,
+        /// Write a drop-in for getty@TTY.service instead of a user unit,
+        /// running in place of the login prompt (needs root)
+        #[arg(long)]
+        getty: bool,
+        /// Print the unit to stdout instead of writing it
+        #[arg(long)]
+        print: bool,
+        /// Flags to run the animation with, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+// Writes (or prints) the unit, then says how to start it
+fn install_service(tty: &str, getty: bool, print: bool, args: &[String]) -> std::io::Result<()> {
+    let program = std::env::current_exe()?;
+    let service = Service::new(tty, getty, program, args.to_vec())?;
+    if print {
+        print!("{}", service.unit());
+        return Ok(());
+    }
+    let path = service.install()?;
+    println!("Wrote {}", path.display());
+    println!("Start it with:");
+    for step in service.next_steps() {
+        println!("  {step}");
+    }
+    Ok(())
+}
+
+// Theme names as clap's possible values, so they're listed and completed
+fn theme_parser() -> impl TypedValueParser<Value = Theme> {
+    PossibleValuesParser::new(theme::ALL.map(|t| t.name))
+        .try_map(|name| theme::find(&name).ok_or("unknown theme"))
+}
+
+// Shows what `--symbol` takes, and flags logos too big for this terminal
+fn list_symbols(ascii: bool) -> std::io::Result<()> {
+    let mut out = std::io::stdout().lock();
+    let terminal = crossterm::terminal::size().ok();
     let mut bouncer = Bouncer::new();
-    let mut running = true;
-
-    // 3. GAME LOOP
-    while running {
-        // DRAW: Render the current frame
-        bouncer.draw(&mut stdout)?;
-
-        // POLL: Wait up to 50ms for an event.
-        if event::poll(Duration::from_millis(50))? {
-            // Read the event ONCE
-            match event::read()? {
-                Event::Key(KeyEvent { code, .. }) => match code {
-                    KeyCode::Char('q') | KeyCode::Esc => running = false,
-                    KeyCode::Char('c') => bouncer.cycle_color(),
-                    KeyCode::Char('s') => bouncer.cycle_symbol(),
-                    KeyCode::Char('f') => bouncer.set_middle_finger(),
-                    KeyCode::Char('a') => bouncer.set_arch(),
-                    _ => {}
-                },
-                Event::Resize(w, h) => {
-                    bouncer.resize(w, h);
-                    execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+    bouncer.set_ascii(ascii);
+    bouncer.set_position(0, 0);
+    for &mode in SymbolMode::all() {
+        bouncer.set_mode(mode);
+        let (width, height) = bouncer.logo_size();
+        // The bouncer keeps the last column and row free
+        let fit = match terminal {
+            Some((cols, rows)) if width >= cols || height >= rows => {
+                format!(", too big for this {cols}x{rows} terminal")
+            }
+            _ => String::new(),
+        };
+        writeln!(out, "{} ({width}x{height}{fit})", mode.name())?;
+        for line in bouncer.render(width, height).lines() {
+            writeln!(out, "  {}", line.trim_end())?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    // First, so `--symbol` and the config file know the packs' names
+    for e in snowflake_bounce::pack::load() {
+        eprintln!("snowflake-bounce: skipping symbol pack {e}");
+    }
+    // Parse CLI args (this handles --version automatically)
+    let args = Args::parse();
+
+    match args.command {
+        Some(Commands::Completions { shell }) => {
+            let mut command = Args::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+            return;
+        }
+        Some(Commands::DumpConfig) => {
+            let dumped = options(&args).and_then(|o| Config::effective(&o).to_toml());
+            match dumped {
+                Ok(toml) => print!("{toml}"),
+                Err(e) => {
+                    eprintln!("snowflake-bounce: {e}");
+                    std::process::exit(2);
                 }
-                _ => {}
             }
+            return;
+        }
+        Some(Commands::InstallService {
+            ref tty,
+            getty,
+            print,
+            args: ref flags,
+        }) => {
+            if let Err(e) = install_service(tty, getty, print, flags) {
+                eprintln!("snowflake-bounce: {e}");
+                std::process::exit(1);
+            }
+            return;
+        }
+        None => {}
+    }
+    if args.list_symbols {
+        // Stopping early is fine, e.g. when piped into `head`
+        let _ = list_symbols(args.ascii || !ui::utf8_locale());
+        return;
+    }
+
+    // Report config mistakes plainly instead of as a Debug dump
+    let options = match options(&args) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("snowflake-bounce: {e}");
+            std::process::exit(2);
         }
+    };
 
-        // UPDATE: Advance animation physics
-        bouncer.update();
+    let result = run(&args, options);
+
+    // Likewise for a sound or audio device (or window or framebuffer) that won't open
+    if let Err(e) = result {
+        eprintln!("snowflake-bounce: {e}");
+        std::process::exit(1);
     }
+}
 
-    // 4. CLEANUP
-    // Always restore terminal state before exiting!
-    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
-    disable_raw_mode()?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(())
+    const CONFIG: &str = r##"
+symbol = "arch"
+speed = 1.5
+color = "magenta"
+gradient = ["blue", "#102030"]
+
+[cycle]
+colors = ["red", { color = "#00ff00", weight = 3 }, "red"]
+in_order = true
+
+[keys]
+quit = ["x"]
+"##;
+
+    // Loads `args` over a config file holding `text`
+    fn load(name: &str, text: &str, flags: &[&str]) -> Options {
+        let path = std::env::temp_dir().join(format!(
+            "snowflake-bounce-{}-{name}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, text).unwrap();
+        let mut args = vec!["snowflake-bounce", "--config", path.to_str().unwrap()];
+        args.extend(flags);
+        let options = options(&Args::try_parse_from(args).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        options
+    }
+
+    // Dumps `args` over `CONFIG`, then checks loading the dump on its own
+    // gives the same options, and dumps the same again
+    fn round_trip(name: &str, args: &[&str]) -> (Options, String) {
+        let options = load(name, CONFIG, args);
+        let dump = Config::effective(&options).to_toml().unwrap();
+        let reloaded = load(&format!("{name}-dump"), &dump, &[]);
+        assert_eq!(
+            reloaded.symbol.unwrap_or(SymbolMode::NixOS),
+            options.symbol.unwrap_or(SymbolMode::NixOS)
+        );
+        assert_eq!(
+            reloaded.speed.map(f64::to_bits),
+            options.speed.map(f64::to_bits)
+        );
+        assert_eq!(reloaded.theme, options.theme);
+        assert_eq!(reloaded.color, options.color);
+        assert_eq!(reloaded.gradient, options.gradient);
+        assert_eq!(reloaded.colors, options.colors);
+        assert_eq!(reloaded.in_order, options.in_order);
+        assert_eq!(reloaded.snow, options.snow);
+        assert_eq!(reloaded.high_contrast, options.high_contrast);
+        assert_eq!(reloaded.keymap, options.keymap);
+        assert_eq!(Config::effective(&reloaded).to_toml().unwrap(), dump);
+        (options, dump)
+    }
+
+    #[test]
+    fn dump_config_round_trips() {
+        round_trip("plain", &[]);
+    }
+
+    #[test]
+    fn dump_config_has_the_flags_not_the_file() {
+        let (options, dump) = round_trip("flags", &["--color", "red", "--high-contrast"]);
+        assert!(options.high_contrast);
+        assert!(dump.contains("color = \"red\""), "{dump}");
+        assert!(!dump.contains("magenta"), "{dump}");
+    }
+
+    #[test]
+    fn dump_config_leaves_out_the_cycle_under_a_theme() {
+        let (options, dump) = round_trip("theme", &["--theme", "nord"]);
+        assert!(options.colors.is_empty());
+        assert!(!options.in_order);
+        assert!(dump.contains("theme = \"nord\""), "{dump}");
+        assert!(!dump.contains("[cycle]"), "{dump}");
+    }
 }
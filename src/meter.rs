@@ -0,0 +1,147 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+// --- Render Stats ---
+/// What drawing has cost so far, for comparing renderers and backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderStats {
+    /// Frames drawn
+    pub frames: u64,
+    /// Bytes written to the terminal, escape sequences included
+    pub bytes: u64,
+    /// Characters printed, leaving out escape sequences. Everything only
+    /// writes the cells that changed, so this is how many did
+    pub cells: u64,
+    /// Time spent drawing, over all frames
+    pub draw_time: Duration,
+}
+
+impl RenderStats {
+    /// Time spent drawing each frame, on average.
+    #[must_use]
+    pub fn average_frame_time(&self) -> Duration {
+        u32::try_from(self.frames)
+            .ok()
+            .filter(|&frames| frames > 0)
+            .map_or(Duration::ZERO, |frames| self.draw_time / frames)
+    }
+
+    fn per_frame(&self, total: u64) -> u64 {
+        total.checked_div(self.frames).unwrap_or(0)
+    }
+}
+
+impl fmt::Display for RenderStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "frames:      {}", self.frames)?;
+        writeln!(
+            f,
+            "bytes:       {} ({} per frame)",
+            self.bytes,
+            self.per_frame(self.bytes)
+        )?;
+        writeln!(
+            f,
+            "cells:       {} ({} per frame)",
+            self.cells,
+            self.per_frame(self.cells)
+        )?;
+        write!(f, "frame time:  {:.2?} average", self.average_frame_time())
+    }
+}
+
+/// A writer that keeps [`RenderStats`] on everything written through it.
+///
+/// Wrap the terminal's output in one and draw each frame with
+/// [`frame`](Self::frame) to have it counted and timed.
+pub struct Meter<W> {
+    inner: W,
+    stats: RenderStats,
+    escape: Escape,
+}
+
+// Where the byte stream is in an escape sequence
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    None,
+    /// Just after ESC
+    Start,
+    /// A control sequence, `ESC [` up to a final byte
+    Csi,
+    /// An operating system command, `ESC ]` up to BEL or `ESC \`
+    Osc,
+    /// ESC inside an OSC, maybe ending it
+    OscEsc,
+}
+
+impl<W: Write> Meter<W> {
+    #[must_use]
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            stats: RenderStats::default(),
+            escape: Escape::None,
+        }
+    }
+
+    /// The counts so far.
+    #[must_use]
+    pub const fn stats(&self) -> RenderStats {
+        self.stats
+    }
+
+    /// Unwraps the writer, counts and all.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Runs `draw` as one frame: counts it and adds the time it took.
+    ///
+    /// # Errors
+    /// Returns whatever error `draw` does.
+    pub fn frame<T>(&mut self, draw: impl FnOnce(&mut Self) -> io::Result<T>) -> io::Result<T> {
+        let start = Instant::now();
+        let drawn = draw(self);
+        self.stats.draw_time += start.elapsed();
+        self.stats.frames += 1;
+        drawn
+    }
+
+    fn count(&mut self, bytes: &[u8]) {
+        self.stats.bytes += bytes.len() as u64;
+        for &b in bytes {
+            self.escape = match (self.escape, b) {
+                (Escape::None, 0x1b) => Escape::Start,
+                // UTF-8 continuation bytes are part of a character already counted
+                (Escape::None, 0x20..=0x7e | 0xc0..) => {
+                    self.stats.cells += 1;
+                    Escape::None
+                }
+                (Escape::Start, b'[') => Escape::Csi,
+                (Escape::Start, b']') => Escape::Osc,
+                // The end of a sequence, a two-byte one, or a control character
+                (Escape::Csi, 0x40..=0x7e)
+                | (Escape::Start | Escape::None, _)
+                | (Escape::Osc | Escape::OscEsc, 0x07)
+                | (Escape::OscEsc, b'\\') => Escape::None,
+                (Escape::Osc | Escape::OscEsc, 0x1b) => Escape::OscEsc,
+                (Escape::OscEsc, _) => Escape::Osc,
+                (state, _) => state,
+            };
+        }
+    }
+}
+
+impl<W: Write> Write for Meter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
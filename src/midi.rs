@@ -0,0 +1,111 @@
+use midir::{Ignore, MidiInput, MidiInputConnection};
+use std::io;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::SymbolMode;
+use crate::command::SPEED_RANGE;
+
+// --- MIDI Input ---
+/// A change asked for by a MIDI controller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Control {
+    /// Multiplier on the frame rate, within [`SPEED_RANGE`]
+    Speed(f64),
+    /// Color as a position on the color wheel, in degrees
+    Hue(u16),
+    Symbol(SymbolMode),
+}
+
+// The first few knobs on most controllers send these by default
+/// Control change number that sets the speed, slowest to fastest.
+pub const SPEED_CC: u8 = 1;
+/// Control change number that turns the color wheel.
+pub const HUE_CC: u8 = 2;
+/// Control change number that picks a symbol, in [`SymbolMode::all`] order.
+pub const SYMBOL_CC: u8 = 3;
+
+/// Control change messages from a MIDI input port, for performing live.
+///
+/// The port is read on midir's own thread for as long as this is alive.
+pub struct Midi {
+    controls: Receiver<Control>,
+    // Dropping the connection closes the port
+    _connection: MidiInputConnection<Sender<Control>>,
+}
+
+impl Midi {
+    /// Connects to the first input port whose name contains `port`; an empty
+    /// `port` takes the first one there is.
+    ///
+    /// # Errors
+    /// Returns an error if there's no matching port or it can't be opened.
+    pub fn open(port: &str) -> io::Result<Self> {
+        let mut input = MidiInput::new("snowflake-bounce").map_err(other)?;
+        input.ignore(Ignore::All);
+        let found = input
+            .ports()
+            .into_iter()
+            .find(|p| input.port_name(p).is_ok_and(|name| name.contains(port)));
+        let Some(found) = found else {
+            let what = if port.is_empty() {
+                "no MIDI input port".to_string()
+            } else {
+                format!("no MIDI input port matching {port:?}")
+            };
+            return Err(io::Error::new(io::ErrorKind::NotFound, what));
+        };
+
+        let (send, controls) = mpsc::channel();
+        let connection = input
+            .connect(
+                &found,
+                "snowflake-bounce",
+                |_, message, send: &mut Sender<Control>| {
+                    if let Some(control) = decode(message) {
+                        // Nothing left to tell once the app is gone
+                        let _ = send.send(control);
+                    }
+                },
+                send,
+            )
+            .map_err(other)?;
+        Ok(Self {
+            controls,
+            _connection: connection,
+        })
+    }
+
+    /// Changes received since the last call, oldest first.
+    pub fn controls(&self) -> impl Iterator<Item = Control> + '_ {
+        self.controls.try_iter()
+    }
+}
+
+fn other(e: impl std::fmt::Display) -> io::Error {
+    io::Error::other(format!("midi: {e}"))
+}
+
+// Turns a control change on any channel into what it controls
+fn decode(message: &[u8]) -> Option<Control> {
+    let &[status, number, value] = message else {
+        return None;
+    };
+    if status & 0xF0 != 0xB0 {
+        return None;
+    }
+    // How far the knob is turned, from 0 to 1
+    let turned = f64::from(value.min(127)) / 127.0;
+    match number {
+        SPEED_CC => {
+            // Evenly spread over the range's ratios, so the middle is normal speed
+            let (min, max) = SPEED_RANGE;
+            Some(Control::Speed(min * (max / min).powf(turned)))
+        }
+        HUE_CC => Some(Control::Hue(u16::from(value) * 359 / 127)),
+        SYMBOL_CC => {
+            let index = usize::from(value) * SymbolMode::all().len() / 128;
+            SymbolMode::all().get(index).copied().map(Control::Symbol)
+        }
+        _ => None,
+    }
+}
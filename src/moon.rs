@@ -0,0 +1,128 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+// --- Moon Phase ---
+// Days from one new moon to the next, on average
+const SYNODIC_MONTH: f64 = 29.530_588_853;
+
+// A new moon to count from: 2000-01-06 18:14 UTC, as a Unix timestamp
+const NEW_MOON: i64 = 947_182_440;
+
+// Rows in the drawn moon; it's twice as many columns across, as cells are
+// about twice as tall as they're wide
+const ROWS: u8 = 9;
+
+/// How far through its cycle the moon is at `at`: 0 is new, 0.5 full, and
+/// it's waxing in between. Close enough to the real thing to the day.
+#[must_use]
+pub fn phase(at: DateTime<Utc>) -> f64 {
+    // Seconds to days loses nothing that matters here
+    #[allow(clippy::cast_precision_loss)]
+    let days = (at.timestamp() - NEW_MOON) as f64 / 86_400.0;
+    (days / SYNODIC_MONTH).rem_euclid(1.0)
+}
+
+/// The moon's phase on `day`, taken at noon UTC, so it changes once a day.
+#[must_use]
+pub fn on(day: NaiveDate) -> f64 {
+    phase(day.and_time(NaiveTime::MIN).and_utc() + chrono::Duration::hours(12))
+}
+
+/// The fraction of the disc that's lit, from 0 to 1.
+#[must_use]
+pub fn illumination(phase: f64) -> f64 {
+    (1.0 - (phase * std::f64::consts::TAU).cos()) / 2.0
+}
+
+/// The phase's everyday name, "New moon" to "Waning crescent".
+#[must_use]
+pub fn name(phase: f64) -> &'static str {
+    const NAMES: [&str; 8] = [
+        "New moon",
+        "Waxing crescent",
+        "First quarter",
+        "Waxing gibbous",
+        "Full moon",
+        "Waning gibbous",
+        "Last quarter",
+        "Waning crescent",
+    ];
+    // Each name covers an eighth of the cycle, centered on its phase
+    let eighths = phase.mul_add(8.0, 0.5).rem_euclid(8.0);
+    NAMES
+        .iter()
+        .zip(0u8..8)
+        .rev()
+        .find(|&(_, n)| eighths >= f64::from(n))
+        .map_or(NAMES[0], |(name, _)| name)
+}
+
+/// The moon as it looks at `phase`, with the phase's name and how much is
+/// lit underneath.
+///
+/// It's lit as seen from the northern hemisphere: from the right while it
+/// waxes and from the left while it wanes. The dark side is drawn faintly,
+/// `░` or with `ascii` `.`.
+#[must_use]
+pub fn art(phase: f64, ascii: bool) -> Vec<String> {
+    let (lit, dark) = if ascii { ('#', '.') } else { ('█', '░') };
+    let angle = phase * std::f64::consts::TAU;
+    let radius = f64::from(ROWS) / 2.0;
+    let mut lines: Vec<String> = (0..ROWS)
+        .map(|row| {
+            // The middle of the cell, from -1 at the top to 1 at the bottom
+            let y = (f64::from(row) + 0.5 - radius) / radius;
+            (0..ROWS * 2)
+                .map(|col| {
+                    let x = (f64::from(col) + 0.5 - radius * 2.0) / (radius * 2.0);
+                    let edge = y.mul_add(-y, 1.0);
+                    if x * x > edge {
+                        return ' ';
+                    }
+                    // Where the shadow's edge crosses this row
+                    let terminator = edge.sqrt() * angle.cos();
+                    let shown = if phase < 0.5 {
+                        x > terminator
+                    } else {
+                        x < -terminator
+                    };
+                    if shown { lit } else { dark }
+                })
+                .collect()
+        })
+        .collect();
+    let width = usize::from(ROWS) * 2;
+    let percent = (0..=100u8)
+        .rev()
+        .find(|&p| illumination(phase).mul_add(100.0, 0.5) >= f64::from(p))
+        .unwrap_or(0);
+    for label in [name(phase).to_string(), format!("{percent}% lit")] {
+        lines.push(format!("{label:^width$}"));
+    }
+    let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    for line in &mut lines {
+        *line = format!("{line:width$}");
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn art_lights_the_side_the_phase_says() {
+        let full = art(0.5, true);
+        assert!(
+            full.iter()
+                .all(|l| l.chars().count() == full[0].chars().count())
+        );
+        assert!(!full.concat().contains('.'), "{full:?}");
+        let new = art(0.0, true);
+        assert!(!new[..usize::from(ROWS)].concat().contains('#'), "{new:?}");
+        // Waxing, lit from the right
+        let middle = &art(0.25, true)[usize::from(ROWS) / 2];
+        let (left, right) = middle.split_at(middle.len() / 2);
+        assert!(left.contains('.') && !left.contains('#'), "{middle:?}");
+        assert!(right.contains('#') && !right.contains('.'), "{middle:?}");
+    }
+}
@@ -0,0 +1,149 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::{Lines, Rect};
+
+// --- Morph ---
+/// Frames a switch between symbols takes to dissolve from one to the other.
+pub const FRAMES: usize = 10;
+
+/// A dissolve from the art a switch of symbols left on screen into the new
+/// one, a scattering of cells at a time.
+///
+/// The old art stays where it was while the new one comes in wherever the
+/// logo is now, so neither leaves the area it was kept inside.
+#[derive(Debug, Clone)]
+pub struct Morph {
+    from: Lines,
+    at: (u16, u16),
+    frame: usize,
+    // Cells the last frame covered, to erase before the next
+    last: Option<Rect>,
+    // Whether the old art goes entirely before the new one comes in
+    teleport: bool,
+}
+
+impl Morph {
+    /// Dissolves `from`, drawn with its top-left corner at `at`.
+    #[must_use]
+    pub const fn new(from: Lines, at: (u16, u16)) -> Self {
+        Self {
+            from,
+            at,
+            frame: 0,
+            last: None,
+            teleport: false,
+        }
+    }
+
+    /// Dissolves `from`, drawn with its top-left corner at `at`, away to
+    /// nothing over the first half of the frames, then the new art in over
+    /// the second, for a logo that jumped rather than moved. With no `from`
+    /// only the new art comes in.
+    #[must_use]
+    pub fn teleport(from: Lines, at: (u16, u16)) -> Self {
+        Self {
+            frame: if from.is_empty() { FRAMES / 2 } else { 0 },
+            teleport: true,
+            ..Self::new(from, at)
+        }
+    }
+
+    /// Whether every frame has been shown.
+    #[must_use]
+    pub const fn done(&self) -> bool {
+        self.frame >= FRAMES
+    }
+
+    /// The cells the last frame covered.
+    #[must_use]
+    pub const fn last(&self) -> Option<Rect> {
+        self.last
+    }
+
+    /// The next frame, with `to` drawn at `to_at`: the rectangle it covers
+    /// and its lines, blanks included.
+    pub fn next_frame(&mut self, to: &Lines, to_at: (u16, u16)) -> (Rect, Vec<String>) {
+        self.frame += 1;
+        let (from, to, turns) = match (self.teleport, self.frame <= FRAMES / 2) {
+            (false, _) => (&self.from, to, self.frame),
+            (true, true) => (&self.from, &Lines::EMPTY, self.frame * 2),
+            (true, false) => (&Lines::EMPTY, to, (self.frame - FRAMES / 2) * 2),
+        };
+        let from = Grid::new(from, self.at);
+        let to = Grid::new(to, to_at);
+        let area = from.rect.union(to.rect);
+        let lines = (area.y..area.y.saturating_add(area.height))
+            .map(|y| {
+                let mut line = String::new();
+                let mut x = area.x;
+                while x < area.x.saturating_add(area.width) {
+                    let grid = if arrived(x, y, turns) { &to } else { &from };
+                    // `None` is the other half of a wide character from the art not picked here
+                    let c = grid.cell(x, y).unwrap_or(' ');
+                    line.push(c);
+                    x = x.saturating_add(width(c));
+                }
+                line
+            })
+            .collect();
+        self.last = Some(area);
+        (area, lines)
+    }
+}
+
+// Whether the cell at `x`, `y` shows the new art once `turns` frames have
+// passed: each cell turns over on a frame of its own, scattered by a hash
+// of where it is
+fn arrived(x: u16, y: u16, turns: usize) -> bool {
+    let hash = (u32::from(x).wrapping_mul(2_654_435_761) ^ u32::from(y).wrapping_mul(40_503))
+        .rotate_left(13);
+    let turn = usize::try_from(hash).unwrap_or(0) % FRAMES;
+    turn < turns
+}
+
+// Cells a character takes up, at least one
+fn width(c: char) -> u16 {
+    u16::try_from(c.width().unwrap_or(1).max(1)).unwrap_or(1)
+}
+
+// Art laid out cell by cell at a position
+struct Grid {
+    rect: Rect,
+    // `None` for the right half of a wide character
+    cells: Vec<Vec<Option<char>>>,
+}
+
+impl Grid {
+    fn new(art: &Lines, (x, y): (u16, u16)) -> Self {
+        let cells: Vec<Vec<Option<char>>> = art
+            .iter()
+            .map(|line| {
+                let mut row = Vec::new();
+                for c in line.chars().filter(|&c| c.width().unwrap_or(0) > 0) {
+                    row.push(Some(c));
+                    row.extend((1..width(c)).map(|_| None));
+                }
+                row
+            })
+            .collect();
+        let width = cells.iter().map(Vec::len).max().unwrap_or(0);
+        let rect = Rect::new(
+            x,
+            y,
+            u16::try_from(width).unwrap_or(u16::MAX),
+            u16::try_from(cells.len()).unwrap_or(u16::MAX),
+        );
+        Self { rect, cells }
+    }
+
+    // What's at `x`, `y` on screen: a blank outside the art
+    fn cell(&self, x: u16, y: u16) -> Option<char> {
+        if !self.rect.contains(x, y) {
+            return Some(' ');
+        }
+        self.cells
+            .get(usize::from(y - self.rect.y))
+            .and_then(|row| row.get(usize::from(x - self.rect.x)).copied())
+            .unwrap_or(Some(' '))
+    }
+}
@@ -0,0 +1,181 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+use std::str::FromStr;
+
+use crate::Rect;
+
+// --- Motion ---
+/// A path for the logo to follow instead of bouncing, set with
+/// [`Bouncer::set_motion`](crate::Bouncer::set_motion).
+///
+/// Positions are worked out afresh each tick from the tick count alone, so
+/// a path always fits whatever the play area has been resized to.
+pub trait Motion {
+    /// Top-left corner of a `size` logo on `tick`, counted from when the
+    /// motion was set, keeping the whole logo inside `area`.
+    fn position(&self, tick: u64, area: Rect, size: (u16, u16)) -> (u16, u16);
+}
+
+/// Ticks one turn takes at a frequency of 1: 20 seconds at normal speed.
+pub const PERIOD: u32 = 400;
+
+// Where (`u`, `v`), each from -1 to 1, puts a `size` logo in `area`: -1 is
+// against the left or top wall and 1 against the right or bottom one
+fn place(area: Rect, (width, height): (u16, u16), (u, v): (f64, f64)) -> (u16, u16) {
+    let along = |start: u16, room: u16, t: f64| {
+        let offset = (f64::from(room) * (t.clamp(-1.0, 1.0) + 1.0) / 2.0).round();
+        // Between 0 and `room`, so it fits back in a u16
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        start.saturating_add(offset as u16)
+    };
+    (
+        along(area.x, area.width.saturating_sub(width), u),
+        along(area.y, area.height.saturating_sub(height), v),
+    )
+}
+
+// How far round a turn of `period` ticks the path is on `tick`, in radians
+fn angle(tick: u64, period: u32) -> f64 {
+    let period = period.max(1);
+    let into = u32::try_from(tick % u64::from(period)).unwrap_or(0);
+    TAU * f64::from(into) / f64::from(period)
+}
+
+// --- Lissajous ---
+/// A Lissajous figure stretched over the whole play area: the logo swings
+/// from wall to wall `x` times a turn across and `y` times down, the two
+/// a quarter turn apart so a 1:1 figure is an ellipse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lissajous {
+    x: u32,
+    y: u32,
+}
+
+impl Lissajous {
+    /// Most swings a turn either axis takes.
+    pub const MAX_FREQUENCY: u32 = 9;
+
+    /// A figure with `x` swings across and `y` down each turn, each clamped
+    /// from 1 to [`MAX_FREQUENCY`](Self::MAX_FREQUENCY).
+    #[must_use]
+    pub fn new(x: u32, y: u32) -> Self {
+        Self {
+            x: x.clamp(1, Self::MAX_FREQUENCY),
+            y: y.clamp(1, Self::MAX_FREQUENCY),
+        }
+    }
+
+    /// Swings across and down each turn, as (`x`, `y`).
+    #[must_use]
+    pub const fn frequencies(&self) -> (u32, u32) {
+        (self.x, self.y)
+    }
+}
+
+impl Motion for Lissajous {
+    fn position(&self, tick: u64, area: Rect, size: (u16, u16)) -> (u16, u16) {
+        let angle = angle(tick, PERIOD);
+        let u = f64::from(self.x).mul_add(angle, FRAC_PI_2).sin();
+        let v = (f64::from(self.y) * angle).sin();
+        place(area, size, (u, v))
+    }
+}
+
+impl FromStr for Lissajous {
+    type Err = String;
+
+    /// Parses `X:Y`, like `3:2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let max = Self::MAX_FREQUENCY;
+        let expected = || format!("`{s}`: expected frequencies like 3:2, each from 1 to {max}");
+        let (x, y) = s.split_once(':').ok_or_else(expected)?;
+        let frequency = |n: &str| {
+            n.trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|n| (1..=max).contains(n))
+                .ok_or_else(expected)
+        };
+        Ok(Self::new(frequency(x)?, frequency(y)?))
+    }
+}
+
+// --- Orbit ---
+/// Circling the middle of the play area on an ellipse the shape of the
+/// area, clockwise, starting on the right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Orbit {
+    radius: f64,
+    period: u32,
+}
+
+impl Orbit {
+    /// An orbit `radius` of the way from the middle to the walls, from 0
+    /// to 1, that takes `period` ticks to go round once.
+    #[must_use]
+    pub const fn new(radius: f64, period: u32) -> Self {
+        Self {
+            radius: radius.clamp(0.0, 1.0),
+            period: if period == 0 { 1 } else { period },
+        }
+    }
+
+    /// How far from the middle to the walls the orbit is, from 0 to 1.
+    #[must_use]
+    pub const fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Ticks one time round takes.
+    #[must_use]
+    pub const fn period(&self) -> u32 {
+        self.period
+    }
+}
+
+impl Motion for Orbit {
+    fn position(&self, tick: u64, area: Rect, size: (u16, u16)) -> (u16, u16) {
+        let angle = angle(tick, self.period);
+        place(
+            area,
+            size,
+            (self.radius * angle.cos(), self.radius * angle.sin()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Rect = Rect {
+        x: 2,
+        y: 1,
+        width: 40,
+        height: 20,
+    };
+
+    #[test]
+    fn place_spans_the_area_between_the_walls() {
+        let size = (10, 4);
+        assert_eq!(place(AREA, size, (-1.0, -1.0)), (2, 1));
+        assert_eq!(place(AREA, size, (1.0, 1.0)), (32, 17));
+        assert_eq!(place(AREA, size, (0.0, 0.0)), (17, 9));
+        // Off the ends is against the wall
+        assert_eq!(place(AREA, size, (-3.0, 5.0)), (2, 17));
+        // A logo bigger than the area stays at its corner
+        assert_eq!(place(AREA, (50, 30), (1.0, 1.0)), (2, 1));
+    }
+
+    #[test]
+    fn lissajous_parses_frequencies() {
+        assert_eq!("3:2".parse(), Ok(Lissajous::new(3, 2)));
+        assert_eq!(" 1 : 9 ".parse(), Ok(Lissajous::new(1, 9)));
+        for bad in ["3", "3:", ":2", "0:2", "3:10", "a:b", "-1:2"] {
+            assert!(bad.parse::<Lissajous>().is_err(), "{bad:?}");
+        }
+        assert_eq!(
+            Lissajous::new(0, 20).frequencies(),
+            (1, Lissajous::MAX_FREQUENCY)
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::VecDeque;
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::banner;
+
+// --- Network Throughput ---
+/// How often [`Throughput::refresh`] reads the counters again.
+pub const SAMPLE_EVERY: Duration = Duration::from_secs(1);
+
+// Seconds of history each sparkline shows
+const HISTORY: usize = 16;
+
+const COUNTERS: &str = "/proc/net/dev";
+
+// Sparkline levels, lowest first
+const LEVELS: u8 = 8;
+const BARS: [char; LEVELS as usize] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const ASCII_BARS: [char; LEVELS as usize] = ['_', '.', ',', ':', '-', '=', '+', '#'];
+
+/// Live download and upload rates over every interface but loopback, read
+/// from `/proc/net/dev` once a [`SAMPLE_EVERY`], with a sparkline of the
+/// last few seconds of each.
+pub struct Throughput {
+    /// Bytes received and sent so far, as of `read_at`
+    totals: (u64, u64),
+    read_at: Instant,
+    /// Bytes a second down and up, oldest first
+    rates: VecDeque<(f64, f64)>,
+}
+
+impl Throughput {
+    /// Starts counting from the interfaces' totals now.
+    ///
+    /// # Errors
+    /// Returns an error if `/proc/net/dev` can't be read, as on systems other
+    /// than Linux.
+    pub fn open() -> io::Result<Self> {
+        let totals = totals().map_err(|e| io::Error::new(e.kind(), format!("{COUNTERS}: {e}")))?;
+        Ok(Self {
+            totals,
+            read_at: Instant::now(),
+            rates: VecDeque::with_capacity(HISTORY),
+        })
+    }
+
+    /// Takes a new sample once a [`SAMPLE_EVERY`] has passed; returns true if
+    /// it did. Counters that can't be read for now are skipped.
+    pub fn refresh(&mut self) -> bool {
+        let elapsed = self.read_at.elapsed();
+        if elapsed < SAMPLE_EVERY {
+            return false;
+        }
+        let Ok((down, up)) = totals() else {
+            return false;
+        };
+        let seconds = elapsed.as_secs_f64();
+        // Counters go backwards when an interface goes away
+        // Losing precision only matters past petabytes a second
+        #[allow(clippy::cast_precision_loss)]
+        let rate = |now: u64, then: u64| now.saturating_sub(then) as f64 / seconds;
+        if self.rates.len() == HISTORY {
+            self.rates.pop_front();
+        }
+        self.rates
+            .push_back((rate(down, self.totals.0), rate(up, self.totals.1)));
+        self.totals = (down, up);
+        self.read_at = Instant::now();
+        true
+    }
+
+    /// The rates as a card for
+    /// [`Bouncer::set_banner`](crate::Bouncer::set_banner), the same size
+    /// whatever they are.
+    #[must_use]
+    pub fn card(&self, ascii: bool) -> Vec<String> {
+        let (down_arrow, up_arrow) = if ascii {
+            ("down", "up  ")
+        } else {
+            ("↓", "↑")
+        };
+        let last = self.rates.back().copied().unwrap_or_default();
+        let down = format!(
+            "{down_arrow} {:>10} {}",
+            rate(last.0),
+            self.sparkline(|r| r.0, ascii)
+        );
+        let up = format!(
+            "{up_arrow} {:>10} {}",
+            rate(last.1),
+            self.sparkline(|r| r.1, ascii)
+        );
+        banner::framed(&[&down, &up], ascii)
+    }
+
+    // One bar per second, scaled to the busiest second shown and padded
+    // on the left until there's a full history
+    fn sparkline(&self, of: impl Fn(&(f64, f64)) -> f64, ascii: bool) -> String {
+        let bars = if ascii { ASCII_BARS } else { BARS };
+        let peak = self.rates.iter().map(&of).fold(0.0, f64::max);
+        let mut line = " ".repeat(HISTORY - self.rates.len());
+        for rate in self.rates.iter().map(of) {
+            let scaled = if peak > 0.0 {
+                rate / peak * f64::from(LEVELS - 1)
+            } else {
+                0.0
+            };
+            // The nearest level
+            let level = (0..LEVELS)
+                .rev()
+                .find(|&level| scaled + 0.5 >= f64::from(level))
+                .unwrap_or(0);
+            line.push(bars[usize::from(level)]);
+        }
+        line
+    }
+}
+
+// Bytes a second in the largest unit that keeps it under 1000
+fn rate(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B/s", "kB/s", "MB/s", "GB/s", "TB/s"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{value:.0} {}", UNITS[unit])
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+// Bytes received and sent over every interface but loopback
+fn totals() -> io::Result<(u64, u64)> {
+    let text = std::fs::read_to_string(COUNTERS)?;
+    Ok(parse(&text))
+}
+
+/// Adds up the received and sent bytes in `/proc/net/dev`, leaving out
+/// loopback.
+#[must_use]
+pub fn parse(text: &str) -> (u64, u64) {
+    text.lines()
+        // Past the two header lines, each is `name: rx_bytes ... tx_bytes ...`
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(name, _)| name.trim() != "lo")
+        .filter_map(|(_, counters)| {
+            let counters: Vec<u64> = counters
+                .split_whitespace()
+                .map(|n| n.parse().unwrap_or(0))
+                .collect();
+            Some((*counters.first()?, *counters.get(8)?))
+        })
+        .fold((0, 0), |(down, up), (rx, tx)| {
+            (down.saturating_add(rx), up.saturating_add(tx))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_width::UnicodeWidthStr;
+
+    fn sampled(rates: &[(f64, f64)]) -> Throughput {
+        Throughput {
+            totals: (0, 0),
+            read_at: Instant::now(),
+            rates: rates.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn card_stays_one_size_whatever_the_rates() {
+        let quiet = sampled(&[]).card(true);
+        let busy = sampled(&[(1.0, 0.0); HISTORY]).card(true);
+        let huge = sampled(&[(5e12, 2.5e6)]).card(true);
+        for card in [&busy, &huge] {
+            assert_eq!(card.len(), quiet.len());
+            assert_eq!(card[0].width(), quiet[0].width());
+        }
+        assert_eq!(
+            busy[1],
+            format!("| down      1 B/s {} |", "#".repeat(HISTORY))
+        );
+        assert!(huge[1].contains("5.0 TB/s"), "{huge:?}");
+        assert!(huge[2].contains("2.5 MB/s"), "{huge:?}");
+    }
+}
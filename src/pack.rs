@@ -0,0 +1,208 @@
+use crossterm::style::Color;
+use serde::Deserialize;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{Art, SymbolMode, Tones, config, theme};
+
+// --- Symbol Packs ---
+/// A symbol loaded from a directory under [`dir`], offered after the
+/// built-in ones as [`SymbolMode::Pack`].
+///
+/// Each pack is a directory holding a `pack.toml` manifest and the art it
+/// names:
+///
+/// ```toml
+/// name = "tux"
+/// art = "art.txt"      # the default
+/// ascii = "ascii.txt"  # optional, drawn with --ascii
+/// width = 14           # optional, lines are padded out to it
+/// height = 8           # optional, likewise with blank lines
+/// color = "yellow"     # optional, a color name or #RRGGBB to start in
+/// link = "https://..." # optional, opened by clicking it (OSC 8)
+/// tones = "tones.txt"  # optional, `2` marks the cells drawn in `tone`
+/// tone = "#5277c3"     # the second color, needed with `tones`
+/// ```
+#[derive(Debug)]
+pub struct Pack {
+    pub name: &'static str,
+    pub art: Art,
+    /// Drawn instead of `art` with `--ascii`, when the pack has one
+    pub ascii: Option<Art>,
+    pub color: Option<Color>,
+    /// The cells drawn in a second color, when the pack has any
+    pub tones: Option<Tones>,
+    /// Where clicking the symbol goes, in terminals with OSC 8 hyperlinks
+    pub link: Option<&'static str>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    name: String,
+    #[serde(default = "default_art")]
+    art: PathBuf,
+    ascii: Option<PathBuf>,
+    width: Option<usize>,
+    height: Option<usize>,
+    color: Option<String>,
+    link: Option<String>,
+    tones: Option<PathBuf>,
+    tone: Option<String>,
+}
+
+fn default_art() -> PathBuf {
+    PathBuf::from("art.txt")
+}
+
+struct Registry {
+    packs: Vec<Pack>,
+    /// The built-in symbols followed by one per pack
+    symbols: Vec<SymbolMode>,
+}
+
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+/// `$XDG_DATA_HOME/snowflake-bounce/symbols`, falling back to `~/.local/share`.
+#[must_use]
+pub fn dir() -> Option<PathBuf> {
+    Some(config::data_dir()?.join("symbols"))
+}
+
+/// Loads every pack in [`dir`], so `--symbol`, the picker and cycling offer
+/// them too. Call it once at startup, before anything looks symbols up;
+/// later calls change nothing.
+///
+/// Packs that can't be loaded are skipped and returned as errors, for the
+/// caller to report. A missing directory just means there are none.
+pub fn load() -> Vec<io::Error> {
+    let mut errors = Vec::new();
+    let mut packs: Vec<Pack> = Vec::new();
+    let mut dirs = dir()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    // Directory order isn't stable, so packs come in name order
+    dirs.sort();
+    for path in dirs {
+        match load_pack(&path) {
+            Ok(pack) if SymbolMode::from_name(pack.name).is_some() || pack.name == "banner" => {
+                errors.push(with_path(&path, "`name` is taken by a built-in symbol"));
+            }
+            Ok(pack) if packs.iter().any(|p| p.name.eq_ignore_ascii_case(pack.name)) => {
+                errors.push(with_path(&path, "`name` is taken by another pack"));
+            }
+            Ok(pack) => packs.push(pack),
+            Err(e) => errors.push(e),
+        }
+    }
+    let symbols = SymbolMode::ALL
+        .into_iter()
+        .chain((0..packs.len()).map(SymbolMode::Pack))
+        .collect();
+    // Only the first call's packs are kept
+    let _ = REGISTRY.set(Registry { packs, symbols });
+    errors
+}
+
+/// Every loaded pack, in [`SymbolMode::Pack`] order.
+#[must_use]
+pub fn packs() -> &'static [Pack] {
+    REGISTRY.get().map_or(&[], |r| r.packs.as_slice())
+}
+
+// The built-in symbols and the packs, once they're loaded
+pub(crate) fn symbols() -> Option<&'static [SymbolMode]> {
+    REGISTRY.get().map(|r| r.symbols.as_slice())
+}
+
+fn load_pack(path: &Path) -> io::Result<Pack> {
+    let manifest = path.join("pack.toml");
+    let text =
+        std::fs::read_to_string(&manifest).map_err(|e| with_path(&manifest, &e.to_string()))?;
+    let manifest: Manifest =
+        toml::from_str(&text).map_err(|e| with_path(&manifest, &e.to_string()))?;
+    let name = manifest.name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(with_path(path, "`name` must be one word"));
+    }
+    let parse_color = |color: &str| {
+        theme::parse_color(color)
+            .ok_or_else(|| with_path(path, &format!("unknown color `{color}`")))
+    };
+    let color = manifest.color.as_deref().map(parse_color).transpose()?;
+    let link = match manifest.link {
+        // It goes inside an escape sequence, which a control character would end
+        Some(url) if url.contains(char::is_control) => {
+            return Err(with_path(path, "`link` can't hold control characters"));
+        }
+        Some(url) => Some(&*Box::leak(url.into_boxed_str())),
+        None => None,
+    };
+    let size = (manifest.width, manifest.height);
+    let art = read_art(&path.join(&manifest.art), size)?;
+    let ascii = match &manifest.ascii {
+        Some(file) => Some(read_art(&path.join(file), size)?),
+        None => None,
+    };
+    let tones = match (&manifest.tones, &manifest.tone) {
+        (Some(file), Some(tone)) => Some(Tones::new(
+            read_art(&path.join(file), size)?,
+            parse_color(tone)?,
+        )),
+        (None, None) => None,
+        _ => return Err(with_path(path, "`tones` and `tone` go together")),
+    };
+    Ok(Pack {
+        name: Box::leak(name.to_string().into_boxed_str()),
+        art,
+        ascii,
+        color,
+        tones,
+        link,
+    })
+}
+
+// Reads art padded out to a rectangle, `size` if the manifest gives one.
+// Packs load once at startup, so it's leaked to last as long as the frames drawing it
+fn read_art(path: &Path, (width, height): (Option<usize>, Option<usize>)) -> io::Result<Art> {
+    let text = std::fs::read_to_string(path).map_err(|e| with_path(path, &e.to_string()))?;
+    let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        return Err(with_path(path, "no art"));
+    }
+    let widest = lines.iter().map(|l| l.width()).max().unwrap_or(0);
+    let width = width.unwrap_or(widest);
+    let height = height.unwrap_or(lines.len());
+    if widest > width || lines.len() > height {
+        return Err(with_path(
+            path,
+            &format!(
+                "art is {widest}x{}, bigger than {width}x{height}",
+                lines.len()
+            ),
+        ));
+    }
+    lines.resize(height, "");
+    let padded: Vec<&'static str> = lines
+        .iter()
+        .map(|l| &*Box::leak(format!("{l}{}", " ".repeat(width - l.width())).into_boxed_str()))
+        .collect();
+    Ok(Box::leak(padded.into_boxed_slice()))
+}
+
+fn with_path(path: &Path, e: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {e}", path.display()),
+    )
+}
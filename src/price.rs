@@ -0,0 +1,210 @@
+use crossterm::style::Color;
+use std::fmt::Write;
+use std::io;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+// --- Price Ticker ---
+/// How often the price is fetched again.
+pub const REFRESH: Duration = Duration::from_mins(1);
+
+// Yahoo's chart endpoint quotes stocks (`AAPL`), indices (`^GSPC`) and
+// crypto (`BTC-USD`) alike, without an API key
+const URL: &str = "https://query1.finance.yahoo.com/v8/finance/chart/";
+
+/// One price, as Yahoo Finance quotes it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    pub symbol: String,
+    pub price: f64,
+    pub currency: String,
+}
+
+/// Quotes for one symbol, fetched on a background thread now and then every
+/// [`REFRESH`], so a slow network never holds up the animation.
+pub struct Quotes {
+    symbol: String,
+    updates: Receiver<io::Result<Quote>>,
+    /// The first price fetched, which moves are measured from
+    opening: Option<f64>,
+    latest: Option<Quote>,
+    error: Option<String>,
+}
+
+impl Quotes {
+    /// Starts fetching the price of `symbol`, e.g. `AAPL` or `BTC-USD`.
+    #[must_use]
+    pub fn watch(symbol: String) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let fetching = symbol.clone();
+        thread::spawn(move || {
+            loop {
+                if tx.send(fetch(&fetching)).is_err() {
+                    // Nobody is watching any more
+                    return;
+                }
+                thread::sleep(REFRESH);
+            }
+        });
+        Self {
+            symbol,
+            updates: rx,
+            opening: None,
+            latest: None,
+            error: None,
+        }
+    }
+
+    /// The symbol and price, with how far it's moved since the first fetch:
+    /// `AAPL 227.48 USD +1.25%`. Until there's a price, what's holding it up.
+    #[must_use]
+    pub fn text(&self) -> String {
+        match (&self.latest, &self.error) {
+            (Some(quote), _) => {
+                let mut text =
+                    format!("{} {} {}", quote.symbol, price(quote.price), quote.currency);
+                if let Some(opening) = self.opening.filter(|&p| p != 0.0) {
+                    let _ = write!(text, " {:+.2}%", (quote.price / opening - 1.0) * 100.0);
+                }
+                text
+            }
+            (None, Some(error)) => error.clone(),
+            (None, None) => format!("Fetching {}...", self.symbol),
+        }
+    }
+
+    /// Green once the price is up since the first fetch, red once it's
+    /// down, and `None` while it hasn't moved.
+    #[must_use]
+    pub fn color(&self) -> Option<Color> {
+        let (opening, latest) = (self.opening?, self.latest.as_ref()?.price);
+        if latest > opening {
+            Some(Color::Green)
+        } else if latest < opening {
+            Some(Color::Red)
+        } else {
+            None
+        }
+    }
+
+    /// Takes in the fetches that came in since the last call; returns true
+    /// if [`text`](Self::text) changed, so a price that holds still isn't
+    /// laid out again. A failed fetch keeps the price from before, and only
+    /// shows while there's none.
+    pub fn update(&mut self) -> bool {
+        let was = self.text();
+        for update in self.updates.try_iter() {
+            match update {
+                Ok(quote) => {
+                    self.opening.get_or_insert(quote.price);
+                    self.latest = Some(quote);
+                }
+                Err(e) => self.error = Some(format!("Couldn't fetch {}: {e}", self.symbol)),
+            }
+        }
+        self.text() != was
+    }
+}
+
+// Cents for most things, more places for coins worth less than one
+fn price(price: f64) -> String {
+    if price.abs() >= 1.0 {
+        format!("{price:.2}")
+    } else {
+        format!("{price:.6}")
+    }
+}
+
+/// Fetches the latest price of `symbol` from Yahoo Finance.
+///
+/// # Errors
+/// Returns an error if the request fails, which it does for an unknown
+/// symbol, or the reply doesn't parse.
+pub fn fetch(symbol: &str) -> io::Result<Quote> {
+    let text = ureq::get(format!("{URL}{}", encode(symbol)))
+        .call()
+        .and_then(|mut reply| reply.body_mut().read_to_string())
+        .map_err(io::Error::other)?;
+    parse(&text).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("no price for {symbol} in the reply"),
+        )
+    })
+}
+
+/// Reads the quote out of a chart reply, from its `meta`.
+#[must_use]
+pub fn parse(text: &str) -> Option<Quote> {
+    let reply: serde_json::Value = serde_json::from_str(text).ok()?;
+    let meta = reply.pointer("/chart/result/0/meta")?;
+    Some(Quote {
+        symbol: meta.get("symbol")?.as_str()?.to_string(),
+        price: meta.get("regularMarketPrice")?.as_f64()?,
+        currency: meta
+            .get("currency")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+// Percent-encodes a symbol for the URL path, where `^GSPC` needs it
+fn encode(symbol: &str) -> String {
+    let mut encoded = String::with_capacity(symbol.len());
+    for b in symbol.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'=' | b'_') {
+            encoded.push(char::from(b));
+        } else {
+            let _ = write!(encoded, "%{b:02X}");
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::Sender;
+
+    fn quotes() -> (Sender<io::Result<Quote>>, Quotes) {
+        let (tx, rx) = mpsc::channel();
+        let quotes = Quotes {
+            symbol: "AAPL".to_string(),
+            updates: rx,
+            opening: None,
+            latest: None,
+            error: None,
+        };
+        (tx, quotes)
+    }
+
+    fn quote(price: f64) -> Quote {
+        Quote {
+            symbol: "AAPL".to_string(),
+            price,
+            currency: "USD".to_string(),
+        }
+    }
+
+    #[test]
+    fn only_a_moved_price_changes_the_text() {
+        let (tx, mut quotes) = quotes();
+        assert_eq!(quotes.text(), "Fetching AAPL...");
+        assert!(!quotes.update());
+        tx.send(Ok(quote(200.0))).unwrap();
+        assert!(quotes.update());
+        assert_eq!(quotes.text(), "AAPL 200.00 USD +0.00%");
+        assert_eq!(quotes.color(), None);
+        tx.send(Ok(quote(200.0))).unwrap();
+        assert!(!quotes.update());
+        tx.send(Ok(quote(190.0))).unwrap();
+        assert!(quotes.update());
+        assert_eq!(quotes.text(), "AAPL 190.00 USD -5.00%");
+        assert_eq!(quotes.color(), Some(Color::Red));
+        // The last price stays up through a failed fetch
+        tx.send(Err(io::Error::other("offline"))).unwrap();
+        assert!(!quotes.update());
+    }
+}
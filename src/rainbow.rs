@@ -0,0 +1,118 @@
+use crossterm::style::Color;
+use std::io::{self, Write};
+
+use crate::Rect;
+use crate::layer::Layer;
+
+// --- Rainbow Trail ---
+/// The rainbow Nyan cat leaves behind, fading out cell by cell.
+///
+/// Every cell the cat moves off turns into a band of rainbow stripes, one
+/// color per row of the cat, that fades through lighter shades and is gone
+/// after [`FADE_TICKS`]. Like the snow, drawing tracks what the screen shows
+/// so each frame only writes the cells that changed.
+pub struct Rainbow {
+    layer: Layer,
+    ascii: bool,
+    /// Ticks run so far
+    tick: u32,
+    /// One cell per screen cell of the area: when it was laid, and in which stripe
+    laid: Vec<Option<(u32, Color)>>,
+}
+
+/// Ticks a cell of the trail lasts before it's gone.
+pub const FADE_TICKS: u32 = 16;
+
+// Top to bottom
+const STRIPES: [Color; 6] = [
+    Color::Red,
+    Color::AnsiValue(208),
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::Magenta,
+];
+
+// Shades from freshly laid to nearly gone
+const SHADES: [char; 4] = ['█', '▓', '▒', '░'];
+const ASCII_SHADES: [char; 4] = ['=', '=', '-', '-'];
+
+impl Rainbow {
+    #[must_use]
+    pub fn new(area: Rect, ascii: bool) -> Self {
+        Self {
+            layer: Layer::new(area),
+            ascii,
+            tick: 0,
+            laid: vec![None; Layer::cells(area)],
+        }
+    }
+
+    /// Moves the trail into a new area, dropping what was laid.
+    pub fn resize(&mut self, area: Rect) {
+        self.layer.resize(area);
+        self.laid = vec![None; Layer::cells(area)];
+    }
+
+    /// Forgets what's on screen, for after it was cleared.
+    pub fn redraw(&mut self) {
+        self.layer.redraw();
+    }
+
+    /// Lays trail over the cells of `left`, where the cat was, that `now`,
+    /// where it is, doesn't cover.
+    pub fn lay(&mut self, left: Rect, now: Rect) {
+        for (i, y) in (left.y..left.y.saturating_add(left.height)).enumerate() {
+            let stripe = STRIPES[i * STRIPES.len() / usize::from(left.height.max(1))];
+            for x in left.x..left.x.saturating_add(left.width) {
+                if !now.contains(x, y)
+                    && let Some(cell) = self.layer.index(x, y)
+                {
+                    self.laid[cell] = Some((self.tick, stripe));
+                }
+            }
+        }
+    }
+
+    /// Ages the trail by `ticks` animation ticks.
+    pub fn update(&mut self, ticks: u32) {
+        self.tick = self.tick.wrapping_add(ticks);
+        let tick = self.tick;
+        for cell in &mut self.laid {
+            if cell.is_some_and(|(at, _)| tick.wrapping_sub(at) >= FADE_TICKS) {
+                *cell = None;
+            }
+        }
+    }
+
+    /// Draws the trail as it is now, writing only the cells that changed.
+    /// Nothing is drawn inside `covered`, the cells something else draws over
+    /// this frame. Draw this before the logo, with the logo's old and new
+    /// positions covered.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&mut self, w: &mut impl Write, covered: &[Rect]) -> io::Result<()> {
+        let area = self.layer.area();
+        let width = usize::from(area.width);
+        if width == 0 {
+            return Ok(());
+        }
+        let shades = if self.ascii { ASCII_SHADES } else { SHADES };
+        let tick = self.tick;
+        let frame = (area.y..)
+            .zip(self.laid.chunks(width))
+            .flat_map(|(y, row)| {
+                (area.x..).zip(row).map(move |(x, cell)| {
+                    let (at, stripe) = (*cell)?;
+                    if covered.iter().any(|r| r.contains(x, y)) {
+                        return None;
+                    }
+                    let age = tick.wrapping_sub(at) * 4 / FADE_TICKS;
+                    Some((shades[usize::try_from(age).unwrap_or(3).min(3)], stripe))
+                })
+            })
+            .collect();
+        self.layer.draw(w, frame)
+    }
+}
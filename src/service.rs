@@ -0,0 +1,169 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config;
+
+// --- Console Service ---
+/// A systemd unit that keeps the animation running on a virtual console
+/// from boot, so a spare screen always shows the bouncing logo.
+///
+/// It's either a user unit, started with the user's session (or at boot
+/// with lingering on), or a drop-in for `getty@TTY.service` that runs the
+/// animation in place of the login prompt.
+pub struct Service {
+    /// The console's name under `/dev`, like `tty2`
+    pub tty: String,
+    /// A getty drop-in rather than a user unit
+    pub getty: bool,
+    /// The program to run, and the arguments to give it
+    pub program: PathBuf,
+    pub args: Vec<String>,
+}
+
+impl Service {
+    /// Checks that `tty` names a device straight under `/dev`.
+    ///
+    /// # Errors
+    /// Returns an error if `tty` is empty or has a `/` or a space in it.
+    pub fn new(tty: &str, getty: bool, program: PathBuf, args: Vec<String>) -> io::Result<Self> {
+        let tty = tty.strip_prefix("/dev/").unwrap_or(tty);
+        if tty.is_empty() || tty.contains('/') || tty.contains(char::is_whitespace) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{tty}`: expected a console like tty2"),
+            ));
+        }
+        Ok(Self {
+            tty: tty.to_string(),
+            getty,
+            program,
+            args,
+        })
+    }
+
+    /// Where the unit goes: under `$XDG_CONFIG_HOME/systemd/user` for a user
+    /// unit, and under `/etc/systemd/system` for a getty drop-in.
+    ///
+    /// # Errors
+    /// Returns an error if there's no home directory to put a user unit in.
+    pub fn path(&self) -> io::Result<PathBuf> {
+        if self.getty {
+            return Ok(Path::new("/etc/systemd/system")
+                .join(format!("getty@{}.service.d", self.tty))
+                .join("snowflake-bounce.conf"));
+        }
+        let home = config::config_home()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+        Ok(home.join("systemd/user").join(self.unit_name()))
+    }
+
+    // The name `systemctl` knows it by
+    fn unit_name(&self) -> String {
+        if self.getty {
+            format!("getty@{}.service", self.tty)
+        } else {
+            format!("snowflake-bounce-{}.service", self.tty)
+        }
+    }
+
+    /// The unit file's contents.
+    #[must_use]
+    pub fn unit(&self) -> String {
+        let mut command = quote(&self.program.to_string_lossy());
+        for arg in &self.args {
+            command.push(' ');
+            command.push_str(&quote(arg));
+        }
+        let tty = &self.tty;
+        if self.getty {
+            // The empty ExecStart= clears getty's own before setting ours
+            format!(
+                "[Service]\n\
+                 ExecStart=\n\
+                 ExecStart=-{command}\n\
+                 Environment=TERM=linux\n"
+            )
+        } else {
+            format!(
+                "[Unit]\n\
+                 Description=Bouncing logo on /dev/{tty}\n\
+                 \n\
+                 [Service]\n\
+                 ExecStart={command}\n\
+                 StandardInput=tty-force\n\
+                 StandardOutput=tty\n\
+                 TTYPath=/dev/{tty}\n\
+                 TTYReset=yes\n\
+                 TTYVHangup=yes\n\
+                 Environment=TERM=linux\n\
+                 Restart=always\n\
+                 \n\
+                 [Install]\n\
+                 WantedBy=default.target\n"
+            )
+        }
+    }
+
+    /// Writes the unit where it goes, making its directory if need be, and
+    /// returns where that was.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be written, as for a getty
+    /// drop-in without root.
+    pub fn install(&self) -> io::Result<PathBuf> {
+        let path = self.path()?;
+        let described = |e: io::Error| io::Error::new(e.kind(), format!("{}: {e}", path.display()));
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(described)?;
+        }
+        std::fs::write(&path, self.unit()).map_err(described)?;
+        Ok(path)
+    }
+
+    /// The commands that load and start the unit once it's written.
+    #[must_use]
+    pub fn next_steps(&self) -> Vec<String> {
+        let name = self.unit_name();
+        if self.getty {
+            vec![
+                "systemctl daemon-reload".to_string(),
+                format!("systemctl enable {name}"),
+                format!("systemctl restart {name}"),
+            ]
+        } else {
+            vec![
+                "systemctl --user daemon-reload".to_string(),
+                format!("systemctl --user enable --now {name}"),
+                // Otherwise it waits for a login
+                "loginctl enable-linger".to_string(),
+            ]
+        }
+    }
+}
+
+// One word of an `ExecStart=` line: left alone if it's plain, and quoted
+// the way systemd reads it otherwise. `%` and `$` are doubled everywhere,
+// as systemd expands them even inside quotes.
+fn quote(word: &str) -> String {
+    let escaped = word.replace('%', "%%").replace('$', "$$");
+    let plain = !escaped.is_empty()
+        && escaped
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%$".contains(c));
+    if plain {
+        return escaped;
+    }
+    let mut quoted = String::from("\"");
+    for c in escaped.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
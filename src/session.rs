@@ -0,0 +1,75 @@
+use crossterm::style::Color;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+
+use crate::{Bouncer, Stats, SymbolMode, config};
+
+// --- Session ---
+/// The scene as it was on quit, saved to `session.toml` in the state
+/// directory so `--resume` can carry on where the last run stopped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub mode: SymbolMode,
+    pub position: (u16, u16),
+    pub velocity: (i32, i32),
+    pub color: Color,
+    pub palette: Vec<Color>,
+    pub stats: Stats,
+    /// The `:speed` multiplier
+    pub speed: f64,
+}
+
+impl Session {
+    /// `$XDG_STATE_HOME/snowflake-bounce/session.toml`
+    #[must_use]
+    pub fn path() -> Option<PathBuf> {
+        Some(config::state_dir()?.join("session.toml"))
+    }
+
+    /// Snapshots a bouncer and the speed it runs at.
+    #[must_use]
+    pub fn capture(bouncer: &Bouncer, speed: f64) -> Self {
+        Self {
+            mode: bouncer.mode(),
+            position: bouncer.position(),
+            velocity: bouncer.velocity(),
+            color: bouncer.color(),
+            palette: bouncer.palette().to_vec(),
+            stats: bouncer.stats(),
+            speed,
+        }
+    }
+
+    /// Puts a bouncer back the way it was captured. Positions outside the
+    /// current area are pulled back in on the next resize.
+    pub fn restore(&self, bouncer: &mut Bouncer) {
+        bouncer.set_mode(self.mode);
+        bouncer.set_position(self.position.0, self.position.1);
+        bouncer.set_velocity(self.velocity.0, self.velocity.1);
+        bouncer.set_palette(&self.palette);
+        bouncer.set_color(self.color);
+        bouncer.set_stats(self.stats);
+    }
+
+    /// Loads the saved session, if there is a readable one.
+    #[must_use]
+    pub fn load() -> Option<Self> {
+        let text = std::fs::read_to_string(Self::path()?).ok()?;
+        toml::from_str(&text).ok()
+    }
+
+    /// Writes the session to [`path`](Self::path), creating the directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if there's no state directory or the file can't be written.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no state directory"))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, text)
+    }
+}
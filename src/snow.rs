@@ -0,0 +1,199 @@
+use crossterm::{
+    cursor, queue,
+    style::{self, Color},
+};
+use std::io::{self, Write};
+
+use crate::{Rect, rng};
+
+// --- Weather ---
+/// How hard it's snowing, and which way the wind blows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weather {
+    /// Flakes per 1000 cells of the play area
+    pub density: u16,
+    /// Wind speed in km/h; positive blows to the right
+    pub wind: i16,
+}
+
+impl Weather {
+    /// A light, still flurry, for when there's no forecast to follow.
+    pub const CALM: Self = Self {
+        density: 8,
+        wind: 0,
+    };
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self::CALM
+    }
+}
+
+// --- Snowfall ---
+/// Background snow drifting down behind the logo.
+///
+/// Flakes fall one row every [`FALL_EVERY`] frames, blown sideways by the
+/// wind, and start again at the top once they reach the bottom.
+///
+/// Drawing keeps track of which cells the screen shows a flake in, so each
+/// frame only writes the cells that changed.
+pub struct Snowfall {
+    area: Rect,
+    weather: Weather,
+    flakes: Vec<(u16, u16)>,
+    /// One cell per screen cell of the area: whether it shows a flake
+    shown: Vec<bool>,
+    frame: u16,
+}
+
+/// Frames between each row the snow falls.
+pub const FALL_EVERY: u16 = 3;
+
+// Anything windier than this blows every flake sideways on every step
+const GALE: i16 = 60;
+
+impl Snowfall {
+    #[must_use]
+    pub fn new(area: Rect, weather: Weather) -> Self {
+        let mut snow = Self {
+            area,
+            weather,
+            flakes: Vec::new(),
+            shown: Vec::new(),
+            frame: 0,
+        };
+        snow.resize(area);
+        snow
+    }
+
+    /// Moves the snow into a new area, scattering fresh flakes over it.
+    pub fn resize(&mut self, area: Rect) {
+        self.area = area;
+        self.flakes.clear();
+        self.shown = vec![false; self.cells()];
+        self.settle();
+    }
+
+    /// Forgets what's on screen, for after it was cleared.
+    pub fn redraw(&mut self) {
+        self.shown.fill(false);
+    }
+
+    fn cells(&self) -> usize {
+        usize::from(self.area.width) * usize::from(self.area.height)
+    }
+
+    #[must_use]
+    pub const fn weather(&self) -> Weather {
+        self.weather
+    }
+
+    /// Changes the weather; the flake count catches up on the next update.
+    pub const fn set_weather(&mut self, weather: Weather) {
+        self.weather = weather;
+    }
+
+    // How many flakes the current density asks for
+    fn target(&self) -> usize {
+        self.cells() * usize::from(self.weather.density) / 1000
+    }
+
+    // Adds or removes flakes until there are as many as the weather calls for
+    fn settle(&mut self) {
+        let target = self.target();
+        self.flakes.truncate(target);
+        while self.flakes.len() < target {
+            let x = self.random_column();
+            let y = self.area.y + rng::<u16>() % self.area.height.max(1);
+            self.flakes.push((x, y));
+        }
+    }
+
+    fn random_column(&self) -> u16 {
+        self.area.x + rng::<u16>() % self.area.width.max(1)
+    }
+
+    /// Advances the snow by one frame.
+    pub fn update(&mut self) {
+        self.frame = (self.frame + 1) % FALL_EVERY;
+        if self.frame != 0 || self.area.width == 0 || self.area.height == 0 {
+            return;
+        }
+        self.settle();
+
+        let Rect {
+            x: left,
+            y: top,
+            width,
+            height,
+        } = self.area;
+        let gust = self.weather.wind.unsigned_abs().min(GALE.unsigned_abs());
+        for i in 0..self.flakes.len() {
+            let (mut x, y) = self.flakes[i];
+            // Stronger winds push more of the flakes over each step
+            if rng::<u16>() % GALE.unsigned_abs() < gust {
+                let offset = x - left;
+                x = left
+                    + if self.weather.wind > 0 {
+                        (offset + 1) % width
+                    } else {
+                        (offset + width - 1) % width
+                    };
+            }
+            self.flakes[i] = if y + 1 >= top + height {
+                (self.random_column(), top)
+            } else {
+                (x, y + 1)
+            };
+        }
+    }
+
+    /// Erases flakes that moved and draws them where they are now, writing
+    /// only the cells that changed. Nothing is drawn inside `covered`, the
+    /// cells something else draws over this frame; flakes there are erased
+    /// and come back once it moves off. Draw this before the logo, with the
+    /// logo's old and new positions covered.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&mut self, w: &mut impl Write, covered: &[Rect]) -> io::Result<()> {
+        let width = usize::from(self.area.width);
+        if width == 0 {
+            return Ok(());
+        }
+        let mut wanted = vec![false; self.shown.len()];
+        for &(x, y) in &self.flakes {
+            if !covered.iter().any(|r| r.contains(x, y)) {
+                wanted[usize::from(y - self.area.y) * width + usize::from(x - self.area.x)] = true;
+            }
+        }
+
+        queue!(w, style::SetForegroundColor(Color::DarkGrey))?;
+        // Each run of changed cells in a row goes out as one move and one print
+        let mut run = String::new();
+        for (row, (shown, wanted)) in
+            (self.area.y..).zip(self.shown.chunks(width).zip(wanted.chunks(width)))
+        {
+            let mut run_start = self.area.x;
+            for (col, (&was, &now)) in (self.area.x..).zip(shown.iter().zip(wanted)) {
+                if was != now {
+                    if run.is_empty() {
+                        run_start = col;
+                    }
+                    run.push(if now { '·' } else { ' ' });
+                } else if !run.is_empty() {
+                    queue!(w, cursor::MoveTo(run_start, row), style::Print(&run))?;
+                    run.clear();
+                }
+            }
+            if !run.is_empty() {
+                queue!(w, cursor::MoveTo(run_start, row), style::Print(&run))?;
+                run.clear();
+            }
+        }
+        queue!(w, style::ResetColor)?;
+        self.shown = wanted;
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[cfg(feature = "sound")]
+use rodio::{Decoder, MixerDeviceSink, Source, source::Buffered};
+
+// --- Sound Choice ---
+/// What a wall hit sounds like, as given to `--sound`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Sound {
+    #[default]
+    None,
+    /// The terminal bell
+    Bell,
+    /// A sample file, played through the default output device
+    File(PathBuf),
+}
+
+impl FromStr for Sound {
+    type Err = String;
+
+    /// Parses `none`, `bell`, or anything else as a file path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("expected `bell`, `none` or a file".to_string()),
+            "none" => Ok(Self::None),
+            "bell" => Ok(Self::Bell),
+            path => Ok(Self::File(PathBuf::from(path))),
+        }
+    }
+}
+
+// --- Speaker ---
+/// Plays a [`Sound`], opened once up front so each hit is cheap.
+pub enum Speaker {
+    Silent,
+    Bell,
+    #[cfg(feature = "sound")]
+    Sample {
+        // Playback stops when the sink is dropped
+        sink: MixerDeviceSink,
+        sample: Buffered<Decoder<io::BufReader<std::fs::File>>>,
+    },
+}
+
+impl Speaker {
+    /// Gets ready to play `sound`, decoding a sample file up front.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read or decoded, there's no
+    /// audio output, or the program was built without the `sound` feature.
+    pub fn open(sound: &Sound) -> io::Result<Self> {
+        match sound {
+            Sound::None => Ok(Self::Silent),
+            Sound::Bell => Ok(Self::Bell),
+            #[cfg(feature = "sound")]
+            Sound::File(path) => {
+                let fail = |e: &dyn std::fmt::Display| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: {e}", path.display()),
+                    )
+                };
+                let file = std::fs::File::open(path).map_err(|e| fail(&e))?;
+                let sample = Decoder::try_from(file).map_err(|e| fail(&e))?.buffered();
+                let sink = rodio::DeviceSinkBuilder::open_default_sink()
+                    .map_err(|e| io::Error::other(format!("audio output: {e}")))?;
+                Ok(Self::Sample { sink, sample })
+            }
+            #[cfg(not(feature = "sound"))]
+            Sound::File(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "playing sound files needs a build with the `sound` feature",
+            )),
+        }
+    }
+
+    /// Plays the sound once, over anything still playing. The bell is only
+    /// queued on `w`, and rings with the next flush.
+    ///
+    /// # Errors
+    /// Returns an error if ringing the bell fails to write.
+    pub fn play(&self, w: &mut impl Write) -> io::Result<()> {
+        match self {
+            Self::Silent => Ok(()),
+            Self::Bell => w.write_all(b"\x07"),
+            #[cfg(feature = "sound")]
+            Self::Sample { sink, sample } => {
+                sink.mixer().add(sample.clone());
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::SymbolMode;
+
+// --- System Info ---
+/// The lines of a neofetch-style card: `user@hostname` over a rule, then
+/// the OS, kernel, uptime and package count, leaving out whatever can't be
+/// found out here.
+#[must_use]
+pub fn lines() -> Vec<String> {
+    let title = format!("{}@{}", crate::banner::user(), crate::banner::hostname());
+    let rule = "-".repeat(title.chars().count());
+    let mut lines = vec![title, rule];
+    lines.push(format!("OS: {}", os_name()));
+    if let Some(kernel) = kernel() {
+        lines.push(format!("Kernel: {kernel}"));
+    }
+    if let Some(uptime) = uptime() {
+        lines.push(format!("Uptime: {}", format_uptime(uptime)));
+    }
+    if let Some((count, manager)) = packages() {
+        lines.push(format!("Packages: {count} ({manager})"));
+    }
+    lines
+}
+
+/// The built-in symbol closest to the running system's logo: the NixOS
+/// snowflake for NixOS and anything unknown, Arch for Arch and its
+/// derivatives, the daemon for the BSDs and the apple on macOS.
+#[must_use]
+pub fn logo() -> SymbolMode {
+    if cfg!(target_os = "macos") {
+        return SymbolMode::Apple;
+    }
+    if cfg!(any(
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly"
+    )) {
+        return SymbolMode::Bsd;
+    }
+    let release = os_release();
+    let ids = [field(&release, "ID"), field(&release, "ID_LIKE")];
+    if ids
+        .iter()
+        .flatten()
+        .any(|id| id.split_whitespace().any(|id| id == "arch"))
+    {
+        SymbolMode::Arch
+    } else {
+        SymbolMode::NixOS
+    }
+}
+
+fn os_release() -> String {
+    ["/etc/os-release", "/usr/lib/os-release"]
+        .into_iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .unwrap_or_default()
+}
+
+// A `KEY=value` line's value, with any quotes taken off
+fn field(release: &str, key: &str) -> Option<String> {
+    release.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix('=')?;
+        Some(value.trim().trim_matches('"').to_string())
+    })
+}
+
+fn os_name() -> String {
+    let release = os_release();
+    field(&release, "PRETTY_NAME")
+        .or_else(|| field(&release, "NAME"))
+        .unwrap_or_else(|| std::env::consts::OS.to_string())
+}
+
+#[cfg(unix)]
+fn kernel() -> Option<String> {
+    // SAFETY: utsname is plain data, so all zeroes is a valid value for it
+    let mut name: libc::utsname = unsafe { std::mem::zeroed() };
+    // SAFETY: `name` is valid to write to
+    if unsafe { libc::uname(&raw mut name) } != 0 {
+        return None;
+    }
+    // SAFETY: uname leaves each field NUL-terminated
+    let release = unsafe { std::ffi::CStr::from_ptr(name.release.as_ptr()) };
+    Some(release.to_string_lossy().into_owned())
+}
+
+#[cfg(not(unix))]
+fn kernel() -> Option<String> {
+    None
+}
+
+// Only Linux has it handy, in /proc/uptime
+fn uptime() -> Option<Duration> {
+    let text = std::fs::read_to_string("/proc/uptime").ok()?;
+    let seconds: f64 = text.split_whitespace().next()?.parse().ok()?;
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+// `3d 4h 12m`, leaving out days and hours while there are none
+fn format_uptime(uptime: Duration) -> String {
+    let minutes = uptime.as_secs() / 60;
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) => format!("{minutes}m"),
+        (0, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h {minutes}m"),
+    }
+}
+
+/// How many packages the system's package manager has installed, and which
+/// manager that is. Counting can take a while, so it's done once and kept.
+#[must_use]
+pub fn packages() -> Option<(usize, &'static str)> {
+    static PACKAGES: OnceLock<Option<(usize, &'static str)>> = OnceLock::new();
+    *PACKAGES.get_or_init(count_packages)
+}
+
+// The first package manager found, looking at its database where that's
+// cheap and asking it otherwise
+fn count_packages() -> Option<(usize, &'static str)> {
+    if std::path::Path::new("/run/current-system/sw").exists()
+        && let Some(count) = count_lines(
+            "nix-store",
+            &["--query", "--requisites", "/run/current-system/sw"],
+        )
+    {
+        return Some((count, "nix"));
+    }
+    if let Ok(entries) = std::fs::read_dir("/var/lib/pacman/local") {
+        let count = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .count();
+        return Some((count, "pacman"));
+    }
+    if let Ok(status) = std::fs::read_to_string("/var/lib/dpkg/status") {
+        let count = status
+            .lines()
+            .filter(|line| *line == "Status: install ok installed")
+            .count();
+        return Some((count, "dpkg"));
+    }
+    if let Ok(installed) = std::fs::read_to_string("/lib/apk/db/installed") {
+        let count = installed.lines().filter(|l| l.starts_with("P:")).count();
+        return Some((count, "apk"));
+    }
+    count_lines("rpm", &["-qa"]).map(|count| (count, "rpm"))
+}
+
+fn count_lines(program: &str, args: &[&str]) -> Option<usize> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output.status.success().then(|| {
+        output
+            .stdout
+            .split(|&b| b == b'\n')
+            .filter(|l| !l.is_empty())
+            .count()
+    })
+}
@@ -0,0 +1,127 @@
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+// --- Log Tail ---
+/// How often [`Tail::refresh`] looks for more of the file.
+pub const POLL: Duration = Duration::from_millis(250);
+
+// How far back from the end a new tail starts reading, to find the last line
+const LOOK_BACK: u64 = 64 * 1024;
+
+/// The last line of a file that keeps growing, like `tail -f` showing one
+/// line at a time.
+///
+/// A file that shrinks, the way a log does when it's rotated or truncated,
+/// is read again from the start.
+pub struct Tail {
+    path: PathBuf,
+    /// How much of the file has been read
+    read: u64,
+    /// What came after the last newline so far
+    partial: String,
+    line: String,
+    checked: Instant,
+}
+
+impl Tail {
+    /// Starts following `path` from its last line.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let len = std::fs::metadata(path)
+            .map_err(|e| with_path(path, &e))?
+            .len();
+        let mut tail = Self {
+            path: path.to_path_buf(),
+            read: len.saturating_sub(LOOK_BACK),
+            partial: String::new(),
+            line: String::new(),
+            checked: Instant::now(),
+        };
+        tail.read_more().map_err(|e| with_path(path, &e))?;
+        Ok(tail)
+    }
+
+    /// The latest line, with control characters taken out so it can't
+    /// upset the terminal. Empty until the file has any.
+    #[must_use]
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// Reads whatever was added since the last look, at most once every
+    /// [`POLL`]; returns true if the latest line changed. A file that
+    /// can't be read for now keeps showing the line it had.
+    pub fn refresh(&mut self) -> bool {
+        if self.checked.elapsed() < POLL {
+            return false;
+        }
+        self.checked = Instant::now();
+        let was = std::mem::take(&mut self.line);
+        if self.read_more().is_err() || self.line.is_empty() {
+            self.line = was;
+            return false;
+        }
+        was != self.line
+    }
+
+    // Reads from where the last read stopped to the end, keeping the last
+    // non-empty line in `line`, the unfinished one counting too
+    fn read_more(&mut self) -> io::Result<()> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if len < self.read {
+            // Truncated or replaced: start over
+            self.read = 0;
+            self.partial.clear();
+        }
+        file.seek(SeekFrom::Start(self.read))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        self.read += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+        if let Some(end) = self.partial.rfind('\n') {
+            let done = self.partial[..end]
+                .lines()
+                .rev()
+                .find(|l| !l.trim().is_empty())
+                .map(clean);
+            self.partial.drain(..=end);
+            if let Some(done) = done {
+                self.line = done;
+            }
+        }
+        if !self.partial.trim().is_empty() {
+            self.line = clean(&self.partial);
+        }
+        Ok(())
+    }
+}
+
+// Tabs become spaces, and other control characters are dropped, along with
+// the colors of build tools' `ESC [ ... m` sequences
+fn clean(line: &str) -> String {
+    let mut clean = String::with_capacity(line.len());
+    let mut chars = line.trim().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => {
+                if chars.next() == Some('[') {
+                    // Up to and including the final byte
+                    chars.by_ref().find(|c| ('@'..='~').contains(c));
+                }
+            }
+            '\t' => clean.push(' '),
+            c if c.is_control() => {}
+            c => clean.push(c),
+        }
+    }
+    clean
+}
+
+fn with_path(path: &Path, e: &io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
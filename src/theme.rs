@@ -0,0 +1,615 @@
+use crossterm::style::Color;
+
+// --- Themes ---
+/// A named palette that bounces pick their next color from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub colors: &'static [Color],
+}
+
+const fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::Rgb { r, g, b }
+}
+
+/// The original seven terminal colors.
+pub const DEFAULT: Theme = Theme {
+    name: "default",
+    colors: &[
+        Color::Green,
+        Color::Blue,
+        Color::White,
+        Color::Yellow,
+        Color::Cyan,
+        Color::Magenta,
+        Color::Red,
+    ],
+};
+
+pub const NORD: Theme = Theme {
+    name: "nord",
+    colors: &[
+        rgb(0x88, 0xc0, 0xd0),
+        rgb(0x81, 0xa1, 0xc1),
+        rgb(0x5e, 0x81, 0xac),
+        rgb(0xa3, 0xbe, 0x8c),
+        rgb(0xeb, 0xcb, 0x8b),
+        rgb(0xb4, 0x8e, 0xad),
+    ],
+};
+
+pub const CATPPUCCIN: Theme = Theme {
+    name: "catppuccin",
+    colors: &[
+        rgb(0x89, 0xb4, 0xfa),
+        rgb(0xcb, 0xa6, 0xf7),
+        rgb(0xf5, 0xc2, 0xe7),
+        rgb(0xa6, 0xe3, 0xa1),
+        rgb(0xf9, 0xe2, 0xaf),
+        rgb(0x94, 0xe2, 0xd5),
+    ],
+};
+
+pub const GRUVBOX: Theme = Theme {
+    name: "gruvbox",
+    colors: &[
+        rgb(0xfb, 0x49, 0x34),
+        rgb(0xb8, 0xbb, 0x26),
+        rgb(0xfa, 0xbd, 0x2f),
+        rgb(0x83, 0xa5, 0x98),
+        rgb(0xd3, 0x86, 0x9b),
+        rgb(0x8e, 0xc0, 0x7c),
+    ],
+};
+
+pub const DRACULA: Theme = Theme {
+    name: "dracula",
+    colors: &[
+        rgb(0xbd, 0x93, 0xf9),
+        rgb(0xff, 0x79, 0xc6),
+        rgb(0x8b, 0xe9, 0xfd),
+        rgb(0x50, 0xfa, 0x7b),
+        rgb(0xf1, 0xfa, 0x8c),
+        rgb(0xff, 0xb8, 0x6c),
+    ],
+};
+
+/// Bright colors for daytime; [`NIGHT`] holds the same hues, dimmed.
+pub const DAY: Theme = Theme {
+    name: "day",
+    colors: &[
+        rgb(0x42, 0xa5, 0xf5),
+        rgb(0x66, 0xbb, 0x6a),
+        rgb(0xff, 0xca, 0x28),
+        rgb(0xef, 0x53, 0x50),
+        rgb(0xab, 0x47, 0xbc),
+        rgb(0x26, 0xc6, 0xda),
+    ],
+};
+
+pub const NIGHT: Theme = Theme {
+    name: "night",
+    colors: &[
+        rgb(0x15, 0x34, 0x63),
+        rgb(0x1c, 0x46, 0x24),
+        rgb(0x66, 0x4e, 0x10),
+        rgb(0x60, 0x1e, 0x1e),
+        rgb(0x3c, 0x1a, 0x46),
+        rgb(0x0e, 0x46, 0x50),
+    ],
+};
+
+// The color vision deficiency palettes keep apart the colors each kind of
+// color blindness confuses, and differ in brightness too
+
+/// Okabe and Ito's palette, for red-green color blindness where green looks
+/// like red.
+pub const DEUTERANOPIA: Theme = Theme {
+    name: "cvd-deuteranopia",
+    colors: &[
+        rgb(0xe6, 0x9f, 0x00),
+        rgb(0x56, 0xb4, 0xe9),
+        rgb(0x00, 0x9e, 0x73),
+        rgb(0xf0, 0xe4, 0x42),
+        rgb(0x00, 0x72, 0xb2),
+        rgb(0xd5, 0x5e, 0x00),
+        rgb(0xcc, 0x79, 0xa7),
+    ],
+};
+
+/// Red-green color blindness where red looks dark: blues and yellows, and
+/// no deep reds.
+pub const PROTANOPIA: Theme = Theme {
+    name: "cvd-protanopia",
+    colors: &[
+        rgb(0x56, 0xb4, 0xe9),
+        rgb(0xf0, 0xe4, 0x42),
+        rgb(0x00, 0x72, 0xb2),
+        rgb(0xe6, 0x9f, 0x00),
+        rgb(0xcc, 0x79, 0xa7),
+        rgb(0xf5, 0xf5, 0xf5),
+    ],
+};
+
+/// Blue-yellow color blindness: reds against cyans, told apart by
+/// brightness as much as hue.
+pub const TRITANOPIA: Theme = Theme {
+    name: "cvd-tritanopia",
+    colors: &[
+        rgb(0xf2, 0x4c, 0x4c),
+        rgb(0x2e, 0xc4, 0xd6),
+        rgb(0xf5, 0xf5, 0xf5),
+        rgb(0xf2, 0x9e, 0xc4),
+        rgb(0x00, 0x7a, 0x7a),
+        rgb(0x9e, 0x9e, 0x9e),
+    ],
+};
+
+// --- Symbol Schemes ---
+// Not in `ALL`: each goes with its symbol rather than being picked by name
+
+/// NixOS's light logo blue and its neighbours; the darker one is kept for
+/// the logo's second tone.
+pub const NIXOS: Theme = Theme {
+    name: "nixos",
+    colors: &[
+        rgb(0x7e, 0xba, 0xe4),
+        rgb(0xa8, 0xd4, 0xf2),
+        rgb(0x6a, 0xae, 0xe0),
+        rgb(0x93, 0xc6, 0xeb),
+    ],
+};
+
+/// Arch's cyan blue, lighter and darker.
+pub const ARCH: Theme = Theme {
+    name: "arch",
+    colors: &[
+        rgb(0x17, 0x93, 0xd1),
+        rgb(0x4d, 0xb8, 0xe8),
+        rgb(0x0f, 0x6d, 0x9e),
+        rgb(0x8f, 0xd6, 0xf5),
+    ],
+};
+
+/// The BSD daemon's reds.
+pub const BSD: Theme = Theme {
+    name: "bsd",
+    colors: &[
+        rgb(0xab, 0x2b, 0x28),
+        rgb(0xd7, 0x3a, 0x31),
+        rgb(0xf2, 0x6b, 0x5b),
+        rgb(0x80, 0x1a, 0x18),
+    ],
+};
+
+/// Brushed aluminium greys.
+pub const APPLE: Theme = Theme {
+    name: "apple",
+    colors: &[
+        rgb(0xa2, 0xaa, 0xad),
+        rgb(0xd1, 0xd5, 0xd8),
+        rgb(0x77, 0x7e, 0x82),
+        rgb(0xf5, 0xf5, 0xf7),
+    ],
+};
+
+/// Icy whites and blues for the snowflakes.
+pub const ICE: Theme = Theme {
+    name: "ice",
+    colors: &[
+        rgb(0xe8, 0xf4, 0xfc),
+        rgb(0xa5, 0xd8, 0xf3),
+        rgb(0x6c, 0xb4, 0xe0),
+        rgb(0xc9, 0xe6, 0xf7),
+    ],
+};
+
+/// Every built-in theme, in menu order.
+pub const ALL: [Theme; 10] = [
+    DEFAULT,
+    NORD,
+    CATPPUCCIN,
+    GRUVBOX,
+    DRACULA,
+    DAY,
+    NIGHT,
+    DEUTERANOPIA,
+    PROTANOPIA,
+    TRITANOPIA,
+];
+
+/// Looks up a built-in theme by name (case-insensitive).
+#[must_use]
+pub fn find(name: &str) -> Option<Theme> {
+    ALL.into_iter().find(|t| t.name.eq_ignore_ascii_case(name))
+}
+
+/// Parses a color from the default palette by name, like `green`, or
+/// anything [`parse_rgb`] takes.
+#[must_use]
+pub fn parse_color(s: &str) -> Option<Color> {
+    DEFAULT
+        .colors
+        .iter()
+        .copied()
+        .find(|c| format!("{c:?}").eq_ignore_ascii_case(s.trim()))
+        .or_else(|| parse_rgb(s))
+}
+
+/// Parses `#RRGGBB`, `RRGGBB` or `r,g,b` into a truecolor [`Color`].
+#[must_use]
+pub fn parse_rgb(s: &str) -> Option<Color> {
+    let s = s.trim();
+    if let Some((r, rest)) = s.split_once(',') {
+        let (g, b) = rest.split_once(',')?;
+        return Some(Color::Rgb {
+            r: r.trim().parse().ok()?,
+            g: g.trim().parse().ok()?,
+            b: b.trim().parse().ok()?,
+        });
+    }
+
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Color::Rgb {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+    })
+}
+
+/// A fully saturated color `degrees` around the hue wheel (0 is red).
+#[must_use]
+pub fn hue(degrees: u16) -> Color {
+    let degrees = degrees % 360;
+    // How far through the current sixth of the wheel, as a channel value
+    let rising = u8::try_from(u32::from(degrees % 60) * 255 / 60).unwrap_or(u8::MAX);
+    let falling = u8::MAX - rising;
+    match degrees / 60 {
+        0 => rgb(255, rising, 0),
+        1 => rgb(falling, 255, 0),
+        2 => rgb(0, 255, rising),
+        3 => rgb(0, falling, 255),
+        4 => rgb(rising, 0, 255),
+        _ => rgb(255, 0, falling),
+    }
+}
+
+// --- Terminal Colors ---
+/// How many colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// The sixteen named colors
+    Ansi16,
+    /// The 256 indexed colors
+    Ansi256,
+    /// Any RGB value
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guesses from `COLORTERM` and `TERM`, as most terminals don't say.
+    /// Without a `TERM` at all, as for a window, it's true color.
+    #[must_use]
+    pub fn detect() -> Self {
+        let var = |name| std::env::var(name).unwrap_or_default();
+        let colorterm = var("COLORTERM");
+        let term = var("TERM");
+        if colorterm == "truecolor"
+            || colorterm == "24bit"
+            || term.is_empty()
+            || term.contains("direct")
+        {
+            Self::TrueColor
+        } else if term.contains("256") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    /// The nearest color to `color` the terminal can show; anything but
+    /// truecolor values is left alone.
+    #[must_use]
+    pub fn fit(self, color: Color) -> Color {
+        let Color::Rgb { r, g, b } = color else {
+            return color;
+        };
+        match self {
+            Self::TrueColor => color,
+            Self::Ansi256 => Color::AnsiValue(nearest_indexed(r, g, b)),
+            Self::Ansi16 => nearest_named(r, g, b),
+        }
+    }
+}
+
+/// A fade from one color at the top of the logo to another at the bottom,
+/// mixed in full color and then fitted to the terminal row by row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gradient {
+    pub top: Color,
+    pub bottom: Color,
+    depth: ColorDepth,
+}
+
+impl Gradient {
+    /// A gradient for the terminal this is running in.
+    #[must_use]
+    pub fn new(top: Color, bottom: Color) -> Self {
+        Self {
+            top,
+            bottom,
+            depth: ColorDepth::detect(),
+        }
+    }
+
+    /// The color of row `row` of a logo `rows` tall.
+    #[must_use]
+    pub fn at(&self, row: usize, rows: usize) -> Color {
+        let amount = if rows > 1 {
+            u16::try_from(row.min(rows - 1) * 1000 / (rows - 1)).unwrap_or(1000)
+        } else {
+            0
+        };
+        self.depth.fit(blend(self.top, self.bottom, amount))
+    }
+}
+
+/// Parses a color like [`parse_color`], fitted to what the terminal can
+/// show, for colors typed in by the user.
+#[must_use]
+pub fn parse_for_terminal(s: &str) -> Option<Color> {
+    parse_color(s).map(|c| ColorDepth::detect().fit(c))
+}
+
+fn distance((r, g, b): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+    d(r, r2) + d(g, g2) + d(b, b2)
+}
+
+// The closer of the 6x6x6 cube and the grey ramp of the 256 colors
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let step = |v: u8| {
+        (0u8..6)
+            .min_by_key(|&i| LEVELS[usize::from(i)].abs_diff(v))
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (step(r), step(g), step(b));
+    let cube = (
+        LEVELS[usize::from(ri)],
+        LEVELS[usize::from(gi)],
+        LEVELS[usize::from(bi)],
+    );
+    // Greys run from 8 to 238 in steps of 10
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let grey = u8::try_from(average.saturating_sub(3) / 10)
+        .unwrap_or(0)
+        .min(23);
+    let level = 8 + grey * 10;
+    if distance((level, level, level), (r, g, b)) < distance(cube, (r, g, b)) {
+        232 + grey
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+fn nearest_named(r: u8, g: u8, b: u8) -> Color {
+    const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::DarkRed,
+        Color::DarkGreen,
+        Color::DarkYellow,
+        Color::DarkBlue,
+        Color::DarkMagenta,
+        Color::DarkCyan,
+        Color::Grey,
+        Color::DarkGrey,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ];
+    NAMED
+        .into_iter()
+        .min_by_key(|&c| xterm_rgb(c).map_or(u32::MAX, |named| distance(named, (r, g, b))))
+        .unwrap_or(Color::White)
+}
+
+// --- Day and Night ---
+// Dawn and dusk, as (start, end) in minutes after midnight
+const DAWN: (u16, u16) = (6 * 60, 7 * 60);
+const DUSK: (u16, u16) = (19 * 60, 20 * 60);
+
+/// How much daylight there is at `minute` past midnight, from 0 (night) to
+/// 1000 (day), rising through dawn and falling through dusk.
+#[must_use]
+pub const fn daylight(minute: u16) -> u16 {
+    let minute = minute % (24 * 60);
+    if minute < DAWN.0 || minute >= DUSK.1 {
+        0
+    } else if minute < DAWN.1 {
+        (minute - DAWN.0) * 1000 / (DAWN.1 - DAWN.0)
+    } else if minute < DUSK.0 {
+        1000
+    } else {
+        (DUSK.1 - minute) * 1000 / (DUSK.1 - DUSK.0)
+    }
+}
+
+/// One of the named terminal colors in xterm's default palette, as red,
+/// green and blue; `None` for the others.
+#[must_use]
+pub const fn xterm_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let rgb = match color {
+        Color::Black => (0, 0, 0),
+        Color::DarkRed => (205, 0, 0),
+        Color::DarkGreen => (0, 205, 0),
+        Color::DarkYellow => (205, 205, 0),
+        Color::DarkBlue => (0, 0, 238),
+        Color::DarkMagenta => (205, 0, 205),
+        Color::DarkCyan => (0, 205, 205),
+        Color::Grey => (229, 229, 229),
+        Color::DarkGrey => (127, 127, 127),
+        Color::Red => (255, 0, 0),
+        Color::Green => (0, 255, 0),
+        Color::Yellow => (255, 255, 0),
+        Color::Blue => (92, 92, 255),
+        Color::Magenta => (255, 0, 255),
+        Color::Cyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Rgb { .. } | Color::AnsiValue(_) | Color::Reset => return None,
+    };
+    Some(rgb)
+}
+
+/// One of the 256 indexed colors as red, green and blue: the sixteen named
+/// ones as xterm shows them, then a 6x6x6 cube, then greys.
+#[must_use]
+pub const fn indexed_rgb(n: u8) -> (u8, u8, u8) {
+    const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::DarkRed,
+        Color::DarkGreen,
+        Color::DarkYellow,
+        Color::DarkBlue,
+        Color::DarkMagenta,
+        Color::DarkCyan,
+        Color::Grey,
+        Color::DarkGrey,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ];
+    const fn level(v: u8) -> u8 {
+        if v == 0 { 0 } else { 55 + v * 40 }
+    }
+    match n {
+        0..=15 => match xterm_rgb(NAMED[n as usize]) {
+            Some(rgb) => rgb,
+            None => (0, 0, 0),
+        },
+        16..=231 => {
+            let n = n - 16;
+            (level(n / 36), level(n / 6 % 6), level(n % 6))
+        }
+        _ => {
+            let grey = 8 + (n - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+/// How the config file writes `color`: by name if [`parse_color`] knows
+/// it, otherwise as `#rrggbb`. `None` for the terminal's own default.
+#[must_use]
+pub fn color_name(color: Color) -> Option<String> {
+    if DEFAULT.colors.contains(&color) {
+        return Some(format!("{color:?}").to_ascii_lowercase());
+    }
+    let (r, g, b) = match color {
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::AnsiValue(n) => indexed_rgb(n),
+        named => xterm_rgb(named)?,
+    };
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+/// Mixes `from` into `to`; `amount` runs from 0 (all `from`) to 1000 (all `to`).
+///
+/// Truecolor values and the named colors mix, the latter as xterm shows
+/// them; anything else switches over halfway.
+#[must_use]
+pub fn blend(from: Color, to: Color, amount: u16) -> Color {
+    let amount = i32::from(amount.min(1000));
+    let truecolor = |color: Color| xterm_rgb(color).map_or(color, |(r, g, b)| rgb(r, g, b));
+    match (truecolor(from), truecolor(to)) {
+        (
+            Color::Rgb { r, g, b },
+            Color::Rgb {
+                r: r2,
+                g: g2,
+                b: b2,
+            },
+        ) => {
+            let mix = |a: u8, b: u8| {
+                let (a, b) = (i32::from(a), i32::from(b));
+                u8::try_from(a + (b - a) * amount / 1000).unwrap_or(u8::MAX)
+            };
+            rgb(mix(r, r2), mix(g, g2), mix(b, b2))
+        }
+        _ if amount < 500 => from,
+        _ => to,
+    }
+}
+
+/// The palette for a [`daylight`] level, between [`NIGHT`] and [`DAY`].
+#[must_use]
+pub fn sky(daylight: u16) -> Vec<Color> {
+    NIGHT
+        .colors
+        .iter()
+        .zip(DAY.colors)
+        .map(|(&night, &day)| blend(night, day, daylight))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rgb_takes_hex_and_triples() {
+        let teal = Some(Color::Rgb {
+            r: 0,
+            g: 128,
+            b: 128,
+        });
+        assert_eq!(parse_rgb("#008080"), teal);
+        assert_eq!(parse_rgb(" 008080 "), teal);
+        assert_eq!(parse_rgb("0, 128,128"), teal);
+        for bad in [
+            "",
+            "#00808",
+            "#0080800",
+            "#00808g",
+            "0,128",
+            "0,128,256",
+            "#ééé",
+        ] {
+            assert_eq!(parse_rgb(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn fit_picks_the_nearest_color_the_terminal_has() {
+        let rgb = |r, g, b| Color::Rgb { r, g, b };
+        assert_eq!(ColorDepth::TrueColor.fit(rgb(1, 2, 3)), rgb(1, 2, 3));
+        assert_eq!(
+            ColorDepth::Ansi256.fit(rgb(255, 0, 0)),
+            Color::AnsiValue(196)
+        );
+        // Greys go to the grey ramp rather than the cube
+        assert_eq!(
+            ColorDepth::Ansi256.fit(rgb(128, 128, 128)),
+            Color::AnsiValue(244)
+        );
+        assert_eq!(ColorDepth::Ansi16.fit(rgb(250, 10, 10)), Color::Red);
+        assert_eq!(ColorDepth::Ansi16.fit(rgb(0, 0, 0)), Color::Black);
+        // Colors that aren't RGB are left for the terminal
+        for depth in [ColorDepth::Ansi16, ColorDepth::Ansi256] {
+            assert_eq!(depth.fit(Color::Cyan), Color::Cyan);
+            assert_eq!(depth.fit(Color::AnsiValue(42)), Color::AnsiValue(42));
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::{Bouncer, Observer, Rect, SymbolMode};
+
+// --- Trajectories ---
+/// Where the logo was after one tick, and how it was moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub position: (u16, u16),
+    pub velocity: (i32, i32),
+}
+
+impl Sample {
+    #[must_use]
+    pub const fn of(bouncer: &Bouncer) -> Self {
+        Self {
+            position: bouncer.position(),
+            velocity: bouncer.velocity(),
+        }
+    }
+}
+
+// A recording is plain text: which symbol bounced in which area, then one
+// `x y dx dy` line per tick, the first being where it started
+const HEADER: &str = "# snowflake-bounce trajectory";
+
+/// Writes every tick of a run to a file, for [`Replay`] to play back.
+///
+/// Add it to the bouncer with [`Bouncer::add_observer`] once
+/// [`start`](Self::start) has written where the run begins. Writing stops
+/// quietly at the first error, leaving what was recorded so far.
+pub struct Recorder {
+    out: Option<BufWriter<File>>,
+}
+
+impl Recorder {
+    /// Creates (or truncates) the file at `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be created.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = File::create(path).map_err(|e| with_path(path, &e))?;
+        Ok(Self {
+            out: Some(BufWriter::new(file)),
+        })
+    }
+
+    /// Writes the symbol, area and starting position of `bouncer`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the file fails.
+    pub fn start(&mut self, bouncer: &Bouncer) -> io::Result<()> {
+        let Some(out) = &mut self.out else {
+            return Ok(());
+        };
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = bouncer.bounds();
+        writeln!(out, "{HEADER}")?;
+        writeln!(out, "symbol {}", bouncer.mode())?;
+        writeln!(out, "area {x},{y},{width},{height}")?;
+        write_sample(out, Sample::of(bouncer))
+    }
+}
+
+impl Observer for Recorder {
+    fn on_tick(&mut self, sample: Sample) {
+        if let Some(out) = &mut self.out
+            && write_sample(out, sample).is_err()
+        {
+            self.out = None;
+        }
+    }
+}
+
+fn write_sample(out: &mut impl Write, sample: Sample) -> io::Result<()> {
+    let ((x, y), (dx, dy)) = (sample.position, sample.velocity);
+    writeln!(out, "{x} {y} {dx} {dy}")
+}
+
+/// A run written by a [`Recorder`], played back one tick at a time.
+pub struct Replay {
+    symbol: Option<SymbolMode>,
+    area: Rect,
+    samples: std::vec::IntoIter<Sample>,
+}
+
+impl Replay {
+    /// Reads a whole recording.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read, or an `InvalidData`
+    /// error naming the line that isn't a recording.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let file = File::open(path).map_err(|e| with_path(path, &e))?;
+        let bad = |n: usize, what: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: line {n}: {what}", path.display()),
+            )
+        };
+        let mut symbol = None;
+        let mut area = None;
+        let mut samples = Vec::new();
+        for (i, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| with_path(path, &e))?;
+            let n = i + 1;
+            let line = line.trim();
+            if n == 1 && line != HEADER {
+                return Err(bad(n, "not a snowflake-bounce trajectory"));
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("symbol ") {
+                // A pack that's gone just leaves the symbol as it is
+                symbol = SymbolMode::from_name(name.trim());
+            } else if let Some(rect) = line.strip_prefix("area ") {
+                area = Some(rect.parse::<Rect>().map_err(|e| bad(n, &e))?);
+            } else {
+                samples.push(parse_sample(line).ok_or_else(|| bad(n, "expected x y dx dy"))?);
+            }
+        }
+        let area = area.ok_or_else(|| bad(1, "no area"))?;
+        if samples.is_empty() {
+            return Err(bad(1, "no ticks recorded"));
+        }
+        Ok(Self {
+            symbol,
+            area,
+            samples: samples.into_iter(),
+        })
+    }
+
+    /// The symbol that was recorded, if it's still around.
+    #[must_use]
+    pub const fn symbol(&self) -> Option<SymbolMode> {
+        self.symbol
+    }
+
+    /// The play area the run was recorded in.
+    #[must_use]
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Whether every tick has been played.
+    #[must_use]
+    pub fn done(&self) -> bool {
+        self.samples.len() == 0
+    }
+}
+
+impl Iterator for Replay {
+    type Item = Sample;
+
+    fn next(&mut self) -> Option<Sample> {
+        self.samples.next()
+    }
+}
+
+fn parse_sample(line: &str) -> Option<Sample> {
+    let mut fields = line.split_whitespace();
+    let mut next = || fields.next();
+    let sample = Sample {
+        position: (next()?.parse().ok()?, next()?.parse().ok()?),
+        velocity: (next()?.parse().ok()?, next()?.parse().ok()?),
+    };
+    fields.next().is_none().then_some(sample)
+}
+
+fn with_path(path: &Path, e: &io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
@@ -0,0 +1,693 @@
+use crossterm::{
+    cursor,
+    event::KeyCode,
+    queue,
+    style::{self, Color},
+    terminal,
+};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::Rect;
+use crate::keymap::{Action, Keymap};
+use crate::theme::{self, Theme};
+
+// --- Overlay ---
+/// A boxed panel drawn on top of the animation, centered in the terminal.
+///
+/// Overlays don't track what was underneath them; call [`Overlay::clear`]
+/// when dismissing so the next frame starts from a blank screen.
+pub struct Overlay {
+    title: String,
+    lines: Vec<String>,
+}
+
+impl Overlay {
+    #[must_use]
+    pub fn new(title: impl Into<String>, lines: Vec<String>) -> Self {
+        Self {
+            title: title.into(),
+            lines,
+        }
+    }
+
+    // Inner width: widest line or the title, whichever is longer
+    fn inner_width(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|l| l.width())
+            .chain(std::iter::once(self.title.width() + 2))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Draws the overlay centered in a `cols` x `rows` terminal, framed in
+    /// plain `+-|` with `ascii`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&self, w: &mut impl Write, cols: u16, rows: u16, ascii: bool) -> io::Result<()> {
+        let (box_w, box_h) = self.size();
+        let left = cols.saturating_sub(box_w) / 2;
+        let top = rows.saturating_sub(box_h) / 2;
+        self.draw_at(w, (left, top), rows, ascii)
+    }
+
+    /// Draws the overlay horizontally centered along the top of the terminal, toast style.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw_top(
+        &self,
+        w: &mut impl Write,
+        cols: u16,
+        rows: u16,
+        ascii: bool,
+    ) -> io::Result<()> {
+        let (box_w, _) = self.size();
+        self.draw_at(w, (cols.saturating_sub(box_w) / 2, 1), rows, ascii)
+    }
+
+    // Outer size including the border
+    fn size(&self) -> (u16, u16) {
+        let box_w = u16::try_from(self.inner_width() + 4).unwrap_or(u16::MAX);
+        let box_h = u16::try_from(self.lines.len() + 2).unwrap_or(u16::MAX);
+        (box_w, box_h)
+    }
+
+    fn draw_at(
+        &self,
+        w: &mut impl Write,
+        (left, top): (u16, u16),
+        rows: u16,
+        ascii: bool,
+    ) -> io::Result<()> {
+        let inner = self.inner_width();
+        let (_, box_h) = self.size();
+        let Glyphs {
+            corners: [top_left, top_right, bottom_left, bottom_right],
+            across,
+            side,
+        } = Glyphs::new(ascii);
+
+        let title = format!(" {} ", self.title);
+        let pad = inner + 2 - title.width();
+        let header = format!("{top_left}{title}{}{top_right}", across.repeat(pad));
+        let footer = format!("{bottom_left}{}{bottom_right}", across.repeat(inner + 2));
+
+        queue!(
+            w,
+            style::SetForegroundColor(Color::White),
+            cursor::MoveTo(left, top),
+            style::Print(header)
+        )?;
+        for (i, line) in self.lines.iter().enumerate() {
+            let row = top.saturating_add(u16::try_from(i + 1).unwrap_or(u16::MAX));
+            if row >= rows {
+                break;
+            }
+            let fill = inner - line.width();
+            queue!(
+                w,
+                cursor::MoveTo(left, row),
+                style::Print(format!("{side} {line}{} {side}", " ".repeat(fill)))
+            )?;
+        }
+        queue!(
+            w,
+            cursor::MoveTo(left, top.saturating_add(box_h - 1)),
+            style::Print(footer),
+            style::ResetColor
+        )?;
+
+        Ok(())
+    }
+
+    /// Wipes the screen so the overlay doesn't linger under the next frame.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn clear(w: &mut impl Write) -> io::Result<()> {
+        queue!(w, terminal::Clear(terminal::ClearType::All))?;
+        Ok(())
+    }
+}
+
+// --- Menu ---
+/// What a key press did to a [`Menu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuAction {
+    None,
+    Moved,
+    Confirm,
+    Cancel,
+}
+
+/// A modal, scrollable list with one selected item.
+pub struct Menu {
+    title: String,
+    items: Vec<String>,
+    selected: usize,
+    offset: usize,
+    height: usize,
+}
+
+impl Menu {
+    /// Creates a menu showing at most `height` items at a time.
+    #[must_use]
+    pub fn new(
+        title: impl Into<String>,
+        items: Vec<String>,
+        selected: usize,
+        height: usize,
+    ) -> Self {
+        let height = height.max(1);
+        let selected = selected.min(items.len().saturating_sub(1));
+        Self {
+            title: title.into(),
+            offset: selected.saturating_sub(height - 1),
+            items,
+            selected,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Moves the selection with arrows, `j`/`k` and `g`/`G`, confirms with Enter, cancels with Esc/`q`.
+    pub fn handle_key(&mut self, code: KeyCode) -> MenuAction {
+        let last = self.items.len().saturating_sub(1);
+        let prev = self.selected;
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Home | KeyCode::Char('g') => self.selected = 0,
+            KeyCode::End | KeyCode::Char('G') => self.selected = last,
+            KeyCode::Enter => return MenuAction::Confirm,
+            KeyCode::Esc | KeyCode::Char('q') => return MenuAction::Cancel,
+            _ => return MenuAction::None,
+        }
+
+        // Keep the selection inside the visible window
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + self.height {
+            self.offset = self.selected + 1 - self.height;
+        }
+
+        if self.selected == prev {
+            MenuAction::None
+        } else {
+            MenuAction::Moved
+        }
+    }
+
+    /// Renders the visible window of items as an [`Overlay`].
+    #[must_use]
+    pub fn overlay(&self) -> Overlay {
+        // Pad every row to the widest item so the box never changes size while scrolling
+        let width = self.items.iter().map(|i| i.width()).max().unwrap_or(0);
+        let lines = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(self.offset)
+            .take(self.height)
+            .map(|(i, item)| {
+                let marker = if i == self.selected { '>' } else { ' ' };
+                let fill = width - item.width();
+                format!("{marker} {item}{}", " ".repeat(fill))
+            })
+            .collect();
+        Overlay::new(self.title.clone(), lines)
+    }
+}
+
+// --- Text Input ---
+/// What a key press did to an [`Input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputAction {
+    None,
+    Edited,
+    Submit(String),
+    Cancel,
+}
+
+/// A single-line text field.
+pub struct Input {
+    prompt: String,
+    buffer: String,
+}
+
+impl Input {
+    #[must_use]
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            buffer: String::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.buffer = text.into();
+    }
+
+    /// Edits the buffer; Enter submits it and Esc cancels.
+    pub fn handle_key(&mut self, code: KeyCode) -> InputAction {
+        match code {
+            KeyCode::Char(c) => self.buffer.push(c),
+            KeyCode::Backspace => {
+                if self.buffer.pop().is_none() {
+                    return InputAction::None;
+                }
+            }
+            KeyCode::Enter => return InputAction::Submit(std::mem::take(&mut self.buffer)),
+            KeyCode::Esc => return InputAction::Cancel,
+            _ => return InputAction::None,
+        }
+        InputAction::Edited
+    }
+
+    /// Renders the field as a one-line [`Overlay`], leaving room to type.
+    #[must_use]
+    pub fn overlay(&self) -> Overlay {
+        let line = format!("{}{}_", self.prompt, self.buffer);
+        let width = line.width().max(24);
+        let fill = width - line.width();
+        Overlay::new("Input", vec![format!("{line}{}", " ".repeat(fill))])
+    }
+
+    /// Draws the field as a full-width line on `row`, vim command-line style.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw_line(&self, w: &mut impl Write, cols: u16, row: u16) -> io::Result<()> {
+        let text = format!("{}{}_", self.prompt, self.buffer);
+        print_line(w, &text, cols, row)
+    }
+}
+
+// Prints `text` on `row`, padded to full width so stale text never survives a shorter update
+fn print_line(w: &mut impl Write, text: &str, cols: u16, row: u16) -> io::Result<()> {
+    let cols = usize::from(cols);
+    let mut line = String::new();
+    let mut used = 0;
+    // Measured in cells, so a wide glyph that would overhang is left off
+    for c in text.chars() {
+        let width = c.width().unwrap_or(0);
+        if used + width > cols {
+            break;
+        }
+        line.push(c);
+        used += width;
+    }
+    line.extend(std::iter::repeat_n(' ', cols - used));
+    queue!(w, cursor::MoveTo(0, row), style::Print(line))?;
+    Ok(())
+}
+
+/// Draws a one-line error message on `row`.
+///
+/// # Errors
+/// Returns an error if writing to the output fails.
+pub fn draw_error(w: &mut impl Write, message: &str, cols: u16, row: u16) -> io::Result<()> {
+    queue!(w, style::SetForegroundColor(Color::Red))?;
+    print_line(w, message, cols, row)?;
+    queue!(w, style::ResetColor)?;
+    Ok(())
+}
+
+// --- Color Picker ---
+/// One row of the color picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    Color(Color),
+    Theme(Theme),
+    /// Opens an RGB input field
+    Rgb,
+}
+
+/// A [`Menu`] over palette colors, themes, and a custom RGB entry.
+pub struct ColorPicker {
+    pub menu: Menu,
+    choices: Vec<ColorChoice>,
+}
+
+impl ColorPicker {
+    /// Builds the picker, listing the default palette's colors then every theme.
+    #[must_use]
+    pub fn new(height: usize) -> Self {
+        let mut choices: Vec<ColorChoice> = theme::DEFAULT
+            .colors
+            .iter()
+            .map(|&c| ColorChoice::Color(c))
+            .collect();
+        choices.extend(theme::ALL.iter().map(|&t| ColorChoice::Theme(t)));
+        choices.push(ColorChoice::Rgb);
+
+        let items = choices
+            .iter()
+            .map(|c| match c {
+                ColorChoice::Color(color) => format!("{color:?}"),
+                ColorChoice::Theme(t) => format!("theme: {}", t.name),
+                ColorChoice::Rgb => "rgb…".to_string(),
+            })
+            .collect();
+        Self {
+            menu: Menu::new("Colors", items, 0, height),
+            choices,
+        }
+    }
+
+    #[must_use]
+    pub fn current(&self) -> ColorChoice {
+        self.choices[self.menu.selected()]
+    }
+}
+
+/// Builds the help overlay: keybindings plus the bouncer's current settings.
+#[must_use]
+pub fn help(bouncer: &crate::Bouncer, keymap: &Keymap) -> Overlay {
+    let bound: Vec<_> = Action::ALL
+        .iter()
+        .map(|&a| (key_names(keymap, a), a.description()))
+        .collect();
+    let width = bound.iter().map(|(k, _)| k.width()).max().unwrap_or(0);
+
+    let mut lines: Vec<String> = bound
+        .into_iter()
+        .map(|(keys, desc)| {
+            let fill = width - keys.width();
+            format!("{keys}{}  {desc}", " ".repeat(fill))
+        })
+        .collect();
+    lines.extend([
+        String::new(),
+        format!("symbol: {}", bouncer.mode),
+        format!("color:  {:?}", bouncer.color()),
+        String::new(),
+        "press any key to continue".to_string(),
+    ]);
+    Overlay::new("Help", lines)
+}
+
+// `q/Esc`, or `-` when the action has been unbound
+fn key_names(keymap: &Keymap, action: Action) -> String {
+    let keys: Vec<_> = keymap
+        .keys(action)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    if keys.is_empty() {
+        "-".to_string()
+    } else {
+        keys.join("/")
+    }
+}
+
+// --- Status Bar ---
+/// Counts rendered frames and reports a once-per-second frames-per-second figure.
+pub struct FpsCounter {
+    frames: u32,
+    since: Instant,
+    fps: u32,
+}
+
+impl FpsCounter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            frames: 0,
+            since: Instant::now(),
+            fps: 0,
+        }
+    }
+
+    /// Records one rendered frame.
+    pub fn tick(&mut self) {
+        self.frames += 1;
+        let elapsed = self.since.elapsed();
+        if elapsed >= Duration::from_secs(1) {
+            let millis = u32::try_from(elapsed.as_millis()).unwrap_or(u32::MAX);
+            self.fps = self.frames.saturating_mul(1000) / millis.max(1);
+            self.frames = 0;
+            self.since = Instant::now();
+        }
+    }
+
+    #[must_use]
+    pub const fn fps(&self) -> u32 {
+        self.fps
+    }
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Draws a one-line status bar on `row`, the row just below the play area.
+///
+/// # Errors
+/// Returns an error if writing to the output fails.
+pub fn draw_status_bar(
+    w: &mut impl Write,
+    bouncer: &crate::Bouncer,
+    keymap: &Keymap,
+    speed: f64,
+    fps: u32,
+    cols: u16,
+    row: u16,
+) -> io::Result<()> {
+    let text = format!(
+        " {} | {:?} | speed {} x{speed} | {} fps | {} help",
+        bouncer.mode,
+        bouncer.color(),
+        bouncer.speed(),
+        fps,
+        key_names(keymap, Action::Help)
+    );
+    // Reverse video would fill in a see-through terminal's background
+    let attribute = if bouncer.transparent() {
+        style::Attribute::Underlined
+    } else {
+        style::Attribute::Reverse
+    };
+    queue!(w, style::SetAttribute(attribute))?;
+    print_line(w, &text, cols, row)?;
+    queue!(w, style::SetAttribute(style::Attribute::Reset))?;
+    Ok(())
+}
+
+// --- Ticker ---
+/// A line of text scrolling right to left, like a news ticker.
+///
+/// The scroll position comes from the clock rather than the frame count, so
+/// the ticker keeps its own pace whatever the animation speed.
+pub struct Ticker {
+    // The message plus a gap before it comes round again
+    chars: Vec<char>,
+    /// Characters scrolled per second
+    speed: u16,
+    started: Instant,
+}
+
+// Blank cells between the end of the message and its next start
+const TICKER_GAP: usize = 8;
+
+impl Ticker {
+    #[must_use]
+    pub fn new(text: &str, speed: u16) -> Self {
+        let chars = text
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .chain(std::iter::repeat_n(' ', TICKER_GAP))
+            .collect();
+        Self {
+            chars,
+            speed,
+            started: Instant::now(),
+        }
+    }
+
+    /// Draws the visible stretch of the message on `row`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    pub fn draw(&self, w: &mut impl Write, cols: u16, row: u16) -> io::Result<()> {
+        let millis = self.started.elapsed().as_millis();
+        let scrolled = millis * u128::from(self.speed) / 1000;
+        let len = self.chars.len();
+        let offset = usize::try_from(scrolled % len as u128).unwrap_or(0);
+        let line: String = self
+            .chars
+            .iter()
+            .cycle()
+            .skip(offset)
+            .take(usize::from(cols))
+            .collect();
+        print_line(w, &line, cols, row)
+    }
+}
+
+// --- Border ---
+// What frames are drawn with: box-drawing lines, or plain ASCII
+struct Glyphs {
+    // Top left, top right, bottom left, bottom right
+    corners: [char; 4],
+    across: &'static str,
+    side: char,
+}
+
+impl Glyphs {
+    const fn new(ascii: bool) -> Self {
+        if ascii {
+            Self {
+                corners: ['+'; 4],
+                across: "-",
+                side: '|',
+            }
+        } else {
+            Self {
+                corners: ['┌', '┐', '└', '┘'],
+                across: "─",
+                side: '│',
+            }
+        }
+    }
+}
+
+/// Draws a frame along the edges of `frame`, in box-drawing lines or with
+/// `ascii` in plain `+-|`.
+///
+/// # Errors
+/// Returns an error if writing to the output fails.
+pub fn draw_border(w: &mut impl Write, frame: Rect, ascii: bool) -> io::Result<()> {
+    if frame.width < 2 || frame.height < 2 {
+        return Ok(());
+    }
+    let inner = usize::from(frame.width - 2);
+    let right = frame.x + frame.width - 1;
+    let bottom = frame.y + frame.height - 1;
+    let Glyphs {
+        corners: [top_left, top_right, bottom_left, bottom_right],
+        across,
+        side,
+    } = Glyphs::new(ascii);
+
+    queue!(
+        w,
+        cursor::MoveTo(frame.x, frame.y),
+        style::Print(format!("{top_left}{}{top_right}", across.repeat(inner)))
+    )?;
+    for row in frame.y + 1..bottom {
+        queue!(
+            w,
+            cursor::MoveTo(frame.x, row),
+            style::Print(side),
+            cursor::MoveTo(right, row),
+            style::Print(side)
+        )?;
+    }
+    queue!(
+        w,
+        cursor::MoveTo(frame.x, bottom),
+        style::Print(format!(
+            "{bottom_left}{}{bottom_right}",
+            across.repeat(inner)
+        ))
+    )?;
+    Ok(())
+}
+
+/// Builds the `S` symbol picker with the bouncer's current symbol selected.
+#[must_use]
+pub fn symbol_picker(bouncer: &crate::Bouncer, height: usize) -> Menu {
+    let items = crate::SymbolMode::all()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let selected = crate::SymbolMode::all()
+        .iter()
+        .position(|&m| m == bouncer.mode)
+        .unwrap_or(0);
+    Menu::new("Symbols", items, selected, height)
+}
+
+// --- Locale ---
+/// Whether the locale says the terminal takes UTF-8, going by the first
+/// of `LC_ALL`, `LC_CTYPE` and `LANG` that's set. With none set, it's the
+/// plain `C` locale. Windows terminals always do.
+#[must_use]
+pub fn utf8_locale() -> bool {
+    if cfg!(windows) {
+        return true;
+    }
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .find(|v| !v.is_empty())
+        .is_some_and(|v| {
+            let v = v.to_string_lossy().to_ascii_lowercase();
+            v.contains("utf-8") || v.contains("utf8")
+        })
+}
+
+/// Whether `NO_COLOR` asks for output without colors: set to anything but
+/// an empty string, as <https://no-color.org> has it.
+#[must_use]
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bouncer;
+    use crate::keymap::Key;
+
+    #[test]
+    fn help_lists_every_action_with_its_keys() {
+        let mut keymap = Keymap::default();
+        keymap.bind(Action::Help, vec![Key::new(KeyCode::F(1))]);
+        keymap.bind(Action::Arch, Vec::new());
+        let help = help(&Bouncer::sized(80, 24), &keymap);
+        for (line, action) in help.lines.iter().zip(Action::ALL) {
+            let keys = key_names(&keymap, action);
+            assert!(line.starts_with(&keys), "{line:?}");
+            assert!(line.ends_with(action.description()), "{line:?}");
+        }
+        let find = |action: Action| {
+            help.lines
+                .iter()
+                .find(|l| l.ends_with(action.description()))
+                .unwrap()
+        };
+        assert!(find(Action::Help).starts_with("F1 "));
+        assert!(find(Action::Quit).starts_with("q/Esc "));
+        assert!(find(Action::Arch).starts_with("- "));
+    }
+
+    #[test]
+    fn overlay_draws_every_line_inside_its_box() {
+        let overlay = Overlay::new("Help", vec!["one".to_string(), "three".to_string()]);
+        let mut out = Vec::new();
+        overlay.draw(&mut out, 40, 10, true).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("+ Help --+"), "{out:?}");
+        // As wide as the title needs, with every line padded to it
+        assert!(out.contains("| one    |"), "{out:?}");
+        assert!(out.contains("| three  |"), "{out:?}");
+        assert!(out.contains("+--------+"), "{out:?}");
+    }
+}
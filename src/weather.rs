@@ -0,0 +1,95 @@
+use std::io;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use crate::snow::Weather;
+
+// --- Forecast ---
+// wttr.in guesses the location from the IP address; `m` asks for metric units
+const URL: &str = "https://wttr.in/?format=%C|%p|%w&m";
+
+/// How often the forecast is fetched again.
+pub const REFRESH: Duration = Duration::from_mins(30);
+
+/// Fetches the local weather now and then every [`REFRESH`], on a
+/// background thread so a slow network never holds up the animation.
+///
+/// Failed fetches are skipped; the snow keeps its last weather.
+#[must_use]
+pub fn watch() -> Receiver<Weather> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        loop {
+            if let Ok(weather) = fetch()
+                && tx.send(weather).is_err()
+            {
+                // Nobody is watching any more
+                return;
+            }
+            thread::sleep(REFRESH);
+        }
+    });
+    rx
+}
+
+/// Fetches the current local weather from wttr.in.
+///
+/// # Errors
+/// Returns an error if the request fails or the reply doesn't parse.
+pub fn fetch() -> io::Result<Weather> {
+    let text = ureq::get(URL)
+        .call()
+        .and_then(|mut reply| reply.body_mut().read_to_string())
+        .map_err(io::Error::other)?;
+    parse(&text).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unexpected weather reply `{}`", text.trim()),
+        )
+    })
+}
+
+/// Reads a `condition|precipitation|wind` reply, e.g. `Light snow|0.4mm|↙11km/h`.
+///
+/// Snow falls heavier the more precipitation there is; rain or dry weather
+/// keeps to a light flurry.
+#[must_use]
+pub fn parse(text: &str) -> Option<Weather> {
+    let mut fields = text.trim().split('|');
+    let (condition, rain, wind) = (fields.next()?, fields.next()?, fields.next()?);
+
+    let tenths = tenths(rain.trim().trim_end_matches("mm"))?;
+    let density = if condition.to_ascii_lowercase().contains("snow") {
+        // 10 per mille for a dusting, up to 60 from 5mm on
+        10 + tenths.min(50)
+    } else {
+        Weather::CALM.density
+    };
+
+    // The arrow points the way the wind blows
+    let wind = wind.trim();
+    let arrow = wind.chars().next()?;
+    let speed: i16 = wind
+        .trim_start_matches(arrow)
+        .trim_end_matches("km/h")
+        .parse()
+        .ok()?;
+    let wind = match arrow {
+        '→' | '↗' | '↘' => speed,
+        '←' | '↙' | '↖' => -speed,
+        _ => 0,
+    };
+    Some(Weather { density, wind })
+}
+
+// Tenths of a unit in a decimal like `0.4`
+fn tenths(s: &str) -> Option<u16> {
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, "0"));
+    let whole: u16 = whole.parse().ok()?;
+    let digit = match fraction.chars().next() {
+        Some(c) => u16::try_from(c.to_digit(10)?).ok()?,
+        None => 0,
+    };
+    Some(whole.saturating_mul(10).saturating_add(digit))
+}
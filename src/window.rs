@@ -0,0 +1,68 @@
+use minifb::{Key, KeyRepeat, Window, WindowOptions};
+use std::io;
+use std::time::Instant;
+
+use crate::app::Options;
+use crate::bitmap::{self, CELL_HEIGHT, CELL_WIDTH};
+use crate::{Bouncer, Rect};
+
+// --- Window ---
+/// Width of the window's play area, in cells.
+pub const COLS: u16 = 100;
+/// Height of the window's play area, in cells.
+pub const ROWS: u16 = 36;
+
+const WIDTH: usize = COLS as usize * CELL_WIDTH;
+const HEIGHT: usize = ROWS as usize * CELL_HEIGHT;
+
+/// Runs the animation in a desktop window instead of the terminal, drawn
+/// with a bundled 8x8 font, until the window is closed or `q`/Esc pressed.
+/// `c` changes color and `s` the symbol.
+///
+/// Only the symbol is taken from `options`; the terminal-only extras
+/// don't apply here.
+///
+/// # Errors
+/// Returns an error if the window can't be opened or drawn to.
+pub fn run(options: &Options) -> io::Result<()> {
+    let mut window = Window::new("snowflake-bounce", WIDTH, HEIGHT, WindowOptions::default())
+        .map_err(io::Error::other)?;
+    window.set_target_fps(60);
+
+    let area = Rect::new(0, 0, COLS, ROWS);
+    let mut bouncer = Bouncer::try_new(COLS, ROWS)?;
+    if let Some(mode) = options.symbol {
+        bouncer.mode = mode;
+        if let Some(color) = mode.color() {
+            bouncer.set_color(color);
+        }
+    }
+    bouncer.set_area(area);
+
+    let mut pixels = vec![0; WIDTH * HEIGHT];
+    let mut last = Instant::now();
+    while window.is_open() {
+        for key in window.get_keys_pressed(KeyRepeat::No) {
+            match key {
+                Key::Escape | Key::Q => return Ok(()),
+                Key::C => bouncer.cycle_color(),
+                Key::S => {
+                    bouncer.cycle_symbol();
+                    // The new symbol may be a different size
+                    bouncer.set_area(area);
+                }
+                _ => {}
+            }
+        }
+
+        let now = Instant::now();
+        bouncer.step(now - last);
+        last = now;
+
+        bitmap::paint(&bouncer, &mut pixels, WIDTH);
+        window
+            .update_with_buffer(&pixels, WIDTH, HEIGHT)
+            .map_err(io::Error::other)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use std::io;
+use std::ptr;
+use std::time::{Duration, Instant};
+use x11_dl::xlib::{self, Xlib};
+
+use crate::app::Options;
+use crate::bitmap::{self, CELL_HEIGHT, CELL_WIDTH};
+use crate::{Bouncer, Rect};
+
+// --- XScreenSaver Hack ---
+// 60 frames a second
+const FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+/// The window xscreensaver wants a hack to draw in, from
+/// `XSCREENSAVER_WINDOW`: an X window id, in hex like `0x2a00007` or in
+/// decimal.
+#[must_use]
+pub fn window_from_env() -> Option<xlib::Window> {
+    let id = std::env::var("XSCREENSAVER_WINDOW").ok()?;
+    let id = id.trim();
+    id.strip_prefix("0x")
+        .or_else(|| id.strip_prefix("0X"))
+        .map_or_else(
+            || id.parse().ok(),
+            |hex| xlib::Window::from_str_radix(hex, 16).ok(),
+        )
+}
+
+// The X connection and what's drawn with it, let go of in order on drop
+struct Screen {
+    xlib: Xlib,
+    display: *mut xlib::Display,
+    window: xlib::Window,
+    gc: xlib::GC,
+    visual: *mut xlib::Visual,
+    depth: u32,
+    width: usize,
+    height: usize,
+}
+
+impl Screen {
+    fn open(window: xlib::Window) -> io::Result<Self> {
+        let xlib = Xlib::open().map_err(io::Error::other)?;
+        // SAFETY: a null name means `$DISPLAY`
+        let display = unsafe { (xlib.XOpenDisplay)(ptr::null()) };
+        if display.is_null() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "can't open the X display",
+            ));
+        }
+        // SAFETY: XWindowAttributes is plain data, so all zeroes is a valid value for it
+        let mut attributes: xlib::XWindowAttributes = unsafe { std::mem::zeroed() };
+        // SAFETY: the display is open and `attributes` is valid to write to
+        let found = unsafe { (xlib.XGetWindowAttributes)(display, window, &raw mut attributes) };
+        if found == 0 {
+            // SAFETY: the display is open, and not used again
+            unsafe { (xlib.XCloseDisplay)(display) };
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no X window {window:#x}"),
+            ));
+        }
+        // The pixels are painted as 0RGB, which only a true color visual takes as is
+        if attributes.depth < 24 {
+            // SAFETY: as above
+            unsafe { (xlib.XCloseDisplay)(display) };
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("{}-bit X windows aren't supported", attributes.depth),
+            ));
+        }
+        // SAFETY: the display is open and the window exists
+        let gc = unsafe { (xlib.XCreateGC)(display, window, 0, ptr::null_mut()) };
+        Ok(Self {
+            xlib,
+            display,
+            window,
+            gc,
+            visual: attributes.visual,
+            depth: u32::try_from(attributes.depth).unwrap_or(24),
+            width: usize::try_from(attributes.width).unwrap_or(0),
+            height: usize::try_from(attributes.height).unwrap_or(0),
+        })
+    }
+
+    // Copies 0RGB pixels, `width` by `height`, into the window
+    fn show(&self, pixels: &mut [u32]) -> io::Result<()> {
+        let (Ok(width), Ok(height)) = (u32::try_from(self.width), u32::try_from(self.height))
+        else {
+            return Ok(());
+        };
+        // SAFETY: the display is open and the visual is the window's
+        let image = unsafe {
+            (self.xlib.XCreateImage)(
+                self.display,
+                self.visual,
+                self.depth,
+                xlib::ZPixmap,
+                0,
+                pixels.as_mut_ptr().cast(),
+                width,
+                height,
+                32,
+                0,
+            )
+        };
+        if image.is_null() {
+            return Err(io::Error::other("couldn't make an X image"));
+        }
+        // SAFETY: the image is `width` by `height` over `pixels`, which
+        // outlive it; its data is taken back before it's destroyed, so
+        // Xlib doesn't free what it doesn't own
+        unsafe {
+            (self.xlib.XPutImage)(
+                self.display,
+                self.window,
+                self.gc,
+                image,
+                0,
+                0,
+                0,
+                0,
+                width,
+                height,
+            );
+            (*image).data = ptr::null_mut();
+            (self.xlib.XDestroyImage)(image);
+            (self.xlib.XFlush)(self.display);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // SAFETY: the GC was made on this display, which is still open
+        unsafe {
+            (self.xlib.XFreeGC)(self.display, self.gc);
+            (self.xlib.XCloseDisplay)(self.display);
+        }
+    }
+}
+
+/// Runs as an xscreensaver hack, drawing into the X window `window` with
+/// the same 8x8 font as `--window` until xscreensaver stops it, as it does
+/// with SIGTERM.
+///
+/// Only the symbol is taken from `options`; the terminal-only extras
+/// don't apply here.
+///
+/// # Errors
+/// Returns an error if the display can't be opened, the window doesn't
+/// exist or has fewer than 24 bits per pixel.
+pub fn run(options: &Options, window: xlib::Window) -> io::Result<()> {
+    let screen = Screen::open(window)?;
+    let cols = u16::try_from(screen.width / CELL_WIDTH).unwrap_or(u16::MAX);
+    let rows = u16::try_from(screen.height / CELL_HEIGHT).unwrap_or(u16::MAX);
+    let area = Rect::new(0, 0, cols, rows);
+    let mut bouncer = Bouncer::try_new(cols, rows)?;
+    if let Some(mode) = options.symbol {
+        bouncer.mode = mode;
+        if let Some(color) = mode.color() {
+            bouncer.set_color(color);
+        }
+    }
+    bouncer.set_area(area);
+
+    let mut pixels = vec![0; screen.width * screen.height];
+    let mut last = Instant::now();
+    loop {
+        let now = Instant::now();
+        bouncer.step(now - last);
+        last = now;
+
+        bitmap::paint(&bouncer, &mut pixels, screen.width);
+        screen.show(&mut pixels)?;
+        std::thread::sleep(FRAME_TIME.saturating_sub(now.elapsed()));
+    }
+}